@@ -8,5 +8,8 @@ fn main() {
         Channel::Nightly => "CHANNEL_NIGHTLY",
         Channel::Dev => "CHANNEL_DEV",
     };
+    println!(
+        "cargo:rustc-check-cfg=cfg(CHANNEL_STABLE, CHANNEL_BETA, CHANNEL_NIGHTLY, CHANNEL_DEV)"
+    );
     println!("cargo:rustc-cfg={channel}")
 }
@@ -1,7 +1,7 @@
 /**
 Representation of an aggregator function
 */
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum SelectAggregator {
     /// Returns the average value of all non-null values.
     /// The result of avg is a floating point value, except all input values are null, than the
@@ -1,20 +1,73 @@
 use std::fmt::Write;
 
+use rorm_declaration::imr::DefaultValue;
+
 use crate::create_column::{CreateColumn, CreateColumnImpl};
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+use crate::create_table::write_unique;
+#[cfg(feature = "postgres")]
+use crate::db_specific::postgres;
 use crate::error::Error;
+use crate::table_ref::TableRef;
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+use crate::value::bool_literal;
+use crate::DBImpl;
 use crate::Value;
 
+/**
+Representation of a constraint added with [AlterTableOperation::AddConstraint].
+ */
+#[derive(Debug)]
+pub enum TableConstraint<'until_build> {
+    /// The combination of the columns has to be unique
+    Unique(&'until_build [&'until_build str]),
+}
+
+/**
+Position of a column added with [AlterTableOperation::AddColumn].
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnPosition {
+    /// Add the column as first column of the table
+    First,
+    /// Add the column after the given column
+    After(String),
+}
+
+/**
+Kind of a constraint dropped with [AlterTableOperation::DropConstraint].
+
+MySQL drops each kind of constraint with a different statement.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// UNIQUE constraint, dropped as index by MySQL
+    Unique,
+    /// FOREIGN KEY constraint
+    ForeignKey,
+    /// CHECK constraint, requires MySQL 8.0.16 or newer
+    Check,
+    /// PRIMARY KEY constraint, MySQL ignores the name
+    PrimaryKey,
+}
+
 /**
 Representation of operations to execute in the context of an ALTER TABLE statement.
  */
 #[derive(Debug)]
 pub enum AlterTableOperation<'until_build, 'post_build> {
-    /// Use this operation to rename a table
+    /// Use this operation to rename a table.
+    ///
+    /// MySQL uses a `RENAME TABLE` statement instead.
+    /// The following operations are applied to the renamed table.
     RenameTo {
         /// New name of the table
         name: String,
     },
-    /// Use this operation to rename a column within a table
+    /// Use this operation to rename a column within a table.
+    ///
+    /// Requires SQLite 3.25 or MySQL 8.0 and newer.
+    /// Older MySQL versions only support `CHANGE COLUMN` with the full column definition.
     RenameColumnTo {
         /// Current column name
         column_name: String,
@@ -25,20 +78,95 @@ pub enum AlterTableOperation<'until_build, 'post_build> {
     AddColumn {
         /// Operation to use for adding the column
         operation: CreateColumnImpl<'until_build, 'post_build>,
+        /// Position of the new column. Only used by MySQL,
+        /// other dialects ignore it and always add the column last.
+        position: Option<ColumnPosition>,
     },
     /// Use this operation to drop an existing column.
     DropColumn {
         /// Name of the column to drop
         name: String,
     },
+    /// Use this operation to change the type of an existing column.
+    ///
+    /// MySQL uses `MODIFY COLUMN`, which replaces the complete definition of the column,
+    /// so `new_type` has to include all attributes that should be kept, e.g. `NOT NULL`.
+    /// Not supported by SQLite.
+    AlterColumnType {
+        /// Name of the column to alter
+        column_name: String,
+        /// New type of the column
+        new_type: String,
+        /// Expression to convert the existing values with. Only supported by Postgres.
+        using: Option<String>,
+    },
+    /// Use this operation to set the default value of an existing column.
+    ///
+    /// Not supported by SQLite.
+    SetDefault {
+        /// Name of the column to alter
+        column_name: String,
+        /// New default value
+        value: &'post_build DefaultValue,
+    },
+    /// Use this operation to remove the default value of an existing column.
+    ///
+    /// Not supported by SQLite.
+    DropDefault {
+        /// Name of the column to alter
+        column_name: String,
+    },
+    /// Use this operation to forbid null values in an existing column.
+    ///
+    /// Only supported by Postgres, use [AlterTableOperation::AlterColumnType] for MySQL.
+    SetNotNull {
+        /// Name of the column to alter
+        column_name: String,
+    },
+    /// Use this operation to allow null values in an existing column.
+    ///
+    /// Only supported by Postgres, use [AlterTableOperation::AlterColumnType] for MySQL.
+    DropNotNull {
+        /// Name of the column to alter
+        column_name: String,
+    },
+    /// Use this operation to add a constraint to an existing table.
+    ///
+    /// Not supported by SQLite, use a unique index instead.
+    AddConstraint {
+        /// Optional name of the constraint
+        name: Option<String>,
+        /// Constraint to add
+        constraint: TableConstraint<'until_build>,
+    },
+    /// Use this operation to drop a named constraint.
+    ///
+    /// Not supported by SQLite, the table has to be rebuilt instead.
+    DropConstraint {
+        /// Name of the constraint
+        name: String,
+        /// Kind of the constraint
+        kind: ConstraintKind,
+    },
 }
 
 /**
 The trait representing an alter table builder
 */
-pub trait AlterTable<'post_build> {
+pub trait AlterTable<'until_build, 'post_build> {
+    /**
+    Add further operations to execute on the table.
+
+    MySQL and Postgres combine the operations into a single statement where possible,
+    SQLite executes every operation in its own statement.
+
+    **Parameter**:
+    - `operations`: Operations to execute after the already added ones.
+     */
+    fn operations(self, operations: Vec<AlterTableOperation<'until_build, 'post_build>>) -> Self;
+
     /**
-    This method is used to build the alter table statement.
+    This method is used to build the alter table statements.
      */
     fn build(self) -> Result<Vec<(String, Vec<Value<'post_build>>)>, Error>;
 }
@@ -49,11 +177,9 @@ Representation of the data of an ALTER TABLE statement.
 #[derive(Debug)]
 pub struct AlterTableData<'until_build, 'post_build> {
     /// Name of the table to operate on
-    pub(crate) name: &'until_build str,
-    /// Operation to execute
-    pub(crate) operation: AlterTableOperation<'until_build, 'post_build>,
-    pub(crate) lookup: Vec<Value<'post_build>>,
-    pub(crate) statements: Vec<(String, Vec<Value<'post_build>>)>,
+    pub(crate) name: TableRef<'until_build>,
+    /// Operations to execute
+    pub(crate) operations: Vec<AlterTableOperation<'until_build, 'post_build>>,
 }
 
 /**
@@ -80,143 +206,1151 @@ pub enum AlterTableImpl<'until_build, 'post_build> {
     Postgres(AlterTableData<'until_build, 'post_build>),
 }
 
-impl<'until_build, 'post_build> AlterTable<'post_build>
+impl<'until_build, 'post_build> AlterTable<'until_build, 'post_build>
     for AlterTableImpl<'until_build, 'post_build>
 {
+    fn operations(
+        mut self,
+        mut operations: Vec<AlterTableOperation<'until_build, 'post_build>>,
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            AlterTableImpl::SQLite(ref mut d) => d.operations.append(&mut operations),
+            #[cfg(feature = "mysql")]
+            AlterTableImpl::MySQL(ref mut d) => d.operations.append(&mut operations),
+            #[cfg(feature = "postgres")]
+            AlterTableImpl::Postgres(ref mut d) => d.operations.append(&mut operations),
+        }
+        self
+    }
+
     fn build(self) -> Result<Vec<(String, Vec<Value<'post_build>>)>, Error> {
         match self {
             #[cfg(feature = "sqlite")]
-            AlterTableImpl::SQLite(mut d) => {
-                let mut s = format!("ALTER TABLE \"{}\" ", d.name);
+            AlterTableImpl::SQLite(d) => {
+                let mut clauses = vec![];
+                let mut statements = vec![];
+                let mut table = d.name.quoted(DBImpl::SQLite);
 
-                match d.operation {
-                    AlterTableOperation::RenameTo { name } => {
-                        write!(s, "RENAME TO \"{name}\"").unwrap();
-                    }
-                    AlterTableOperation::RenameColumnTo {
-                        column_name,
-                        new_column_name,
-                    } => write!(
-                        s,
-                        "RENAME COLUMN \"{column_name}\" TO \"{new_column_name}\""
-                    )
-                    .unwrap(),
-                    AlterTableOperation::AddColumn { mut operation } => {
-                        write!(s, "ADD COLUMN ").unwrap();
+                for operation in d.operations {
+                    let mut s = String::new();
+                    let mut lookup = vec![];
 
-                        #[cfg(any(feature = "mysql", feature = "postgres"))]
-                        if let CreateColumnImpl::SQLite(ref mut ccd) = operation {
-                            ccd.statements = Some(&mut d.statements);
-                            ccd.lookup = Some(&mut d.lookup);
-                        }
-                        #[cfg(not(any(feature = "mysql", feature = "postgres")))]
-                        {
-                            let CreateColumnImpl::SQLite(ref mut ccd) = operation;
-                            ccd.statements = Some(&mut d.statements);
-                            ccd.lookup = Some(&mut d.lookup);
+                    match operation {
+                        AlterTableOperation::RenameTo { name } => {
+                            clauses.extend(
+                                statements
+                                    .drain(..)
+                                    .map(|(sql, values)| AlterClause::Complete(sql, values)),
+                            );
+                            let renamed = TableRef {
+                                schema: d.name.schema,
+                                name: &name,
+                            }
+                            .quoted(DBImpl::SQLite);
+                            clauses.push(AlterClause::Rename {
+                                statement: format!(
+                                    "ALTER TABLE {table} RENAME TO {}",
+                                    DBImpl::SQLite.quote_identifier(&name)
+                                ),
+                                prefix: format!("ALTER TABLE {renamed}"),
+                            });
+                            table = renamed;
+                            continue;
                         }
+                        AlterTableOperation::RenameColumnTo {
+                            column_name,
+                            new_column_name,
+                        } => write!(
+                            s,
+                            "RENAME COLUMN \"{column_name}\" TO \"{new_column_name}\""
+                        )
+                        .unwrap(),
+                        AlterTableOperation::AddColumn { mut operation, .. } => {
+                            write!(s, "ADD COLUMN ").unwrap();
+
+                            #[cfg(any(feature = "mysql", feature = "postgres"))]
+                            if let CreateColumnImpl::SQLite(ref mut ccd) = operation {
+                                ccd.statements = Some(&mut statements);
+                                ccd.lookup = Some(&mut lookup);
+                            }
+                            #[cfg(not(any(feature = "mysql", feature = "postgres")))]
+                            {
+                                let CreateColumnImpl::SQLite(ref mut ccd) = operation;
+                                ccd.statements = Some(&mut statements);
+                                ccd.lookup = Some(&mut lookup);
+                            }
 
-                        operation.build(&mut s)?;
-                    }
-                    AlterTableOperation::DropColumn { name } => {
-                        write!(s, "DROP COLUMN \"{name}\"").unwrap()
-                    }
-                };
+                            operation.build(&mut s)?;
+                        }
+                        AlterTableOperation::DropColumn { name } => {
+                            write!(s, "DROP COLUMN \"{name}\"").unwrap()
+                        }
+                        AlterTableOperation::AlterColumnType { .. }
+                        | AlterTableOperation::SetDefault { .. }
+                        | AlterTableOperation::DropDefault { .. }
+                        | AlterTableOperation::SetNotNull { .. }
+                        | AlterTableOperation::DropNotNull { .. } => {
+                            return Err(Error::UnsupportedFeature(
+                                "SQLite doesn't support altering existing columns".to_string(),
+                            ));
+                        }
+                        AlterTableOperation::AddConstraint { .. } => {
+                            return Err(Error::UnsupportedFeature(
+                                "SQLite doesn't support adding constraints to existing tables"
+                                    .to_string(),
+                            ));
+                        }
+                        AlterTableOperation::DropConstraint { .. } => {
+                            return Err(Error::UnsupportedFeature(
+                                "SQLite doesn't support dropping constraints, the table has to be rebuilt"
+                                    .to_string(),
+                            ));
+                        }
+                    };
 
-                write!(s, ";").unwrap();
+                    clauses.push(AlterClause::Single(s, lookup));
+                }
 
-                let mut statements = vec![(s, d.lookup)];
-                statements.extend(d.statements);
+                let mut result = combine_clauses(
+                    format!("ALTER TABLE {}", d.name.quoted(DBImpl::SQLite)),
+                    clauses,
+                );
+                result.extend(statements);
 
-                Ok(statements)
+                Ok(result)
             }
             #[cfg(feature = "mysql")]
-            AlterTableImpl::MySQL(mut d) => {
-                let mut s = format!("ALTER TABLE `{}` ", d.name);
+            AlterTableImpl::MySQL(d) => {
+                let mut clauses = vec![];
+                let mut statements = vec![];
+                let mut table = d.name.quoted(DBImpl::MySQL);
 
-                match d.operation {
-                    AlterTableOperation::RenameTo { name } => {
-                        write!(s, "RENAME TO `{name}`").unwrap();
-                    }
-                    AlterTableOperation::RenameColumnTo {
-                        column_name,
-                        new_column_name,
-                    } => write!(s, "RENAME COLUMN `{column_name}` TO `{new_column_name}`").unwrap(),
-                    AlterTableOperation::AddColumn { mut operation } => {
-                        write!(s, "ADD COLUMN ").unwrap();
+                for operation in d.operations {
+                    let mut s = String::new();
+                    let mut lookup = vec![];
 
-                        #[cfg(any(feature = "sqlite", feature = "postgres"))]
-                        if let CreateColumnImpl::MySQL(ref mut ccd) = operation {
-                            ccd.statements = Some(&mut d.statements);
-                            ccd.lookup = Some(&mut d.lookup);
-                        }
-                        #[cfg(not(any(feature = "sqlite", feature = "postgres")))]
-                        {
-                            let CreateColumnImpl::MySQL(ref mut ccd) = operation;
-                            ccd.statements = Some(&mut d.statements);
-                            ccd.lookup = Some(&mut d.lookup);
+                    match operation {
+                        AlterTableOperation::RenameTo { name } => {
+                            // MySQL renames tables with its own statement
+                            clauses.extend(
+                                statements
+                                    .drain(..)
+                                    .map(|(sql, values)| AlterClause::Complete(sql, values)),
+                            );
+                            // RENAME TABLE moves the table to the schema of the new name
+                            let renamed = TableRef {
+                                schema: d.name.schema,
+                                name: &name,
+                            }
+                            .quoted(DBImpl::MySQL);
+                            clauses.push(AlterClause::Rename {
+                                statement: format!("RENAME TABLE {table} TO {renamed}"),
+                                prefix: format!("ALTER TABLE {renamed}"),
+                            });
+                            table = renamed;
+                            continue;
                         }
+                        AlterTableOperation::RenameColumnTo {
+                            column_name,
+                            new_column_name,
+                        } => write!(s, "RENAME COLUMN `{column_name}` TO `{new_column_name}`")
+                            .unwrap(),
+                        AlterTableOperation::AddColumn {
+                            mut operation,
+                            position,
+                        } => {
+                            write!(s, "ADD COLUMN ").unwrap();
 
-                        operation.build(&mut s)?;
-                    }
-                    AlterTableOperation::DropColumn { name } => {
-                        write!(s, "DROP COLUMN `{name}`").unwrap()
-                    }
-                };
+                            #[cfg(any(feature = "sqlite", feature = "postgres"))]
+                            if let CreateColumnImpl::MySQL(ref mut ccd) = operation {
+                                ccd.statements = Some(&mut statements);
+                                ccd.lookup = Some(&mut lookup);
+                            }
+                            #[cfg(not(any(feature = "sqlite", feature = "postgres")))]
+                            {
+                                let CreateColumnImpl::MySQL(ref mut ccd) = operation;
+                                ccd.statements = Some(&mut statements);
+                                ccd.lookup = Some(&mut lookup);
+                            }
 
-                write!(s, ";").unwrap();
+                            operation.build(&mut s)?;
 
-                let mut statements = vec![(s, d.lookup)];
-                statements.extend(d.statements);
+                            if let Some(position) = position {
+                                s.truncate(s.trim_end().len());
+                                match position {
+                                    ColumnPosition::First => write!(s, " FIRST").unwrap(),
+                                    ColumnPosition::After(column) => {
+                                        write!(s, " AFTER `{column}`").unwrap()
+                                    }
+                                }
+                            }
+                        }
+                        AlterTableOperation::DropColumn { name } => {
+                            write!(s, "DROP COLUMN `{name}`").unwrap()
+                        }
+                        AlterTableOperation::AlterColumnType {
+                            column_name,
+                            new_type,
+                            using,
+                        } => {
+                            if using.is_some() {
+                                return Err(Error::UnsupportedFeature(
+                                    "MySQL doesn't support USING when altering the type of a column"
+                                        .to_string(),
+                                ));
+                            }
+                            write!(s, "MODIFY COLUMN `{column_name}` {new_type}").unwrap()
+                        }
+                        AlterTableOperation::SetDefault { column_name, value } => {
+                            write!(s, "ALTER COLUMN `{column_name}` SET DEFAULT ").unwrap();
+                            match value {
+                                DefaultValue::String(dv) => {
+                                    lookup.push(Value::String(dv));
+                                    write!(s, "?").unwrap();
+                                }
+                                DefaultValue::Integer(i) => write!(s, "{i}").unwrap(),
+                                DefaultValue::Float(f) => write!(s, "{f}").unwrap(),
+                                DefaultValue::Boolean(b) => {
+                                    write!(s, "{}", bool_literal(*b, DBImpl::MySQL)).unwrap()
+                                }
+                            }
+                        }
+                        AlterTableOperation::DropDefault { column_name } => {
+                            write!(s, "ALTER COLUMN `{column_name}` DROP DEFAULT").unwrap()
+                        }
+                        AlterTableOperation::SetNotNull { .. }
+                        | AlterTableOperation::DropNotNull { .. } => {
+                            return Err(Error::UnsupportedFeature(
+                                "MySQL requires the full column definition to change its nullability"
+                                    .to_string(),
+                            ));
+                        }
+                        AlterTableOperation::AddConstraint { name, constraint } => {
+                            write!(s, "ADD ").unwrap();
+                            match constraint {
+                                TableConstraint::Unique(columns) => {
+                                    write_unique(&mut s, DBImpl::MySQL, name.as_deref(), columns)?
+                                }
+                            }
+                        }
+                        AlterTableOperation::DropConstraint { name, kind } => match kind {
+                            ConstraintKind::Unique => write!(s, "DROP INDEX `{name}`").unwrap(),
+                            ConstraintKind::ForeignKey => {
+                                write!(s, "DROP FOREIGN KEY `{name}`").unwrap()
+                            }
+                            ConstraintKind::Check => write!(s, "DROP CHECK `{name}`").unwrap(),
+                            ConstraintKind::PrimaryKey => write!(s, "DROP PRIMARY KEY").unwrap(),
+                        },
+                    };
+
+                    clauses.push(AlterClause::Combinable(s, lookup));
+                }
+
+                let mut result = combine_clauses(
+                    format!("ALTER TABLE {}", d.name.quoted(DBImpl::MySQL)),
+                    clauses,
+                );
+                result.extend(statements);
 
-                Ok(statements)
+                Ok(result)
             }
             #[cfg(feature = "postgres")]
-            AlterTableImpl::Postgres(mut d) => {
-                let mut s = format!("ALTER TABLE \"{}\" ", d.name);
+            AlterTableImpl::Postgres(d) => {
+                let mut clauses = vec![];
+                let mut statements = vec![];
+                let mut table = d.name.quoted(DBImpl::Postgres);
 
-                match d.operation {
-                    AlterTableOperation::RenameTo { name } => {
-                        write!(s, "RENAME TO \"{name}\"").unwrap();
-                    }
+                for operation in d.operations {
+                    let mut s = String::new();
 
-                    AlterTableOperation::RenameColumnTo {
-                        column_name,
-                        new_column_name,
-                    } => {
-                        write!(
-                            s,
-                            "RENAME COLUMN \"{column_name}\" TO \"{new_column_name}\""
-                        )
-                        .unwrap();
-                    }
-                    AlterTableOperation::AddColumn { mut operation } => {
-                        write!(s, "ADD COLUMN ").unwrap();
+                    // Renames can't be combined with other operations
+                    let single = matches!(operation, AlterTableOperation::RenameColumnTo { .. });
+
+                    match operation {
+                        AlterTableOperation::RenameTo { name } => {
+                            clauses.extend(
+                                statements
+                                    .drain(..)
+                                    .map(|(sql, values)| AlterClause::Complete(sql, values)),
+                            );
+                            let renamed = TableRef {
+                                schema: d.name.schema,
+                                name: &name,
+                            }
+                            .quoted(DBImpl::Postgres);
+                            clauses.push(AlterClause::Rename {
+                                statement: format!(
+                                    "ALTER TABLE {table} RENAME TO {}",
+                                    DBImpl::Postgres.quote_identifier(&name)
+                                ),
+                                prefix: format!("ALTER TABLE {renamed}"),
+                            });
+                            table = renamed;
+                            continue;
+                        }
+
+                        AlterTableOperation::RenameColumnTo {
+                            column_name,
+                            new_column_name,
+                        } => {
+                            write!(
+                                s,
+                                "RENAME COLUMN \"{column_name}\" TO \"{new_column_name}\""
+                            )
+                            .unwrap();
+                        }
+                        AlterTableOperation::AddColumn { mut operation, .. } => {
+                            write!(s, "ADD COLUMN ").unwrap();
 
-                        #[cfg(any(feature = "sqlite", feature = "mysql"))]
-                        if let CreateColumnImpl::Postgres(ref mut ccd) = operation {
-                            ccd.statements = Some(&mut d.statements);
+                            #[cfg(any(feature = "sqlite", feature = "mysql"))]
+                            if let CreateColumnImpl::Postgres(ref mut ccd) = operation {
+                                ccd.statements = Some(&mut statements);
+                            }
+                            #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+                            {
+                                let CreateColumnImpl::Postgres(ref mut ccd) = operation;
+                                ccd.statements = Some(&mut statements);
+                            }
+
+                            operation.build(&mut s)?;
+                        }
+                        AlterTableOperation::DropColumn { name } => {
+                            write!(s, "DROP COLUMN \"{name}\"").unwrap()
+                        }
+                        AlterTableOperation::AlterColumnType {
+                            column_name,
+                            new_type,
+                            using,
+                        } => {
+                            write!(s, "ALTER COLUMN \"{column_name}\" TYPE {new_type}").unwrap();
+                            if let Some(using) = using {
+                                write!(s, " USING {using}").unwrap();
+                            }
                         }
-                        #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
-                        {
-                            let CreateColumnImpl::Postgres(ref mut ccd) = operation;
-                            ccd.statements = Some(&mut d.statements);
+                        AlterTableOperation::SetDefault { column_name, value } => {
+                            write!(s, "ALTER COLUMN \"{column_name}\" SET DEFAULT ").unwrap();
+                            match value {
+                                DefaultValue::String(dv) => {
+                                    write!(s, "{}", postgres::fmt(dv)).unwrap()
+                                }
+                                DefaultValue::Integer(i) => write!(s, "{i}").unwrap(),
+                                DefaultValue::Float(f) => write!(s, "{f}").unwrap(),
+                                DefaultValue::Boolean(b) => {
+                                    write!(s, "{}", bool_literal(*b, DBImpl::Postgres)).unwrap()
+                                }
+                            }
                         }
+                        AlterTableOperation::DropDefault { column_name } => {
+                            write!(s, "ALTER COLUMN \"{column_name}\" DROP DEFAULT").unwrap()
+                        }
+                        AlterTableOperation::SetNotNull { column_name } => {
+                            write!(s, "ALTER COLUMN \"{column_name}\" SET NOT NULL").unwrap()
+                        }
+                        AlterTableOperation::DropNotNull { column_name } => {
+                            write!(s, "ALTER COLUMN \"{column_name}\" DROP NOT NULL").unwrap()
+                        }
+                        AlterTableOperation::AddConstraint { name, constraint } => {
+                            write!(s, "ADD ").unwrap();
+                            match constraint {
+                                TableConstraint::Unique(columns) => write_unique(
+                                    &mut s,
+                                    DBImpl::Postgres,
+                                    name.as_deref(),
+                                    columns,
+                                )?,
+                            }
+                        }
+                        AlterTableOperation::DropConstraint { name, .. } => {
+                            write!(s, "DROP CONSTRAINT \"{name}\"").unwrap()
+                        }
+                    };
+
+                    clauses.push(if single {
+                        AlterClause::Single(s, vec![])
+                    } else {
+                        AlterClause::Combinable(s, vec![])
+                    });
+                }
 
-                        operation.build(&mut s)?;
-                    }
-                    AlterTableOperation::DropColumn { name } => {
-                        write!(s, "DROP COLUMN \"{name}\"").unwrap()
-                    }
-                };
+                let mut result = combine_clauses(
+                    format!("ALTER TABLE {}", d.name.quoted(DBImpl::Postgres)),
+                    clauses,
+                );
+                result.extend(statements);
 
-                write!(s, ";").unwrap();
+                Ok(result)
+            }
+        }
+    }
+}
+
+/**
+A single operation of an ALTER TABLE statement, built for a dialect.
+ */
+enum AlterClause<'post_build> {
+    /// Clause that can be combined with other clauses in one statement
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    Combinable(String, Vec<Value<'post_build>>),
+    /// Clause that requires its own ALTER TABLE statement
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    Single(String, Vec<Value<'post_build>>),
+    /// Statement renaming the table, the following clauses start with the new `prefix`
+    Rename {
+        /// The complete statement without semicolon
+        statement: String,
+        /// The start of the following statements, e.g. `ALTER TABLE "account"`
+        prefix: String,
+    },
+    /// Terminated statement required by a previous clause, e.g. to create a trigger
+    Complete(String, Vec<Value<'post_build>>),
+}
+
+/**
+Joins consecutive combinable clauses into one ALTER TABLE statement.
 
-                let mut statements = vec![(s, d.lookup)];
-                statements.extend(d.statements);
+**Parameter**:
+- `prefix`: The start of the statement, e.g. `ALTER TABLE "user"`
+- `clauses`: The clauses of the operations in order
+ */
+fn combine_clauses<'post_build>(
+    mut prefix: String,
+    clauses: Vec<AlterClause<'post_build>>,
+) -> Vec<(String, Vec<Value<'post_build>>)> {
+    let end = |(mut s, values): (String, Vec<Value<'post_build>>)| {
+        s.push(';');
+        (s, values)
+    };
+    let mut statements = vec![];
+    let mut combined: Option<(String, Vec<Value<'post_build>>)> = None;
 
-                Ok(statements)
+    for clause in clauses {
+        match clause {
+            #[cfg(any(feature = "mysql", feature = "postgres"))]
+            AlterClause::Combinable(sql, values) => match combined {
+                Some((ref mut s, ref mut lookup)) => {
+                    write!(s, ", {sql}").unwrap();
+                    lookup.extend(values);
+                }
+                None => combined = Some((format!("{prefix} {sql}"), values)),
+            },
+            #[cfg(any(feature = "sqlite", feature = "postgres"))]
+            AlterClause::Single(sql, values) => {
+                statements.extend(combined.take().map(end));
+                statements.push(end((format!("{prefix} {sql}"), values)));
+            }
+            AlterClause::Rename {
+                statement,
+                prefix: renamed,
+            } => {
+                statements.extend(combined.take().map(end));
+                statements.push(end((statement, vec![])));
+                prefix = renamed;
+            }
+            AlterClause::Complete(sql, values) => {
+                statements.extend(combined.take().map(end));
+                statements.push((sql, values));
             }
         }
     }
+    statements.extend(combined.map(end));
+    statements
+}
+
+#[cfg(test)]
+mod test {
+    use rorm_declaration::imr::DefaultValue;
+
+    use rorm_declaration::imr::DbType;
+
+    use crate::alter_table::{
+        AlterTable, AlterTableOperation, ColumnPosition, ConstraintKind, TableConstraint,
+    };
+    use crate::error::Error;
+    #[cfg(feature = "mysql")]
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn rename_table_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .alter_table(
+                    "user",
+                    AlterTableOperation::RenameTo {
+                        name: "account".to_string(),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"user\" RENAME TO \"account\";".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn rename_table_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .alter_table(
+                    "user",
+                    AlterTableOperation::RenameTo {
+                        name: "account".to_string(),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [("RENAME TABLE `user` TO `account`;".to_string(), vec![])]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn rename_table_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .alter_table(
+                    "order",
+                    AlterTableOperation::RenameTo {
+                        name: "purchase".to_string(),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"order\" RENAME TO \"purchase\";".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn rename_column_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .alter_table(
+                    "user",
+                    AlterTableOperation::RenameColumnTo {
+                        column_name: "name".to_string(),
+                        new_column_name: "username".to_string(),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"user\" RENAME COLUMN \"name\" TO \"username\";".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn rename_column_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .alter_table(
+                    "user",
+                    AlterTableOperation::RenameColumnTo {
+                        column_name: "name".to_string(),
+                        new_column_name: "username".to_string(),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE `user` RENAME COLUMN `name` TO `username`;".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn rename_column_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .alter_table(
+                    "post",
+                    AlterTableOperation::RenameColumnTo {
+                        column_name: "body".to_string(),
+                        new_column_name: "content".to_string(),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"post\" RENAME COLUMN \"body\" TO \"content\";".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    const DEFAULT_NAME: &DefaultValue = &DefaultValue::String(String::new());
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn alter_column_sqlite() {
+        for operation in [
+            AlterTableOperation::AlterColumnType {
+                column_name: "age".to_string(),
+                new_type: "INTEGER".to_string(),
+                using: None,
+            },
+            AlterTableOperation::SetDefault {
+                column_name: "name".to_string(),
+                value: DEFAULT_NAME,
+            },
+            AlterTableOperation::DropDefault {
+                column_name: "name".to_string(),
+            },
+            AlterTableOperation::SetNotNull {
+                column_name: "name".to_string(),
+            },
+        ] {
+            assert!(matches!(
+                DBImpl::SQLite.alter_table("user", operation).build(),
+                Err(Error::UnsupportedFeature(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn alter_column_mysql() {
+        let build = |operation| DBImpl::MySQL.alter_table("user", operation).build();
+
+        assert_eq!(
+            build(AlterTableOperation::AlterColumnType {
+                column_name: "age".to_string(),
+                new_type: "BIGINT NOT NULL".to_string(),
+                using: None,
+            })
+            .unwrap(),
+            [(
+                "ALTER TABLE `user` MODIFY COLUMN `age` BIGINT NOT NULL;".to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            build(AlterTableOperation::SetDefault {
+                column_name: "name".to_string(),
+                value: DEFAULT_NAME,
+            })
+            .unwrap(),
+            [(
+                "ALTER TABLE `user` ALTER COLUMN `name` SET DEFAULT ?;".to_string(),
+                vec![Value::String("")]
+            )]
+        );
+        assert_eq!(
+            build(AlterTableOperation::DropDefault {
+                column_name: "name".to_string(),
+            })
+            .unwrap(),
+            [(
+                "ALTER TABLE `user` ALTER COLUMN `name` DROP DEFAULT;".to_string(),
+                vec![]
+            )]
+        );
+        assert!(matches!(
+            build(AlterTableOperation::AlterColumnType {
+                column_name: "age".to_string(),
+                new_type: "BIGINT".to_string(),
+                using: Some("age + 0".to_string()),
+            }),
+            Err(Error::UnsupportedFeature(_))
+        ));
+        assert!(matches!(
+            build(AlterTableOperation::SetNotNull {
+                column_name: "name".to_string(),
+            }),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn alter_column_postgres() {
+        let build = |operation| {
+            DBImpl::Postgres
+                .alter_table("user", operation)
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(
+            build(AlterTableOperation::AlterColumnType {
+                column_name: "age".to_string(),
+                new_type: "bigint".to_string(),
+                using: Some("age::bigint".to_string()),
+            }),
+            [(
+                "ALTER TABLE \"user\" ALTER COLUMN \"age\" TYPE bigint USING age::bigint;"
+                    .to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            build(AlterTableOperation::SetDefault {
+                column_name: "name".to_string(),
+                value: DEFAULT_NAME,
+            }),
+            [(
+                "ALTER TABLE \"user\" ALTER COLUMN \"name\" SET DEFAULT '';".to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            build(AlterTableOperation::DropDefault {
+                column_name: "name".to_string(),
+            }),
+            [(
+                "ALTER TABLE \"user\" ALTER COLUMN \"name\" DROP DEFAULT;".to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            build(AlterTableOperation::SetNotNull {
+                column_name: "name".to_string(),
+            }),
+            [(
+                "ALTER TABLE \"user\" ALTER COLUMN \"name\" SET NOT NULL;".to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            build(AlterTableOperation::DropNotNull {
+                column_name: "name".to_string(),
+            }),
+            [(
+                "ALTER TABLE \"user\" ALTER COLUMN \"name\" DROP NOT NULL;".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn add_constraint_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddConstraint {
+                        name: Some("unique_membership".to_string()),
+                        constraint: TableConstraint::Unique(&["user_id", "group_id"]),
+                    },
+                )
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn add_constraint_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddConstraint {
+                        name: Some("unique_membership".to_string()),
+                        constraint: TableConstraint::Unique(&["user_id", "group_id"]),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE `user` ADD CONSTRAINT `unique_membership` UNIQUE (`user_id`, `group_id`);"
+                    .to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn add_constraint_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddConstraint {
+                        name: Some("unique_email".to_string()),
+                        constraint: TableConstraint::Unique(&["email"]),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"user\" ADD CONSTRAINT \"unique_email\" UNIQUE (\"email\");"
+                    .to_string(),
+                vec![]
+            )]
+        );
+        assert!(matches!(
+            DBImpl::Postgres
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddConstraint {
+                        name: None,
+                        constraint: TableConstraint::Unique(&[]),
+                    },
+                )
+                .build(),
+            Err(Error::EmptyColumnList { .. })
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn drop_constraint_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .alter_table(
+                    "user",
+                    AlterTableOperation::DropConstraint {
+                        name: "user_group".to_string(),
+                        kind: ConstraintKind::Unique,
+                    },
+                )
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn drop_constraint_mysql() {
+        let build = |kind| {
+            DBImpl::MySQL
+                .alter_table(
+                    "user",
+                    AlterTableOperation::DropConstraint {
+                        name: "user_group".to_string(),
+                        kind,
+                    },
+                )
+                .build()
+                .unwrap()
+                .remove(0)
+                .0
+        };
+
+        assert_eq!(
+            build(ConstraintKind::Unique),
+            "ALTER TABLE `user` DROP INDEX `user_group`;"
+        );
+        assert_eq!(
+            build(ConstraintKind::ForeignKey),
+            "ALTER TABLE `user` DROP FOREIGN KEY `user_group`;"
+        );
+        assert_eq!(
+            build(ConstraintKind::Check),
+            "ALTER TABLE `user` DROP CHECK `user_group`;"
+        );
+        assert_eq!(
+            build(ConstraintKind::PrimaryKey),
+            "ALTER TABLE `user` DROP PRIMARY KEY;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn drop_constraint_postgres() {
+        for kind in [ConstraintKind::Unique, ConstraintKind::ForeignKey] {
+            assert_eq!(
+                DBImpl::Postgres
+                    .alter_table(
+                        "user",
+                        AlterTableOperation::DropConstraint {
+                            name: "user_group".to_string(),
+                            kind,
+                        },
+                    )
+                    .build()
+                    .unwrap(),
+                [(
+                    "ALTER TABLE \"user\" DROP CONSTRAINT \"user_group\";".to_string(),
+                    vec![]
+                )]
+            );
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn add_column_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddColumn {
+                        operation: DBImpl::SQLite.create_column("user", "age", DbType::Int64, &[]),
+                        position: Some(ColumnPosition::After("name".to_string())),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"user\" ADD COLUMN \"age\" INTEGER ;".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn add_column_mysql() {
+        let add_age = |position| {
+            DBImpl::MySQL
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddColumn {
+                        operation: DBImpl::MySQL.create_column("user", "age", DbType::Int64, &[]),
+                        position,
+                    },
+                )
+                .build()
+                .unwrap()
+                .remove(0)
+                .0
+        };
+
+        assert_eq!(
+            add_age(None),
+            "ALTER TABLE `user` ADD COLUMN `age` BIGINT(255) ;"
+        );
+        assert_eq!(
+            add_age(Some(ColumnPosition::First)),
+            "ALTER TABLE `user` ADD COLUMN `age` BIGINT(255) FIRST;"
+        );
+        assert_eq!(
+            add_age(Some(ColumnPosition::After("name".to_string()))),
+            "ALTER TABLE `user` ADD COLUMN `age` BIGINT(255) AFTER `name`;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn add_column_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .alter_table(
+                    "user",
+                    AlterTableOperation::AddColumn {
+                        operation: DBImpl::Postgres.create_column(
+                            "user",
+                            "age",
+                            DbType::Int64,
+                            &[]
+                        ),
+                        position: Some(ColumnPosition::First),
+                    },
+                )
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"user\" ADD COLUMN \"age\" bigint ;".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn operations_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .alter_table(
+                    "user",
+                    AlterTableOperation::DropColumn {
+                        name: "age".to_string(),
+                    },
+                )
+                .operations(vec![AlterTableOperation::DropColumn {
+                    name: "email".to_string(),
+                }])
+                .build()
+                .unwrap(),
+            [
+                (
+                    "ALTER TABLE \"user\" DROP COLUMN \"age\";".to_string(),
+                    vec![]
+                ),
+                (
+                    "ALTER TABLE \"user\" DROP COLUMN \"email\";".to_string(),
+                    vec![]
+                )
+            ]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn operations_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .alter_table(
+                    "user",
+                    AlterTableOperation::DropDefault {
+                        column_name: "name".to_string(),
+                    },
+                )
+                .operations(vec![
+                    AlterTableOperation::SetDefault {
+                        column_name: "name".to_string(),
+                        value: DEFAULT_NAME,
+                    },
+                    AlterTableOperation::DropColumn {
+                        name: "email".to_string(),
+                    },
+                ])
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE `user` ALTER COLUMN `name` DROP DEFAULT, ALTER COLUMN `name` SET DEFAULT ?, DROP COLUMN `email`;"
+                    .to_string(),
+                vec![Value::String("")]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn operations_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .alter_table(
+                    "user",
+                    AlterTableOperation::DropColumn {
+                        name: "age".to_string(),
+                    },
+                )
+                .operations(vec![AlterTableOperation::DropColumn {
+                    name: "email".to_string(),
+                }])
+                .build()
+                .unwrap(),
+            [(
+                "ALTER TABLE \"user\" DROP COLUMN \"age\", DROP COLUMN \"email\";".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn operations_after_rename_sqlite() {
+        let statements = DBImpl::SQLite
+            .alter_table(
+                "user",
+                AlterTableOperation::RenameTo {
+                    name: "account".to_string(),
+                },
+            )
+            .operations(vec![AlterTableOperation::DropColumn {
+                name: "age".to_string(),
+            }])
+            .build()
+            .unwrap();
+        assert_eq!(
+            statements,
+            [
+                (
+                    "ALTER TABLE \"user\" RENAME TO \"account\";".to_string(),
+                    vec![]
+                ),
+                (
+                    "ALTER TABLE \"account\" DROP COLUMN \"age\";".to_string(),
+                    vec![]
+                )
+            ]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn operations_after_rename_mysql() {
+        let statements = DBImpl::MySQL
+            .alter_table(
+                "user",
+                AlterTableOperation::RenameTo {
+                    name: "account".to_string(),
+                },
+            )
+            .operations(vec![
+                AlterTableOperation::DropColumn {
+                    name: "age".to_string(),
+                },
+                AlterTableOperation::RenameTo {
+                    name: "member".to_string(),
+                },
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(
+            statements,
+            [
+                ("RENAME TABLE `user` TO `account`;".to_string(), vec![]),
+                (
+                    "ALTER TABLE `account` DROP COLUMN `age`;".to_string(),
+                    vec![]
+                ),
+                ("RENAME TABLE `account` TO `member`;".to_string(), vec![])
+            ]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn operations_after_rename_postgres() {
+        let statements = DBImpl::Postgres
+            .alter_table(
+                "user",
+                AlterTableOperation::AddColumn {
+                    operation: DBImpl::Postgres
+                        .create_column("user", "age", DbType::Int64, &[])
+                        .comment("in years"),
+                    position: None,
+                },
+            )
+            .operations(vec![
+                AlterTableOperation::RenameTo {
+                    name: "account".to_string(),
+                },
+                AlterTableOperation::DropColumn {
+                    name: "email".to_string(),
+                },
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(
+            statements,
+            [
+                (
+                    "ALTER TABLE \"user\" ADD COLUMN \"age\" bigint ;".to_string(),
+                    vec![]
+                ),
+                (
+                    "COMMENT ON COLUMN \"user\".\"age\" IS 'in years';".to_string(),
+                    vec![]
+                ),
+                (
+                    "ALTER TABLE \"user\" RENAME TO \"account\";".to_string(),
+                    vec![]
+                ),
+                (
+                    "ALTER TABLE \"account\" DROP COLUMN \"email\";".to_string(),
+                    vec![]
+                )
+            ]
+        );
+    }
 }
@@ -1,11 +1,17 @@
-use std::fmt::{Debug, Error, Write};
+use std::fmt::{self, Debug, Write};
 
+use rorm_declaration::imr::DbType;
+
+use crate::aggregation::SelectAggregator;
 #[cfg(feature = "mysql")]
 use crate::db_specific::mysql;
 #[cfg(feature = "postgres")]
 use crate::db_specific::postgres;
 #[cfg(feature = "sqlite")]
 use crate::db_specific::sqlite;
+use crate::error::Error;
+use crate::ordering::Ordering;
+use crate::select::SelectImpl;
 use crate::value::{NullType, Value};
 use crate::DBImpl;
 
@@ -18,15 +24,16 @@ pub trait BuildCondition<'a>: 'a {
     /**
     This method is used to convert a condition to SQL.
      */
-    fn build(&self, dialect: DBImpl, lookup: &mut Vec<Value<'a>>) -> String {
+    fn build(&self, dialect: DBImpl, lookup: &mut Vec<Value<'a>>) -> Result<String, Error> {
         let mut string = String::new();
-        self.build_to_writer(&mut string, dialect, lookup)
-            .expect("Writing to a string shouldn't fail");
-        string
+        self.build_to_writer(&mut string, dialect, lookup)?;
+        Ok(string)
     }
 
     /**
     This method is used to convert a condition to SQL without allocating a dedicated string.
+
+    Returns an error, if the condition is invalid or uses features not supported by the dialect.
      */
     fn build_to_writer(
         &self,
@@ -45,6 +52,23 @@ pub enum TernaryCondition<'a> {
     Between(Box<[Condition<'a>; 3]>),
     /// Between represents "{} NOT BETWEEN {} AND {}" from SQL
     NotBetween(Box<[Condition<'a>; 3]>),
+    /// Representation of "{} LIKE {} ESCAPE {}" in SQL
+    LikeEscape(Box<[Condition<'a>; 3]>),
+    /// Representation of "{} NOT LIKE {} ESCAPE {}" in SQL
+    NotLikeEscape(Box<[Condition<'a>; 3]>),
+}
+
+impl<'a> TernaryCondition<'a> {
+    /// The operands of the condition
+    #[cfg(feature = "sqlite")]
+    fn operands(&self) -> &[Condition<'a>] {
+        match self {
+            TernaryCondition::Between(params)
+            | TernaryCondition::NotBetween(params)
+            | TernaryCondition::LikeEscape(params)
+            | TernaryCondition::NotLikeEscape(params) => params.as_ref(),
+        }
+    }
 }
 
 impl<'a> BuildCondition<'a> for TernaryCondition<'a> {
@@ -54,15 +78,17 @@ impl<'a> BuildCondition<'a> for TernaryCondition<'a> {
         dialect: DBImpl,
         lookup: &mut Vec<Value<'a>>,
     ) -> Result<(), Error> {
-        let (keyword, [lhs, mhs, rhs]) = match self {
-            TernaryCondition::Between(params) => ("BETWEEN", params.as_ref()),
-            TernaryCondition::NotBetween(params) => ("NOT BETWEEN", params.as_ref()),
+        let (keyword, separator, [lhs, mhs, rhs]) = match self {
+            TernaryCondition::Between(params) => ("BETWEEN", "AND", params.as_ref()),
+            TernaryCondition::NotBetween(params) => ("NOT BETWEEN", "AND", params.as_ref()),
+            TernaryCondition::LikeEscape(params) => ("LIKE", "ESCAPE", params.as_ref()),
+            TernaryCondition::NotLikeEscape(params) => ("NOT LIKE", "ESCAPE", params.as_ref()),
         };
         write!(writer, "(")?;
         lhs.build_to_writer(writer, dialect, lookup)?;
         write!(writer, " {keyword} ")?;
         mhs.build_to_writer(writer, dialect, lookup)?;
-        write!(writer, " AND ")?;
+        write!(writer, " {separator} ")?;
         rhs.build_to_writer(writer, dialect, lookup)?;
         write!(writer, ")")?;
         Ok(())
@@ -90,14 +116,79 @@ pub enum BinaryCondition<'a> {
     Like(Box<[Condition<'a>; 2]>),
     /// Representation of "{} NOT LIKE {}" in SQL
     NotLike(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} ILIKE {}" in SQL
+    ///
+    /// Only postgres supports ILIKE natively.
+    /// For the other dialects, both operands are lowercased and compared using LIKE,
+    /// so the result doesn't depend on the collation of the column.
+    ILike(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} NOT ILIKE {}" in SQL
+    ///
+    /// See [BinaryCondition::ILike] for the emulation on dialects other than postgres.
+    NotILike(Box<[Condition<'a>; 2]>),
     /// Representation of "{} REGEXP {}" in SQL
     Regexp(Box<[Condition<'a>; 2]>),
     /// Representation of "{} NOT REGEXP {}" in SQL
     NotRegexp(Box<[Condition<'a>; 2]>),
     /// Representation of "{} IN {}" in SQL
+    ///
+    /// If the right hand side is an empty [Condition::List], the condition is always false.
     In(Box<[Condition<'a>; 2]>),
     /// Representation of "{} NOT IN {}" in SQL
+    ///
+    /// If the right hand side is an empty [Condition::List], the condition is always true.
     NotIn(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} + {}" in SQL
+    Add(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} - {}" in SQL
+    Subtract(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} * {}" in SQL
+    Multiply(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} / {}" in SQL
+    Divide(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} % {}" in SQL
+    Modulo(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} @> {}" in SQL, the left array contains the right one
+    #[cfg(feature = "postgres-only")]
+    Contains(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} <@ {}" in SQL, the left array is contained by the right one
+    #[cfg(feature = "postgres-only")]
+    ContainedBy(Box<[Condition<'a>; 2]>),
+    /// Representation of "{} && {}" in SQL, the arrays have elements in common
+    #[cfg(feature = "postgres-only")]
+    Overlaps(Box<[Condition<'a>; 2]>),
+}
+
+impl<'a> BinaryCondition<'a> {
+    /// The operands of the condition
+    #[cfg(feature = "sqlite")]
+    fn operands(&self) -> &[Condition<'a>] {
+        match self {
+            BinaryCondition::Equals(params)
+            | BinaryCondition::NotEquals(params)
+            | BinaryCondition::Greater(params)
+            | BinaryCondition::GreaterOrEquals(params)
+            | BinaryCondition::Less(params)
+            | BinaryCondition::LessOrEquals(params)
+            | BinaryCondition::Like(params)
+            | BinaryCondition::NotLike(params)
+            | BinaryCondition::ILike(params)
+            | BinaryCondition::NotILike(params)
+            | BinaryCondition::Regexp(params)
+            | BinaryCondition::NotRegexp(params)
+            | BinaryCondition::In(params)
+            | BinaryCondition::NotIn(params)
+            | BinaryCondition::Add(params)
+            | BinaryCondition::Subtract(params)
+            | BinaryCondition::Multiply(params)
+            | BinaryCondition::Divide(params)
+            | BinaryCondition::Modulo(params) => params.as_ref(),
+            #[cfg(feature = "postgres-only")]
+            BinaryCondition::Contains(params)
+            | BinaryCondition::ContainedBy(params)
+            | BinaryCondition::Overlaps(params) => params.as_ref(),
+        }
+    }
 }
 
 impl<'a> BuildCondition<'a> for BinaryCondition<'a> {
@@ -107,6 +198,17 @@ impl<'a> BuildCondition<'a> for BinaryCondition<'a> {
         dialect: DBImpl,
         lookup: &mut Vec<Value<'a>>,
     ) -> Result<(), Error> {
+        let lowercase = match self {
+            BinaryCondition::ILike(_) | BinaryCondition::NotILike(_) => match dialect {
+                #[cfg(feature = "sqlite")]
+                DBImpl::SQLite => true,
+                #[cfg(feature = "mysql")]
+                DBImpl::MySQL => true,
+                #[cfg(feature = "postgres")]
+                DBImpl::Postgres => false,
+            },
+            _ => false,
+        };
         let (keyword, [lhs, rhs]) = match self {
             BinaryCondition::Equals(params) => ("=", params.as_ref()),
             BinaryCondition::NotEquals(params) => ("<>", params.as_ref()),
@@ -116,16 +218,51 @@ impl<'a> BuildCondition<'a> for BinaryCondition<'a> {
             BinaryCondition::LessOrEquals(params) => ("<=", params.as_ref()),
             BinaryCondition::Like(params) => ("LIKE", params.as_ref()),
             BinaryCondition::NotLike(params) => ("NOT LIKE", params.as_ref()),
+            BinaryCondition::ILike(params) => {
+                (if lowercase { "LIKE" } else { "ILIKE" }, params.as_ref())
+            }
+            BinaryCondition::NotILike(params) => (
+                if lowercase { "NOT LIKE" } else { "NOT ILIKE" },
+                params.as_ref(),
+            ),
             BinaryCondition::Regexp(params) => ("REGEXP", params.as_ref()),
             BinaryCondition::NotRegexp(params) => ("NOT REGEXP", params.as_ref()),
             BinaryCondition::In(params) => ("IN", params.as_ref()),
             BinaryCondition::NotIn(params) => ("NOT IN", params.as_ref()),
+            BinaryCondition::Add(params) => ("+", params.as_ref()),
+            BinaryCondition::Subtract(params) => ("-", params.as_ref()),
+            BinaryCondition::Multiply(params) => ("*", params.as_ref()),
+            BinaryCondition::Divide(params) => ("/", params.as_ref()),
+            BinaryCondition::Modulo(params) => ("%", params.as_ref()),
+            #[cfg(feature = "postgres-only")]
+            BinaryCondition::Contains(params) => ("@>", params.as_ref()),
+            #[cfg(feature = "postgres-only")]
+            BinaryCondition::ContainedBy(params) => ("<@", params.as_ref()),
+            #[cfg(feature = "postgres-only")]
+            BinaryCondition::Overlaps(params) => ("&&", params.as_ref()),
         };
-        write!(writer, "(")?;
+
+        // "{} IN ()" is not valid SQL, so replace it with a constant condition
+        if let Condition::List(values) = rhs {
+            if values.is_empty() {
+                let constant = match self {
+                    BinaryCondition::In(_) => Some("(1 = 0)"),
+                    BinaryCondition::NotIn(_) => Some("(1 = 1)"),
+                    _ => None,
+                };
+                if let Some(constant) = constant {
+                    write!(writer, "{constant}")?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let (prefix, suffix) = if lowercase { ("LOWER(", ")") } else { ("", "") };
+        write!(writer, "({prefix}")?;
         lhs.build_to_writer(writer, dialect, lookup)?;
-        write!(writer, " {keyword} ")?;
+        write!(writer, "{suffix} {keyword} {prefix}")?;
         rhs.build_to_writer(writer, dialect, lookup)?;
-        write!(writer, ")")?;
+        write!(writer, "{suffix})")?;
         Ok(())
     }
 }
@@ -144,9 +281,26 @@ pub enum UnaryCondition<'a> {
     /// Representation of SQL's "NOT EXISTS {}"
     NotExists(Box<Condition<'a>>),
     /// Representation of SQL's "NOT {}"
+    ///
+    /// The negation is wrapped in parentheses as a whole,
+    /// so it binds only to the given condition when combined with AND or OR.
     Not(Box<Condition<'a>>),
 }
 
+impl<'a> UnaryCondition<'a> {
+    /// The operand of the condition
+    #[cfg(feature = "sqlite")]
+    fn operand(&self) -> &Condition<'a> {
+        match self {
+            UnaryCondition::IsNull(value)
+            | UnaryCondition::IsNotNull(value)
+            | UnaryCondition::Exists(value)
+            | UnaryCondition::NotExists(value)
+            | UnaryCondition::Not(value) => value.as_ref(),
+        }
+    }
+}
+
 impl<'a> BuildCondition<'a> for UnaryCondition<'a> {
     fn build_to_writer(
         &self,
@@ -174,6 +328,88 @@ impl<'a> BuildCondition<'a> for UnaryCondition<'a> {
     }
 }
 
+/**
+The quantifiers to compare a value with the rows of a subquery.
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Quantifier {
+    /// Representation of ANY in SQL, the comparison holds for at least one row
+    Any,
+    /// Representation of ALL in SQL, the comparison holds for every row
+    All,
+}
+
+/**
+The string functions available via [Condition::StringFunction].
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StringFunction {
+    /// Concatenates all arguments, SQLite uses the `||` operator instead of CONCAT
+    Concat,
+    /// Substring of the first argument, starting at the 1-based position of the second argument
+    /// with the optional length of the third argument
+    Substr,
+    /// Removes leading and trailing spaces
+    Trim,
+    /// Number of characters, MySQL uses CHAR_LENGTH as its LENGTH counts bytes
+    Length,
+    /// Converts to lower case
+    Lower,
+    /// Converts to upper case
+    Upper,
+}
+
+/**
+The math functions available via [Condition::MathFunction].
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MathFunction {
+    /// Rounds the first argument to the number of decimal places of the optional second argument.
+    ///
+    /// Postgres only supports the precision for `numeric` values.
+    Round,
+    /// Absolute value
+    Abs,
+    /// Smallest integer not less than the argument, requires SQLite 3.35 with math functions
+    Ceil,
+    /// Largest integer not greater than the argument, requires SQLite 3.35 with math functions
+    Floor,
+    /// Remainder of dividing the first by the second argument, SQLite uses the `%` operator
+    Mod,
+}
+
+/**
+Representation of "CASE WHEN {} THEN {} ... ELSE {} END" in SQL.
+
+Used via [Condition::Case], e.g. as selected expression or as operand of a comparison.
+The results are written like [Condition::Value], so all values except identifiers are bound.
+*/
+#[derive(Debug, PartialEq)]
+pub struct CaseExpr<'a> {
+    /// The conditions with the value to return, if the condition is the first one to hold
+    pub branches: Vec<(Condition<'a>, Value<'a>)>,
+    /// The value to return, if no condition holds. Defaults to NULL.
+    pub else_value: Option<Value<'a>>,
+}
+
+/**
+Representation of a call of an aggregate function with an optional FILTER clause in SQL,
+e.g. "COUNT(*) FILTER (WHERE {})".
+
+Used via [Condition::Aggregate], e.g. as selected expression.
+MySQL lacks FILTER, so the filter is emulated by "COUNT(CASE WHEN {} THEN {} END)",
+which ignores the rows not matching the filter as well.
+*/
+#[derive(Debug, PartialEq)]
+pub struct AggregateExpr<'a> {
+    /// The aggregate function to call
+    pub function: SelectAggregator,
+    /// The expression to aggregate, `*` is used if not set
+    pub argument: Option<Condition<'a>>,
+    /// Only aggregate the rows, for which the condition holds
+    pub filter: Option<Condition<'a>>,
+}
+
 /**
 This enum represents a condition tree.
 */
@@ -191,6 +427,78 @@ pub enum Condition<'a> {
     TernaryCondition(TernaryCondition<'a>),
     /// Representation of a value.
     Value(Value<'a>),
+    /// A list of [Condition]s, that get expanded to "({}, {} ...)"
+    List(Vec<Condition<'a>>),
+    /// Representation of a subquery, that gets expanded to "(SELECT ...)"
+    ///
+    /// If the subquery uses features not supported by the dialect,
+    /// [BuildCondition::build_to_writer] returns an error.
+    Subquery(Box<SelectImpl<'a, 'a>>),
+    /// Extraction of the value at a path of a JSON document as text.
+    ///
+    /// The path consists of the keys to follow, starting at the document's root.
+    /// Postgres uses `#>>`, SQLite uses `json_extract` and MySQL uses `JSON_EXTRACT`.
+    JsonExtract(Box<Condition<'a>>, &'a [&'a str]),
+    /// Representation of "{} COLLATE {}" in SQL, compares the expression using the given collation.
+    ///
+    /// The collation is passed through as is, postgres quotes it as identifier.
+    /// Use it on an operand of a comparison, e.g. `NOCASE` for SQLite
+    /// to compare case-insensitively without wrapping the column in LOWER().
+    Collate(Box<Condition<'a>>, &'a str),
+    /// Representation of "COALESCE({}, ...)" in SQL, returns the first argument that isn't NULL.
+    ///
+    /// Building without arguments results in an error.
+    Coalesce(Vec<Condition<'a>>),
+    /// Representation of "NULLIF({}, {})" in SQL, returns NULL if both arguments are equal
+    /// and the first argument otherwise.
+    NullIf(Box<[Condition<'a>; 2]>),
+    /// Representation of "IFNULL({}, {})" in SQL, returns the second argument if the first one is NULL.
+    ///
+    /// Postgres lacks IFNULL, so COALESCE is used instead.
+    IfNull(Box<[Condition<'a>; 2]>),
+    /// Representation of a call of a [StringFunction], e.g. "LOWER({})" in SQL.
+    ///
+    /// The arguments may be columns or bound values.
+    /// Building with the wrong number of arguments for the function results in an error.
+    StringFunction(StringFunction, Vec<Condition<'a>>),
+    /// Representation of a call of a [MathFunction], e.g. "ABS({})" in SQL.
+    ///
+    /// The arguments may be columns or bound values.
+    /// Building with the wrong number of arguments for the function results in an error.
+    MathFunction(MathFunction, Vec<Condition<'a>>),
+    /// Representation of a CASE expression, see [CaseExpr].
+    ///
+    /// Building without branches results in an error.
+    Case(Box<CaseExpr<'a>>),
+    /// Representation of an aggregate function call, see [AggregateExpr].
+    Aggregate(Box<AggregateExpr<'a>>),
+    /// Representation of "CAST({} AS {})" in SQL, converts the expression to the given type.
+    ///
+    /// MySQL casts all integers and booleans to `SIGNED`.
+    /// Types the dialect doesn't support result in an error.
+    Cast(Box<Condition<'a>>, DbType),
+    /// Representation of a quantified subquery, that gets expanded to "ANY (SELECT ...)" or "ALL (SELECT ...)"
+    ///
+    /// Use it as right hand side of a comparison, e.g. [BinaryCondition::Greater]
+    /// to check that a value is greater than every row of the subquery.
+    /// Not supported by SQLite.
+    Quantified(Quantifier, Box<SelectImpl<'a, 'a>>),
+    /// A raw SQL fragment, that gets expanded to "({sql})"
+    ///
+    /// Every `?` outside of quotes is a placeholder for the next entry of `values`,
+    /// it is rewritten to `$n` for postgres.
+    /// Note that this clashes with postgres' `?` operators, use their function equivalents instead.
+    /// If the number of placeholders doesn't match the number of values,
+    /// [BuildCondition::build_to_writer] returns an error.
+    ///
+    /// The fragment is inserted as is, so the caller is responsible for
+    /// not including untrusted input in `sql`. Pass it as one of the `values` instead.
+    Raw {
+        /// The SQL fragment
+        sql: &'a str,
+        /// The values to bind to the placeholders in `sql`
+        values: Vec<Value<'a>>,
+    },
 }
 
 impl<'a> BuildCondition<'a> for Condition<'a> {
@@ -215,19 +523,270 @@ impl<'a> BuildCondition<'a> for Condition<'a> {
                             write!(writer, " {keyword}")?;
                             cond.build_to_writer(writer, dialect, lookup)?;
                         }
-                        Ok(())
+                        Ok::<(), Error>(())
                     })?;
                 }
-                write!(writer, ")")?;
-                Ok(())
+                write!(writer, ")")?
+            }
+            Condition::UnaryCondition(unary) => unary.build_to_writer(writer, dialect, lookup)?,
+            Condition::BinaryCondition(binary) => {
+                binary.build_to_writer(writer, dialect, lookup)?
             }
-            Condition::UnaryCondition(unary) => unary.build_to_writer(writer, dialect, lookup),
-            Condition::BinaryCondition(binary) => binary.build_to_writer(writer, dialect, lookup),
             Condition::TernaryCondition(ternary) => {
-                ternary.build_to_writer(writer, dialect, lookup)
+                ternary.build_to_writer(writer, dialect, lookup)?
+            }
+            Condition::List(conditions) => {
+                write!(writer, "(")?;
+                for (idx, cond) in conditions.iter().enumerate() {
+                    if idx > 0 {
+                        write!(writer, ", ")?;
+                    }
+                    cond.build_to_writer(writer, dialect, lookup)?;
+                }
+                write!(writer, ")")?
+            }
+            Condition::Subquery(select) => {
+                select.check_dialect(dialect)?;
+                let mut s = String::new();
+                select.build_to_writer(&mut s, lookup)?;
+                write!(writer, "({s})")?
+            }
+            Condition::JsonExtract(document, path) => match dialect {
+                #[cfg(feature = "sqlite")]
+                DBImpl::SQLite => {
+                    write!(writer, "json_extract(")?;
+                    document.build_to_writer(writer, dialect, lookup)?;
+                    write!(writer, ", {})", sqlite::fmt(&json_path(path)))?
+                }
+                #[cfg(feature = "mysql")]
+                DBImpl::MySQL => {
+                    write!(writer, "JSON_UNQUOTE(JSON_EXTRACT(")?;
+                    document.build_to_writer(writer, dialect, lookup)?;
+                    write!(writer, ", {}))", mysql::fmt(&json_path(path)))?
+                }
+                #[cfg(feature = "postgres")]
+                DBImpl::Postgres => {
+                    let keys: Vec<_> = path.iter().map(|key| escape_json_key(key)).collect();
+                    write!(writer, "(")?;
+                    document.build_to_writer(writer, dialect, lookup)?;
+                    write!(
+                        writer,
+                        " #>> {})",
+                        postgres::fmt(&format!("{{{}}}", keys.join(",")))
+                    )?
+                }
+            },
+            Condition::Quantified(quantifier, select) => {
+                let keyword = match quantifier {
+                    Quantifier::Any => "ANY",
+                    Quantifier::All => "ALL",
+                };
+                #[cfg(feature = "sqlite")]
+                if matches!(dialect, DBImpl::SQLite) {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support ANY and ALL".to_string(),
+                    ));
+                }
+                select.check_dialect(dialect)?;
+                let mut s = String::new();
+                select.build_to_writer(&mut s, lookup)?;
+                write!(writer, "{keyword} ({s})")?
+            }
+            Condition::Coalesce(arguments) => {
+                if arguments.is_empty() {
+                    return Err(Error::SQLBuildError(
+                        "COALESCE requires at least one argument".to_string(),
+                    ));
+                }
+                write!(writer, "COALESCE(")?;
+                write_arguments(writer, arguments, ", ", dialect, lookup)?;
+                write!(writer, ")")?
+            }
+            Condition::NullIf(arguments) => {
+                write!(writer, "NULLIF(")?;
+                write_arguments(writer, arguments.as_slice(), ", ", dialect, lookup)?;
+                write!(writer, ")")?
+            }
+            Condition::IfNull(arguments) => {
+                match dialect {
+                    #[cfg(feature = "sqlite")]
+                    DBImpl::SQLite => write!(writer, "IFNULL(")?,
+                    #[cfg(feature = "mysql")]
+                    DBImpl::MySQL => write!(writer, "IFNULL(")?,
+                    #[cfg(feature = "postgres")]
+                    DBImpl::Postgres => write!(writer, "COALESCE(")?,
+                };
+                write_arguments(writer, arguments.as_slice(), ", ", dialect, lookup)?;
+                write!(writer, ")")?
+            }
+            Condition::StringFunction(function, arguments) => {
+                let (name, valid) = match function {
+                    StringFunction::Concat => ("CONCAT", !arguments.is_empty()),
+                    StringFunction::Substr => ("SUBSTR", matches!(arguments.len(), 2 | 3)),
+                    StringFunction::Trim => ("TRIM", arguments.len() == 1),
+                    StringFunction::Length => match dialect {
+                        #[cfg(feature = "sqlite")]
+                        DBImpl::SQLite => ("LENGTH", arguments.len() == 1),
+                        #[cfg(feature = "mysql")]
+                        DBImpl::MySQL => ("CHAR_LENGTH", arguments.len() == 1),
+                        #[cfg(feature = "postgres")]
+                        DBImpl::Postgres => ("LENGTH", arguments.len() == 1),
+                    },
+                    StringFunction::Lower => ("LOWER", arguments.len() == 1),
+                    StringFunction::Upper => ("UPPER", arguments.len() == 1),
+                };
+                if !valid {
+                    return Err(Error::SQLBuildError(format!(
+                        "{name} doesn't accept {} arguments",
+                        arguments.len()
+                    )));
+                }
+
+                #[cfg(feature = "sqlite")]
+                if matches!(
+                    (dialect, function),
+                    (DBImpl::SQLite, StringFunction::Concat)
+                ) {
+                    write!(writer, "(")?;
+                    write_arguments(writer, arguments, " || ", dialect, lookup)?;
+                    write!(writer, ")")?;
+                    return Ok(());
+                }
+
+                write!(writer, "{name}(")?;
+                write_arguments(writer, arguments, ", ", dialect, lookup)?;
+                write!(writer, ")")?
+            }
+            Condition::MathFunction(function, arguments) => {
+                let (name, valid) = match function {
+                    MathFunction::Round => ("ROUND", matches!(arguments.len(), 1 | 2)),
+                    MathFunction::Abs => ("ABS", arguments.len() == 1),
+                    MathFunction::Ceil => ("CEIL", arguments.len() == 1),
+                    MathFunction::Floor => ("FLOOR", arguments.len() == 1),
+                    MathFunction::Mod => ("MOD", arguments.len() == 2),
+                };
+                if !valid {
+                    return Err(Error::SQLBuildError(format!(
+                        "{name} doesn't accept {} arguments",
+                        arguments.len()
+                    )));
+                }
+
+                #[cfg(feature = "sqlite")]
+                if matches!((dialect, function), (DBImpl::SQLite, MathFunction::Mod)) {
+                    write!(writer, "(")?;
+                    write_arguments(writer, arguments, " % ", dialect, lookup)?;
+                    write!(writer, ")")?;
+                    return Ok(());
+                }
+
+                write!(writer, "{name}(")?;
+                write_arguments(writer, arguments, ", ", dialect, lookup)?;
+                write!(writer, ")")?
+            }
+            Condition::Case(case) => {
+                if case.branches.is_empty() {
+                    return Err(Error::SQLBuildError(
+                        "CASE requires at least one WHEN branch".to_string(),
+                    ));
+                }
+                write!(writer, "CASE")?;
+                for (condition, value) in &case.branches {
+                    write!(writer, " WHEN ")?;
+                    condition.build_to_writer(writer, dialect, lookup)?;
+                    write!(writer, " THEN ")?;
+                    Condition::Value(*value).build_to_writer(writer, dialect, lookup)?;
+                }
+                if let Some(value) = case.else_value {
+                    write!(writer, " ELSE ")?;
+                    Condition::Value(value).build_to_writer(writer, dialect, lookup)?;
+                }
+                write!(writer, " END")?
+            }
+            Condition::Aggregate(aggregate) => {
+                let function = match aggregate.function {
+                    SelectAggregator::Avg => "AVG",
+                    SelectAggregator::Count => "COUNT",
+                    SelectAggregator::Sum => "SUM",
+                    SelectAggregator::Max => "MAX",
+                    SelectAggregator::Min => "MIN",
+                };
+                write!(writer, "{function}(")?;
+                #[cfg(feature = "mysql")]
+                if let (DBImpl::MySQL, Some(filter)) = (dialect, &aggregate.filter) {
+                    write!(writer, "CASE WHEN ")?;
+                    filter.build_to_writer(writer, dialect, lookup)?;
+                    write!(writer, " THEN ")?;
+                    match &aggregate.argument {
+                        Some(argument) => argument.build_to_writer(writer, dialect, lookup)?,
+                        None => write!(writer, "1")?,
+                    }
+                    write!(writer, " END)")?;
+                    return Ok(());
+                }
+                match &aggregate.argument {
+                    Some(argument) => argument.build_to_writer(writer, dialect, lookup)?,
+                    None => write!(writer, "*")?,
+                }
+                write!(writer, ")")?;
+                if let Some(filter) = &aggregate.filter {
+                    write!(writer, " FILTER (WHERE ")?;
+                    filter.build_to_writer(writer, dialect, lookup)?;
+                    write!(writer, ")")?;
+                }
+            }
+            Condition::Cast(expression, target_type) => {
+                let cast = crate::db_specific::cast_type(dialect, *target_type)?;
+                write!(writer, "CAST(")?;
+                expression.build_to_writer(writer, dialect, lookup)?;
+                write!(writer, " AS {cast})")?
+            }
+            Condition::Collate(expression, collation) => {
+                expression.build_to_writer(writer, dialect, lookup)?;
+                match dialect {
+                    #[cfg(feature = "sqlite")]
+                    DBImpl::SQLite => write!(writer, " COLLATE {collation}")?,
+                    #[cfg(feature = "mysql")]
+                    DBImpl::MySQL => write!(writer, " COLLATE {collation}")?,
+                    #[cfg(feature = "postgres")]
+                    DBImpl::Postgres => write!(
+                        writer,
+                        " COLLATE {}",
+                        DBImpl::Postgres.quote_identifier(collation)
+                    )?,
+                }
+            }
+            Condition::Raw { sql, values } => {
+                let mut placeholders = 0;
+                let mut quote = None;
+                write!(writer, "(")?;
+                for c in sql.chars() {
+                    match quote {
+                        Some(q) if c == q => quote = None,
+                        Some(_) => {}
+                        None if matches!(c, '\'' | '"' | '`') => quote = Some(c),
+                        None if c == '?' => {
+                            if let Some(value) = values.get(placeholders) {
+                                lookup.push(*value);
+                                write_placeholder(writer, dialect, lookup.len())?;
+                            }
+                            placeholders += 1;
+                            continue;
+                        }
+                        None => {}
+                    }
+                    writer.write_char(c)?;
+                }
+                if placeholders != values.len() {
+                    return Err(Error::SQLBuildError(format!(
+                        "Raw SQL has {placeholders} placeholders, but {} values were given",
+                        values.len()
+                    )));
+                }
+                write!(writer, ")")?
             }
             Condition::Value(value) => match value {
-                Value::Ident(string) => write!(writer, "{string}"),
+                Value::Ident(string) => write!(writer, "{string}")?,
                 Value::Column {
                     table_name,
                     column_name,
@@ -237,53 +796,373 @@ impl<'a> BuildCondition<'a> for Condition<'a> {
                         if let Some(table_name) = table_name {
                             write!(writer, "{table_name}.")?;
                         }
-                        write!(writer, "{column_name}")
+                        write!(writer, "{column_name}")?
                     }
                     #[cfg(feature = "mysql")]
                     DBImpl::MySQL => {
                         if let Some(table_name) = table_name {
                             write!(writer, "{table_name}.")?;
                         }
-                        write!(writer, "{column_name}")
+                        write!(writer, "{column_name}")?
                     }
                     #[cfg(feature = "postgres")]
                     DBImpl::Postgres => {
                         if let Some(table_name) = table_name {
                             write!(writer, "\"{table_name}\".")?;
                         }
-                        write!(writer, "{column_name}")
+                        write!(writer, "{column_name}")?
                     }
                 },
                 Value::Choice(c) => match dialect {
                     #[cfg(feature = "sqlite")]
-                    DBImpl::SQLite => write!(writer, "{}", sqlite::fmt(c)),
+                    DBImpl::SQLite => write!(writer, "{}", sqlite::fmt(c))?,
                     #[cfg(feature = "mysql")]
-                    DBImpl::MySQL => write!(writer, "{}", mysql::fmt(c)),
+                    DBImpl::MySQL => write!(writer, "{}", mysql::fmt(c))?,
                     #[cfg(feature = "postgres")]
-                    DBImpl::Postgres => write!(writer, "{}", postgres::fmt(c)),
+                    DBImpl::Postgres => write!(writer, "{}", postgres::fmt(c))?,
+                },
+                Value::Null(NullType::Choice) => write!(writer, "NULL")?,
+                Value::Default => write!(writer, "DEFAULT")?,
+                #[cfg_attr(not(feature = "postgres"), allow(unused_variables))]
+                Value::NextVal(seq) => match dialect {
+                    #[cfg(feature = "sqlite")]
+                    DBImpl::SQLite => {
+                        return Err(Error::UnsupportedFeature(
+                            "SQLite doesn't support sequences".to_string(),
+                        ))
+                    }
+                    #[cfg(feature = "mysql")]
+                    DBImpl::MySQL => {
+                        return Err(Error::UnsupportedFeature(
+                            "MySQL doesn't support sequences".to_string(),
+                        ))
+                    }
+                    #[cfg(feature = "postgres")]
+                    DBImpl::Postgres => write!(writer, "{}", postgres::nextval(seq))?,
                 },
-                Value::Null(NullType::Choice) => write!(writer, "NULL"),
 
                 _ => {
                     lookup.push(*value);
-                    match dialect {
-                        #[cfg(feature = "sqlite")]
-                        DBImpl::SQLite => {
-                            write!(writer, "?")
-                        }
-                        #[cfg(feature = "mysql")]
-                        DBImpl::MySQL => {
-                            write!(writer, "?")
-                        }
-                        #[cfg(feature = "postgres")]
-                        DBImpl::Postgres => {
-                            write!(writer, "${}", lookup.len())
-                        }
-                    }
+                    write_placeholder(writer, dialect, lookup.len())?
                 }
             },
         }
+        Ok(())
+    }
+}
+
+impl<'a> Condition<'a> {
+    /**
+    Checks whether the condition or one of its operands matches the predicate.
+
+    Subqueries are not searched, as they are checked when they are built.
+     */
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn contains(&self, predicate: &impl Fn(&Condition<'a>) -> bool) -> bool {
+        if predicate(self) {
+            return true;
+        }
+        match self {
+            Condition::Conjunction(conditions)
+            | Condition::Disjunction(conditions)
+            | Condition::List(conditions)
+            | Condition::Coalesce(conditions)
+            | Condition::StringFunction(_, conditions)
+            | Condition::MathFunction(_, conditions) => {
+                conditions.iter().any(|c| c.contains(predicate))
+            }
+            Condition::NullIf(conditions) | Condition::IfNull(conditions) => {
+                conditions.iter().any(|c| c.contains(predicate))
+            }
+            Condition::UnaryCondition(unary) => unary.operand().contains(predicate),
+            Condition::BinaryCondition(binary) => {
+                binary.operands().iter().any(|c| c.contains(predicate))
+            }
+            Condition::TernaryCondition(ternary) => {
+                ternary.operands().iter().any(|c| c.contains(predicate))
+            }
+            Condition::JsonExtract(condition, _)
+            | Condition::Collate(condition, _)
+            | Condition::Cast(condition, _) => condition.contains(predicate),
+            Condition::Case(case) => {
+                case.branches.iter().any(|(condition, value)| {
+                    condition.contains(predicate) || predicate(&Condition::Value(*value))
+                }) || case
+                    .else_value
+                    .is_some_and(|value| predicate(&Condition::Value(value)))
+            }
+            Condition::Aggregate(aggregate) => aggregate
+                .argument
+                .iter()
+                .chain(&aggregate.filter)
+                .any(|c| c.contains(predicate)),
+            Condition::Value(_)
+            | Condition::Subquery(_)
+            | Condition::Quantified(_, _)
+            | Condition::Raw { .. } => false,
+        }
+    }
+
+    /**
+    Returns an error, if the condition uses features not supported by SQLite.
+     */
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn check_sqlite(&self) -> Result<(), Error> {
+        if self.contains(&|c| matches!(c, Condition::Quantified(_, _))) {
+            return Err(Error::UnsupportedFeature(
+                "SQLite doesn't support ANY and ALL".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /**
+    Build the condition with all values written as literals instead of placeholders.
+
+    This is required for statements which don't support bind parameters, like CHECK constraints.
+    Returns an error for values which have no literal representation.
+     */
+    pub(crate) fn build_inlined(&self, dialect: DBImpl) -> Result<String, Error> {
+        let mut lookup = vec![];
+        let sql = self.build(dialect, &mut lookup)?;
+        crate::query::inline_values(&sql, &lookup, dialect)
+    }
+}
+
+/// Write the placeholder for the value at the (1-based) `index` of the lookup
+#[cfg_attr(not(feature = "postgres"), allow(unused_variables))]
+pub(crate) fn write_placeholder(
+    writer: &mut impl Write,
+    dialect: DBImpl,
+    index: usize,
+) -> fmt::Result {
+    match dialect {
+        #[cfg(feature = "sqlite")]
+        DBImpl::SQLite => write!(writer, "?"),
+        #[cfg(feature = "mysql")]
+        DBImpl::MySQL => write!(writer, "?"),
+        #[cfg(feature = "postgres")]
+        DBImpl::Postgres => write!(writer, "${index}"),
+    }
+}
+
+/**
+A group of conditions added to a WHERE clause.
+*/
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum WhereGroup<'until_build, 'post_query> {
+    /// The condition is combined with the previous groups using AND
+    And(&'until_build Condition<'post_query>),
+    /// The condition is combined with the previous groups using OR
+    Or(&'until_build Condition<'post_query>),
+    /// All of the conditions have to be met
+    All(&'until_build [Condition<'post_query>]),
+    /// Any of the conditions has to be met
+    Any(&'until_build [Condition<'post_query>]),
+    /// Only rows after the given key values, see [crate::select::Select::paginate_after]
+    Keyset(&'until_build [(&'until_build str, Value<'post_query>, Ordering)]),
+}
+
+/**
+Build the condition of a WHERE clause by folding the groups in the order they were added.
+
+Consecutive groups with the same operator are chained without additional parentheses,
+the chain so far is wrapped in parentheses when the operator changes.
+The operator of the first group is ignored.
+Returns `None`, if there are no groups.
+ */
+pub(crate) fn build_where<'post_query>(
+    groups: &[WhereGroup<'_, 'post_query>],
+    dialect: DBImpl,
+    lookup: &mut Vec<Value<'post_query>>,
+) -> Result<Option<String>, Error> {
+    if groups.is_empty() {
+        return Ok(None);
+    }
+
+    let mut build_condition = |condition: &Condition<'post_query>| {
+        #[cfg(feature = "sqlite")]
+        if matches!(dialect, DBImpl::SQLite) {
+            condition.check_sqlite()?;
+        }
+        condition.build(dialect, lookup)
+    };
+
+    let mut s = String::new();
+    let mut previous_operator = None;
+    for (idx, group) in groups.iter().enumerate() {
+        let (operator, term) = match group {
+            WhereGroup::And(condition) => (" AND ", build_condition(condition)?),
+            WhereGroup::Or(condition) => (" OR ", build_condition(condition)?),
+            WhereGroup::All(conditions) | WhereGroup::Any(conditions) => {
+                let keyword = if matches!(group, WhereGroup::All(_)) {
+                    " AND "
+                } else {
+                    " OR "
+                };
+                let mut term = String::new();
+                for (idx, condition) in conditions.iter().enumerate() {
+                    if idx > 0 {
+                        term.push_str(keyword);
+                    }
+                    term.push_str(&build_condition(condition)?);
+                }
+                if conditions.len() > 1 {
+                    term = format!("({term})");
+                }
+                (" AND ", term)
+            }
+            WhereGroup::Keyset(keys) => {
+                (" AND ", build_keyset(keys, dialect, &mut build_condition)?)
+            }
+        };
+
+        if idx > 0 {
+            if previous_operator.is_some_and(|previous| previous != operator) {
+                s = format!("({s})");
+            }
+            s.push_str(operator);
+            previous_operator = Some(operator);
+        }
+        s.push_str(&term);
+    }
+    if groups.len() > 1 {
+        s = format!("({s})");
     }
+
+    Ok(Some(s))
+}
+
+/**
+Build the condition of a keyset pagination, matching the rows after the given key values.
+
+The columns are quoted like in the ORDER BY clause added by
+[Select::paginate_after](crate::select::Select::paginate_after),
+so both refer to the same columns.
+ */
+fn build_keyset<'post_query>(
+    keys: &[(&str, Value<'post_query>, Ordering)],
+    dialect: DBImpl,
+    build_condition: &mut impl FnMut(&Condition<'post_query>) -> Result<String, Error>,
+) -> Result<String, Error> {
+    let operator = |ordering: &Ordering| match ordering {
+        Ordering::Asc => ">",
+        Ordering::Desc => "<",
+    };
+    let keys: Vec<_> = keys
+        .iter()
+        .map(|(column, value, ordering)| {
+            let column = match dialect {
+                #[cfg(feature = "postgres")]
+                DBImpl::Postgres => format!("\"{column}\""),
+                #[allow(unreachable_patterns)]
+                _ => column.to_string(),
+            };
+            (column, *value, *ordering)
+        })
+        .collect();
+
+    if keys.len() > 1 && keys.iter().all(|(_, _, ordering)| *ordering == keys[0].2) {
+        let columns: Vec<_> = keys.iter().map(|(column, _, _)| column.as_str()).collect();
+        let mut values = Vec::with_capacity(keys.len());
+        for (_, value, _) in &keys {
+            values.push(build_condition(&Condition::Value(*value))?);
+        }
+        return Ok(format!(
+            "(({}) {} ({}))",
+            columns.join(", "),
+            operator(&keys[0].2),
+            values.join(", ")
+        ));
+    }
+
+    let mut alternatives = Vec::with_capacity(keys.len());
+    for (idx, (column, value, ordering)) in keys.iter().enumerate() {
+        let mut terms = Vec::with_capacity(idx + 1);
+        for (previous_column, previous_value, _) in &keys[..idx] {
+            terms.push(format!(
+                "({previous_column} = {})",
+                build_condition(&Condition::Value(*previous_value))?
+            ));
+        }
+        terms.push(format!(
+            "({column} {} {})",
+            operator(ordering),
+            build_condition(&Condition::Value(*value))?
+        ));
+        alternatives.push(if terms.len() > 1 {
+            format!("({})", terms.join(" AND "))
+        } else {
+            terms.remove(0)
+        });
+    }
+    Ok(if alternatives.len() > 1 {
+        format!("({})", alternatives.join(" OR "))
+    } else {
+        alternatives.remove(0)
+    })
+}
+
+/**
+Escapes the wildcards `%` and `_` as well as the escape character itself in `input`,
+so it is matched literally by a LIKE pattern.
+
+Combine the result with [TernaryCondition::LikeEscape] using the same `escape_char`.
+
+**Parameter**:
+- `input`: Text to match literally, e.g. entered by a user.
+- `escape_char`: Character to put in front of every special character.
+
+**Usage**:
+
+```
+use rorm_sql::conditional::escape_like_pattern;
+
+assert_eq!(escape_like_pattern("100%_!", '!'), "100!%!_!!");
+```
+ */
+pub fn escape_like_pattern(input: &str, escape_char: char) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if c == '%' || c == '_' || c == escape_char {
+            escaped.push(escape_char);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Write the arguments of a function separated by `separator`
+fn write_arguments<'a>(
+    writer: &mut impl Write,
+    arguments: &[Condition<'a>],
+    separator: &str,
+    dialect: DBImpl,
+    lookup: &mut Vec<Value<'a>>,
+) -> Result<(), Error> {
+    for (idx, argument) in arguments.iter().enumerate() {
+        if idx > 0 {
+            write!(writer, "{separator}")?;
+        }
+        argument.build_to_writer(writer, dialect, lookup)?;
+    }
+    Ok(())
+}
+
+/// Quotes a key of a JSON path, so it can contain arbitrary characters
+fn escape_json_key(key: &str) -> String {
+    format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Converts a list of keys to a JSON path as used by SQLite and MySQL
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
+fn json_path(path: &[&str]) -> String {
+    let mut json_path = String::from("$");
+    for key in path {
+        json_path.push('.');
+        json_path.push_str(&escape_json_key(key));
+    }
+    json_path
 }
 
 /**
@@ -372,8 +1251,14 @@ macro_rules! or {
 
 #[cfg(test)]
 mod test {
-    use crate::conditional::Condition;
+    use crate::conditional::{
+        escape_like_pattern, BinaryCondition, BuildCondition, Condition, TernaryCondition,
+        UnaryCondition,
+    };
+    #[cfg(feature = "mysql")]
+    use crate::select::Select;
     use crate::value::Value;
+    use crate::DBImpl;
 
     #[test]
     fn empty_and() {
@@ -426,4 +1311,646 @@ mod test {
             ])
         );
     }
+
+    fn in_list(values: Vec<Condition>, negated: bool) -> Condition {
+        let params = Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::List(values),
+        ]);
+        Condition::BinaryCondition(if negated {
+            BinaryCondition::NotIn(params)
+        } else {
+            BinaryCondition::In(params)
+        })
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn in_list_sqlite() {
+        let condition = in_list(
+            vec![
+                Condition::Value(Value::I64(1)),
+                Condition::Value(Value::I64(2)),
+                Condition::Value(Value::I64(3)),
+            ],
+            false,
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "(id IN (?, ?, ?))"
+        );
+        assert_eq!(lookup, vec![Value::I64(1), Value::I64(2), Value::I64(3)]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn not_in_list_postgres() {
+        let condition = in_list(
+            vec![
+                Condition::Value(Value::String("foo")),
+                Condition::Value(Value::String("bar")),
+            ],
+            true,
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "(id NOT IN ($1, $2))"
+        );
+        assert_eq!(lookup, vec![Value::String("foo"), Value::String("bar")]);
+    }
+
+    #[test]
+    fn in_empty_list() {
+        let dialects = [
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite,
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL,
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres,
+        ];
+        for dialect in dialects {
+            let mut lookup = vec![];
+            assert_eq!(
+                in_list(vec![], false).build(dialect, &mut lookup).unwrap(),
+                "(1 = 0)"
+            );
+            assert_eq!(
+                in_list(vec![], true).build(dialect, &mut lookup).unwrap(),
+                "(1 = 1)"
+            );
+            assert!(lookup.is_empty());
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn in_subquery_postgres() {
+        use crate::select::Select;
+
+        let where_clause = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("banned")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "user_id", None, None)];
+        let subquery = DBImpl::Postgres
+            .select(&columns, "ban", &[], &[])
+            .where_clause(&where_clause);
+        let condition = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("active")),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::In(Box::new([
+                Condition::Value(Value::Ident("id")),
+                Condition::Subquery(Box::new(subquery)),
+            ]))),
+        ]);
+
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "((active = $1) AND (id IN (SELECT \"user_id\" FROM \"ban\" WHERE (banned = $2))))"
+        );
+        assert_eq!(lookup, vec![Value::Bool(true), Value::Bool(true)]);
+    }
+
+    #[cfg(all(feature = "mysql", feature = "postgres"))]
+    #[test]
+    fn in_subquery_other_dialect() {
+        use crate::error::Error;
+
+        let columns = [DBImpl::Postgres.select_column(None, "user_id", None, None)];
+        let condition = Condition::BinaryCondition(BinaryCondition::In(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Subquery(Box::new(DBImpl::Postgres.select(&columns, "ban", &[], &[]))),
+        ])));
+
+        assert!(matches!(
+            condition.build(DBImpl::MySQL, &mut vec![]),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    fn between(negated: bool) -> Condition<'static> {
+        let params = Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+            Condition::Value(Value::I64(67)),
+        ]);
+        Condition::TernaryCondition(if negated {
+            TernaryCondition::NotBetween(params)
+        } else {
+            TernaryCondition::Between(params)
+        })
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn not_between_sqlite() {
+        let mut lookup = vec![];
+        assert_eq!(
+            between(true).build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "(age NOT BETWEEN ? AND ?)"
+        );
+        assert_eq!(lookup, vec![Value::I64(18), Value::I64(67)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn between_mysql() {
+        let condition = and!(
+            between(false),
+            or!(
+                Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                    Condition::Value(Value::Ident("name")),
+                    Condition::Value(Value::String("foo")),
+                ]))),
+                between(true),
+            ),
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::MySQL, &mut lookup).unwrap(),
+            "((age BETWEEN ? AND ?) AND ((name = ?) OR (age NOT BETWEEN ? AND ?)))"
+        );
+        assert_eq!(
+            lookup,
+            vec![
+                Value::I64(18),
+                Value::I64(67),
+                Value::String("foo"),
+                Value::I64(18),
+                Value::I64(67)
+            ]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn between_postgres() {
+        let condition = and!(
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("name")),
+                Condition::Value(Value::String("foo")),
+            ]))),
+            between(false),
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "((name = $1) AND (age BETWEEN $2 AND $3))"
+        );
+        assert_eq!(
+            lookup,
+            vec![Value::String("foo"), Value::I64(18), Value::I64(67)]
+        );
+    }
+
+    fn ilike(negated: bool) -> Condition<'static> {
+        let params = Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("%foo%")),
+        ]);
+        Condition::BinaryCondition(if negated {
+            BinaryCondition::NotILike(params)
+        } else {
+            BinaryCondition::ILike(params)
+        })
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn ilike_sqlite() {
+        let mut lookup = vec![];
+        assert_eq!(
+            ilike(false).build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "(LOWER(name) LIKE LOWER(?))"
+        );
+        assert_eq!(lookup, vec![Value::String("%foo%")]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn ilike_mysql() {
+        let mut lookup = vec![];
+        assert_eq!(
+            ilike(true).build(DBImpl::MySQL, &mut lookup).unwrap(),
+            "(LOWER(name) NOT LIKE LOWER(?))"
+        );
+        assert_eq!(lookup, vec![Value::String("%foo%")]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn ilike_postgres() {
+        let mut lookup = vec![];
+        assert_eq!(
+            ilike(false).build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "(name ILIKE $1)"
+        );
+        assert_eq!(
+            ilike(true).build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "(name NOT ILIKE $2)"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn like_escape_postgres() {
+        let condition = Condition::TernaryCondition(TernaryCondition::LikeEscape(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("100!%%")),
+            Condition::Value(Value::String("!")),
+        ])));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "(name LIKE $1 ESCAPE $2)"
+        );
+        assert_eq!(lookup, vec![Value::String("100!%%"), Value::String("!")]);
+    }
+
+    #[test]
+    fn escape_like() {
+        assert_eq!(
+            escape_like_pattern("50% off_sale\\", '\\'),
+            "50\\% off\\_sale\\\\"
+        );
+        assert_eq!(escape_like_pattern("a!b%c_d", '!'), "a!!b!%c!_d");
+        assert_eq!(escape_like_pattern("plain", '!'), "plain");
+    }
+
+    fn null_check<'a>(is_null: Condition<'a>) -> Condition<'a> {
+        and!(
+            Condition::UnaryCondition(UnaryCondition::IsNull(Box::new(is_null))),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("name")),
+                Condition::Value(Value::String("foo")),
+            ]))),
+            Condition::UnaryCondition(UnaryCondition::IsNotNull(Box::new(Condition::Value(
+                Value::Ident("created_at")
+            )))),
+        )
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn is_null_sqlite() {
+        let mut lookup = vec![];
+        assert_eq!(
+            null_check(Condition::Value(Value::Ident("deleted_at")))
+                .build(DBImpl::SQLite, &mut lookup)
+                .unwrap(),
+            "((deleted_at IS NULL) AND (name = ?) AND (created_at IS NOT NULL))"
+        );
+        assert_eq!(lookup, vec![Value::String("foo")]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn is_null_mysql() {
+        let mut lookup = vec![];
+        assert_eq!(
+            null_check(Condition::Value(Value::Ident("deleted_at")))
+                .build(DBImpl::MySQL, &mut lookup)
+                .unwrap(),
+            "((deleted_at IS NULL) AND (name = ?) AND (created_at IS NOT NULL))"
+        );
+        assert_eq!(lookup, vec![Value::String("foo")]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn is_null_postgres() {
+        let mut lookup = vec![];
+        let column = Condition::Value(Value::Column {
+            table_name: Some("user"),
+            column_name: "deleted_at",
+        });
+        assert_eq!(
+            null_check(column)
+                .build(DBImpl::Postgres, &mut lookup)
+                .unwrap(),
+            "((\"user\".deleted_at IS NULL) AND (name = $1) AND (created_at IS NOT NULL))"
+        );
+        assert_eq!(lookup, vec![Value::String("foo")]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn not_sqlite() {
+        let condition = Condition::UnaryCondition(UnaryCondition::Not(Box::new(
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("age")),
+                Condition::Value(Value::I64(18)),
+            ]))),
+        )));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "(NOT (age = ?))"
+        );
+        assert_eq!(lookup, vec![Value::I64(18)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn not_mysql() {
+        let condition = Condition::UnaryCondition(UnaryCondition::Not(Box::new(Condition::Value(
+            Value::Ident("active"),
+        ))));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::MySQL, &mut lookup).unwrap(),
+            "(NOT active)"
+        );
+        assert!(lookup.is_empty());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn not_postgres() {
+        let equals = |column, value| {
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident(column)),
+                Condition::Value(value),
+            ])))
+        };
+        let condition = or!(
+            Condition::UnaryCondition(UnaryCondition::Not(Box::new(equals("age", Value::I64(18))))),
+            Condition::UnaryCondition(UnaryCondition::Not(Box::new(and!(
+                equals("name", Value::String("foo")),
+                equals("active", Value::Bool(true)),
+            )))),
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "((NOT (age = $1)) OR (NOT ((name = $2) AND (active = $3))))"
+        );
+        assert_eq!(
+            lookup,
+            vec![Value::I64(18), Value::String("foo"), Value::Bool(true)]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn raw_sqlite() {
+        let condition = and!(
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("active")),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+            Condition::Raw {
+                sql: "lower(name) = ? OR nickname = '?'",
+                values: vec![Value::String("foo")],
+            },
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "((active = ?) AND (lower(name) = ? OR nickname = '?'))"
+        );
+        assert_eq!(lookup, vec![Value::Bool(true), Value::String("foo")]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn raw_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+        let error = |values| {
+            let condition = Condition::Raw {
+                sql: "lower(name) = ? OR nickname = '?'",
+                values,
+            };
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(vec![]),
+            "sql build error: Raw SQL has 1 placeholders, but 0 values were given"
+        );
+        assert_eq!(
+            error(vec![Value::String("foo"), Value::String("bar")]),
+            "sql build error: Raw SQL has 1 placeholders, but 2 values were given"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn raw_postgres() {
+        let condition = and!(
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("active")),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+            Condition::Raw {
+                sql: "lower(name) = ? OR nickname = '?'",
+                values: vec![Value::String("foo")],
+            },
+            Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                Condition::Value(Value::Ident("age")),
+                Condition::Value(Value::I64(18)),
+            ]))),
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "((active = $1) AND (lower(name) = $2 OR nickname = '?') AND (age > $3))"
+        );
+        assert_eq!(
+            lookup,
+            vec![Value::Bool(true), Value::String("foo"), Value::I64(18)]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn json_sqlite() {
+        let settings = serde_json::json!({"theme": "dark"});
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("settings")),
+            Condition::Value(Value::JsonValue(&settings)),
+        ])));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "(settings = ?)"
+        );
+        assert_eq!(lookup, vec![Value::JsonValue(&settings)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn json_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::JsonExtract(
+                Box::new(Condition::Value(Value::Ident("data"))),
+                &["address", "city"],
+            ),
+            Condition::Value(Value::String("Berlin")),
+        ])));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::MySQL, &mut lookup).unwrap(),
+            "(JSON_UNQUOTE(JSON_EXTRACT(data, '$.\"address\".\"city\"')) = ?)"
+        );
+        assert_eq!(lookup, vec![Value::String("Berlin")]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn json_postgres() {
+        let settings = serde_json::json!({"theme": "dark"});
+        let condition = and!(
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::JsonExtract(
+                    Box::new(Condition::Value(Value::Ident("data"))),
+                    &["address", "city"],
+                ),
+                Condition::Value(Value::String("Berlin")),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("settings")),
+                Condition::Value(Value::JsonValue(&settings)),
+            ]))),
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "(((data #>> '{\"address\",\"city\"}') = $1) AND (settings = $2))"
+        );
+        assert_eq!(
+            lookup,
+            vec![Value::String("Berlin"), Value::JsonValue(&settings)]
+        );
+    }
+
+    #[cfg(feature = "postgres-only")]
+    #[test]
+    fn array_contains_postgres() {
+        let tags = [Value::String("rust"), Value::String("sql")];
+        let condition = Condition::Disjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Contains(Box::new([
+                Condition::Value(Value::Ident("tags")),
+                Condition::Value(Value::Array(&tags)),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::ContainedBy(Box::new([
+                Condition::Value(Value::Ident("tags")),
+                Condition::Value(Value::Array(&tags)),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Overlaps(Box::new([
+                Condition::Value(Value::Ident("tags")),
+                Condition::Value(Value::Array(&tags)),
+            ]))),
+        ]);
+
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "((tags @> $1) OR (tags <@ $2) OR (tags && $3))"
+        );
+        assert_eq!(lookup, vec![Value::Array(&tags); 3]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn temporal_sqlite() {
+        use chrono::NaiveDate;
+
+        let birthday = Value::ChronoNaiveDate(NaiveDate::from_ymd_opt(2023, 1, 2).unwrap());
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("birthday")),
+            Condition::Value(birthday),
+        ])));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::SQLite, &mut lookup).unwrap(),
+            "(birthday = ?)"
+        );
+        assert_eq!(lookup, vec![birthday]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn temporal_mysql() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let opens = Value::ChronoNaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap());
+        let updated_at = Value::ChronoNaiveDateTime(
+            NaiveDate::from_ymd_opt(2023, 1, 2)
+                .unwrap()
+                .and_hms_opt(8, 30, 0)
+                .unwrap(),
+        );
+        let condition = and!(
+            Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+                Condition::Value(Value::Ident("opens")),
+                Condition::Value(opens),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::LessOrEquals(Box::new([
+                Condition::Value(Value::Ident("updated_at")),
+                Condition::Value(updated_at),
+            ]))),
+        );
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::MySQL, &mut lookup).unwrap(),
+            "((opens < ?) AND (updated_at <= ?))"
+        );
+        assert_eq!(lookup, vec![opens, updated_at]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn temporal_postgres() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let created_at = Value::ChronoDateTime(
+            Utc.from_utc_datetime(
+                &NaiveDate::from_ymd_opt(2023, 1, 2)
+                    .unwrap()
+                    .and_hms_opt(8, 30, 0)
+                    .unwrap(),
+            ),
+        );
+        let condition = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("created_at")),
+            Condition::Value(created_at),
+        ])));
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "(created_at >= $1)"
+        );
+        assert_eq!(lookup, vec![created_at]);
+    }
+
+    #[cfg(feature = "postgres-only")]
+    #[test]
+    fn interval_postgres() {
+        let interval = Value::ChronoDuration(chrono::Duration::days(7));
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::BinaryCondition(BinaryCondition::Subtract(Box::new([
+                Condition::Value(Value::Ident("due_at")),
+                Condition::Value(Value::Ident("created_at")),
+            ]))),
+            Condition::Value(interval),
+        ])));
+
+        let mut lookup = vec![];
+        assert_eq!(
+            condition.build(DBImpl::Postgres, &mut lookup).unwrap(),
+            "((due_at - created_at) > $1)"
+        );
+        assert_eq!(lookup, vec![interval]);
+    }
 }
@@ -2,6 +2,7 @@ use std::fmt::Write;
 
 use rorm_declaration::imr::DefaultValue;
 
+use crate::conditional::Condition;
 #[cfg(feature = "postgres")]
 use crate::create_trigger::trigger_annotation_to_trigger_postgres;
 #[cfg(feature = "sqlite")]
@@ -13,7 +14,10 @@ use crate::db_specific::postgres;
 #[cfg(feature = "sqlite")]
 use crate::db_specific::sqlite;
 use crate::error::Error;
-use crate::{Annotation, DbType, Value};
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use crate::table_ref::TableRef;
+use crate::value::bool_literal;
+use crate::{Annotation, DBImpl, DbType, Value};
 
 /**
 Trait representing the create table builder.
@@ -43,9 +47,15 @@ Representation of the data of the creation of a column for the sqlite dialect
 #[cfg(feature = "sqlite")]
 pub struct CreateColumnSQLiteData<'until_build, 'post_build> {
     pub(crate) name: &'until_build str,
-    pub(crate) table_name: &'until_build str,
+    pub(crate) table_name: TableRef<'until_build>,
     pub(crate) data_type: DbType,
     pub(crate) annotations: Vec<SQLAnnotation<'post_build>>,
+    pub(crate) collation: Option<&'until_build str>,
+    pub(crate) generated: Option<(&'until_build str, bool)>,
+    pub(crate) check: Option<&'until_build Condition<'post_build>>,
+    pub(crate) comment: Option<&'until_build str>,
+    pub(crate) deferrable: Option<bool>,
+    pub(crate) default: Option<Value<'post_build>>,
     pub(crate) statements: Option<&'until_build mut Vec<(String, Vec<Value<'post_build>>)>>,
     pub(crate) lookup: Option<&'until_build mut Vec<Value<'post_build>>>,
 }
@@ -59,6 +69,12 @@ pub struct CreateColumnMySQLData<'until_build, 'post_build> {
     pub(crate) name: &'until_build str,
     pub(crate) data_type: DbType,
     pub(crate) annotations: Vec<SQLAnnotation<'post_build>>,
+    pub(crate) collation: Option<&'until_build str>,
+    pub(crate) generated: Option<(&'until_build str, bool)>,
+    pub(crate) check: Option<&'until_build Condition<'post_build>>,
+    pub(crate) comment: Option<&'until_build str>,
+    pub(crate) deferrable: Option<bool>,
+    pub(crate) default: Option<Value<'post_build>>,
     pub(crate) statements: Option<&'until_build mut Vec<(String, Vec<Value<'post_build>>)>>,
     pub(crate) lookup: Option<&'until_build mut Vec<Value<'post_build>>>,
 }
@@ -70,9 +86,15 @@ Representation of the data of the creation of a column for the mysql dialect
 #[cfg(feature = "postgres")]
 pub struct CreateColumnPostgresData<'until_build, 'post_build> {
     pub(crate) name: &'until_build str,
-    pub(crate) table_name: &'until_build str,
+    pub(crate) table_name: TableRef<'until_build>,
     pub(crate) data_type: DbType,
     pub(crate) annotations: Vec<SQLAnnotation<'post_build>>,
+    pub(crate) collation: Option<&'until_build str>,
+    pub(crate) generated: Option<(&'until_build str, bool)>,
+    pub(crate) check: Option<&'until_build Condition<'post_build>>,
+    pub(crate) comment: Option<&'until_build str>,
+    pub(crate) deferrable: Option<bool>,
+    pub(crate) default: Option<Value<'post_build>>,
     pub(crate) pre_statements: Option<&'until_build mut Vec<(String, Vec<Value<'post_build>>)>>,
     pub(crate) statements: Option<&'until_build mut Vec<(String, Vec<Value<'post_build>>)>>,
 }
@@ -101,6 +123,148 @@ pub enum CreateColumnImpl<'until_build, 'post_build> {
     Postgres(CreateColumnPostgresData<'until_build, 'post_build>),
 }
 
+impl<'until_build, 'post_build> CreateColumnImpl<'until_build, 'post_build> {
+    /**
+    Set the collation of the column.
+
+    The collation is passed through as is, Postgres quotes it as identifier.
+
+    **Parameter**:
+    - `collation`: Name of the collation, e.g. `NOCASE` for SQLite
+     */
+    pub fn collate(mut self, collation: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(ref mut d) => d.collation = Some(collation),
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(ref mut d) => d.collation = Some(collation),
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(ref mut d) => d.collation = Some(collation),
+        }
+        self
+    }
+
+    /**
+    Make the column a generated column, computed from other columns of the row.
+
+    Postgres only supports stored generated columns.
+
+    **Parameter**:
+    - `expression`: Expression to compute the column with
+    - `stored`: Whether the values are stored or computed when read
+     */
+    pub fn generated(mut self, expression: &'until_build str, stored: bool) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(ref mut d) => d.generated = Some((expression, stored)),
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(ref mut d) => d.generated = Some((expression, stored)),
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(ref mut d) => d.generated = Some((expression, stored)),
+        }
+        self
+    }
+
+    /**
+    Add a CHECK constraint to the column.
+
+    The values of the condition are written as literals, as bind parameters
+    are not supported in constraints.
+
+    **Parameter**:
+    - `condition`: Condition every value of the column has to fulfill
+     */
+    pub fn check(mut self, condition: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(ref mut d) => d.check = Some(condition),
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(ref mut d) => d.check = Some(condition),
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(ref mut d) => d.check = Some(condition),
+        }
+        self
+    }
+
+    /**
+    Attach a comment to the column.
+
+    MySQL writes the comment inline, Postgres adds a `COMMENT ON COLUMN` statement
+    after the table is created. SQLite doesn't support comments and ignores it.
+
+    **Parameter**:
+    - `comment`: Comment describing the column
+     */
+    pub fn comment(mut self, comment: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(ref mut d) => d.comment = Some(comment),
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(ref mut d) => d.comment = Some(comment),
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(ref mut d) => d.comment = Some(comment),
+        }
+        self
+    }
+
+    /**
+    Make the foreign key of the column deferrable, so it's checked at the end of the transaction.
+
+    Requires a foreign key annotation, MySQL doesn't support deferrable constraints.
+
+    **Parameter**:
+    - `initially_deferred`: Whether the check is deferred by default instead of on demand
+     */
+    pub fn deferrable(mut self, initially_deferred: bool) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(ref mut d) => d.deferrable = Some(initially_deferred),
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(ref mut d) => d.deferrable = Some(initially_deferred),
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(ref mut d) => d.deferrable = Some(initially_deferred),
+        }
+        self
+    }
+
+    /**
+    Set the default value of the column.
+
+    The value is written as escaped literal, see [Value::to_sql_literal],
+    as bind parameters are not supported in column definitions.
+    Building returns an error, if the column has a default value annotation as well.
+
+    **Parameter**:
+    - `value`: The default value of the column
+     */
+    pub fn default_value(mut self, value: Value<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(ref mut d) => d.default = Some(value),
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(ref mut d) => d.default = Some(value),
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(ref mut d) => d.default = Some(value),
+        }
+        self
+    }
+
+    /// Whether the column is annotated as primary key
+    pub(crate) fn is_primary_key(&self) -> bool {
+        let annotations = match self {
+            #[cfg(feature = "sqlite")]
+            CreateColumnImpl::SQLite(d) => &d.annotations,
+            #[cfg(feature = "mysql")]
+            CreateColumnImpl::MySQL(d) => &d.annotations,
+            #[cfg(feature = "postgres")]
+            CreateColumnImpl::Postgres(d) => &d.annotations,
+        };
+        annotations
+            .iter()
+            .any(|x| x.annotation.eq_shallow(&Annotation::PrimaryKey))
+    }
+}
+
 impl<'until_build, 'post_build> CreateColumn<'post_build>
     for CreateColumnImpl<'until_build, 'post_build>
 {
@@ -108,31 +272,32 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
         match self {
             #[cfg(feature = "sqlite")]
             CreateColumnImpl::SQLite(mut d) => {
+                check_deferrable(d.name, d.deferrable, &d.annotations)?;
+                check_default(d.name, d.default, &d.annotations)?;
+
                 write!(
                     s,
                     "\"{}\" {} ",
                     d.name,
-                    match d.data_type {
-                        DbType::Binary | DbType::Uuid => "BLOB",
-                        DbType::VarChar
-                        | DbType::Date
-                        | DbType::DateTime
-                        | DbType::Timestamp
-                        | DbType::Time
-                        | DbType::Choices => "TEXT",
-                        DbType::Int8
-                        | DbType::Int16
-                        | DbType::Int32
-                        | DbType::Int64
-                        | DbType::Boolean => "INTEGER",
-                        DbType::Float | DbType::Double => "REAL",
-                        DbType::BitVec | DbType::MacAddress | DbType::IpNetwork => unreachable!(
-                            "BitVec, MacAddress and IpNetwork are not available for sqlite"
-                        ),
-                    }
+                    sqlite::data_type(d.data_type).unwrap_or_else(|| unreachable!(
+                        "BitVec, MacAddress and IpNetwork are not available for sqlite"
+                    ))
                 )
                 .unwrap();
 
+                if let Some(collation) = d.collation {
+                    write!(s, "COLLATE {collation} ").unwrap();
+                }
+
+                if let Some((expression, stored)) = d.generated {
+                    write!(
+                        s,
+                        "GENERATED ALWAYS AS ({expression}) {} ",
+                        if stored { "STORED" } else { "VIRTUAL" }
+                    )
+                    .unwrap();
+                }
+
                 for (idx, x) in d.annotations.iter().enumerate() {
                     if let Some(ref mut s) = d.statements {
                         trigger_annotation_to_trigger_sqlite(
@@ -167,11 +332,7 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                             DefaultValue::Integer(i) => write!(s, "DEFAULT {i}").unwrap(),
                             DefaultValue::Float(f) => write!(s, "DEFAULT {f}").unwrap(),
                             DefaultValue::Boolean(b) => {
-                                if *b {
-                                    write!(s, "DEFAULT 1").unwrap();
-                                } else {
-                                    write!(s, "DEFAULT 0").unwrap();
-                                }
+                                write!(s, "DEFAULT {}", bool_literal(*b, DBImpl::SQLite)).unwrap()
                             }
                         },
                         Annotation::NotNull => write!(s, "NOT NULL").unwrap(),
@@ -179,8 +340,12 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                         Annotation::Unique => write!(s, "UNIQUE").unwrap(),
                         Annotation::ForeignKey(fk) => write!(
                             s,
-                            "REFERENCES \"{}\" (\"{}\") ON DELETE {} ON UPDATE {}",
-                            fk.table_name, fk.column_name, fk.on_delete, fk.on_update
+                            "REFERENCES \"{}\" (\"{}\") ON DELETE {} ON UPDATE {}{}",
+                            fk.table_name,
+                            fk.column_name,
+                            fk.on_delete,
+                            fk.on_update,
+                            deferrable_clause(d.deferrable)
                         )
                         .unwrap(),
                         _ => {}
@@ -191,10 +356,31 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                     }
                 }
 
+                if let Some(value) = d.default {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "DEFAULT {}", value.to_sql_literal(DBImpl::SQLite)?).unwrap();
+                }
+
+                if let Some(condition) = d.check {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "CHECK ({})", condition.build_inlined(DBImpl::SQLite)?).unwrap();
+                }
+
                 Ok(())
             }
             #[cfg(feature = "mysql")]
             CreateColumnImpl::MySQL(mut d) => {
+                if d.deferrable.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support deferrable foreign keys".to_string(),
+                    ));
+                }
+                check_default(d.name, d.default, &d.annotations)?;
+
                 write!(s, "`{}` ", d.name).unwrap();
 
                 match d.data_type {
@@ -223,7 +409,23 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                             )));
                         }
                     }
-                    DbType::Binary | DbType::Uuid => write!(s, "LONGBLOB ").unwrap(),
+                    DbType::Binary => {
+                        let a_opt = d
+                            .annotations
+                            .iter()
+                            .find(|x| x.annotation.eq_shallow(&Annotation::MaxLength(0)));
+
+                        match a_opt.map(|a| a.annotation) {
+                            // VARBINARY must fit into the maximum row size of 65535 bytes
+                            Some(Annotation::MaxLength(max_length))
+                                if *max_length < 2i32.pow(16) - 1 =>
+                            {
+                                write!(s, "VARBINARY({max_length}) ").unwrap()
+                            }
+                            _ => write!(s, "LONGBLOB ").unwrap(),
+                        }
+                    }
+                    DbType::Uuid => write!(s, "LONGBLOB ").unwrap(),
                     DbType::Int8 => write!(s, "TINYINT(255) ").unwrap(),
                     DbType::Int16 => write!(s, "SMALLINT(255) ").unwrap(),
                     DbType::Int32 => write!(s, "INT(255) ").unwrap(),
@@ -269,6 +471,19 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                     }
                 };
 
+                if let Some(collation) = d.collation {
+                    write!(s, "COLLATE {collation} ").unwrap();
+                }
+
+                if let Some((expression, stored)) = d.generated {
+                    write!(
+                        s,
+                        "GENERATED ALWAYS AS ({expression}) {} ",
+                        if stored { "STORED" } else { "VIRTUAL" }
+                    )
+                    .unwrap();
+                }
+
                 for (idx, x) in d.annotations.iter().enumerate() {
                     match &x.annotation {
                         Annotation::AutoIncrement => write!(s, "AUTO_INCREMENT").unwrap(),
@@ -308,11 +523,7 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                             DefaultValue::Integer(i) => write!(s, "DEFAULT {i}").unwrap(),
                             DefaultValue::Float(f) => write!(s, "DEFAULT {f}").unwrap(),
                             DefaultValue::Boolean(b) => {
-                                if *b {
-                                    write!(s, "DEFAULT 1").unwrap();
-                                } else {
-                                    write!(s, "DEFAULT 0").unwrap();
-                                }
+                                write!(s, "DEFAULT {}", bool_literal(*b, DBImpl::MySQL)).unwrap()
                             }
                         },
                         Annotation::NotNull => write!(s, "NOT NULL").unwrap(),
@@ -332,10 +543,34 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                     }
                 }
 
+                if let Some(value) = d.default {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "DEFAULT {}", value.to_sql_literal(DBImpl::MySQL)?).unwrap();
+                }
+
+                if let Some(condition) = d.check {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "CHECK ({})", condition.build_inlined(DBImpl::MySQL)?).unwrap();
+                }
+
+                if let Some(comment) = d.comment {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "COMMENT {}", mysql::fmt(comment)).unwrap();
+                }
+
                 Ok(())
             }
             #[cfg(feature = "postgres")]
             CreateColumnImpl::Postgres(mut d) => {
+                check_deferrable(d.name, d.deferrable, &d.annotations)?;
+                check_default(d.name, d.default, &d.annotations)?;
+
                 write!(s, "\"{}\" ", d.name).unwrap();
 
                 match d.data_type {
@@ -368,12 +603,17 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
 
                         if let Some(a) = a_opt {
                             if let Annotation::Choices(values) = a.annotation {
+                                // The type is created in the schema of the table
+                                let type_name = match d.table_name.schema {
+                                    Some(schema) => {
+                                        format!("{schema}._{}_{}", d.table_name.name, d.name)
+                                    }
+                                    None => format!("_{}_{}", d.table_name.name, d.name),
+                                };
                                 if let Some(stmts) = d.pre_statements {
                                     stmts.push((
                                         format!(
-                                            "CREATE TYPE _{}_{} AS ENUM({});",
-                                            d.table_name,
-                                            d.name,
+                                            "CREATE TYPE {type_name} AS ENUM({});",
                                             values
                                                 .iter()
                                                 .map(|x| { postgres::fmt(x) })
@@ -383,7 +623,7 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                                         vec![],
                                     ));
                                 };
-                                write!(s, "_{}_{} ", d.table_name, d.name,).unwrap();
+                                write!(s, "{type_name} ").unwrap();
                             } else {
                                 return Err(Error::SQLBuildError(
                                     "VARCHAR must have a MaxLength annotation".to_string(),
@@ -440,6 +680,24 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                     DbType::Time => write!(s, "time ").unwrap(),
                 };
 
+                if let Some(collation) = d.collation {
+                    write!(
+                        s,
+                        "COLLATE {} ",
+                        DBImpl::Postgres.quote_identifier(collation)
+                    )
+                    .unwrap();
+                }
+
+                if let Some((expression, stored)) = d.generated {
+                    if !stored {
+                        return Err(Error::UnsupportedFeature(
+                            "Postgres only supports stored generated columns".to_string(),
+                        ));
+                    }
+                    write!(s, "GENERATED ALWAYS AS ({expression}) STORED ").unwrap();
+                }
+
                 for (idx, x) in d.annotations.iter().enumerate() {
                     if let Some(ref mut s) = d.statements {
                         trigger_annotation_to_trigger_postgres(
@@ -472,11 +730,7 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                             DefaultValue::Integer(i) => write!(s, "DEFAULT {i}").unwrap(),
                             DefaultValue::Float(f) => write!(s, "DEFAULT {f}").unwrap(),
                             DefaultValue::Boolean(b) => {
-                                if *b {
-                                    write!(s, "DEFAULT true").unwrap();
-                                } else {
-                                    write!(s, "DEFAULT false").unwrap();
-                                }
+                                write!(s, "DEFAULT {}", bool_literal(*b, DBImpl::Postgres)).unwrap()
                             }
                         },
                         Annotation::NotNull => write!(s, "NOT NULL").unwrap(),
@@ -484,8 +738,12 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                         Annotation::Unique => write!(s, "UNIQUE").unwrap(),
                         Annotation::ForeignKey(fk) => write!(
                             s,
-                            "REFERENCES \"{}\"(\"{}\") ON DELETE {} ON UPDATE {}",
-                            fk.table_name, fk.column_name, fk.on_delete, fk.on_update
+                            "REFERENCES \"{}\"(\"{}\") ON DELETE {} ON UPDATE {}{}",
+                            fk.table_name,
+                            fk.column_name,
+                            fk.on_delete,
+                            fk.on_update,
+                            deferrable_clause(d.deferrable)
                         )
                         .unwrap(),
                         _ => {}
@@ -496,8 +754,553 @@ impl<'until_build, 'post_build> CreateColumn<'post_build>
                     }
                 }
 
+                if let Some(value) = d.default {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "DEFAULT {}", value.to_sql_literal(DBImpl::Postgres)?).unwrap();
+                }
+
+                if let Some(condition) = d.check {
+                    if !s.ends_with(' ') {
+                        write!(s, " ").unwrap();
+                    }
+                    write!(s, "CHECK ({})", condition.build_inlined(DBImpl::Postgres)?).unwrap();
+                }
+
+                if let (Some(comment), Some(statements)) = (d.comment, d.statements) {
+                    statements.push((
+                        format!(
+                            "COMMENT ON COLUMN {}.\"{}\" IS {};",
+                            d.table_name.quoted(DBImpl::Postgres),
+                            d.name,
+                            postgres::fmt(comment)
+                        ),
+                        vec![],
+                    ));
+                }
+
                 Ok(())
             }
         }
     }
 }
+
+/// Checks that a deferrable column has a foreign key to defer.
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn check_deferrable(
+    name: &str,
+    deferrable: Option<bool>,
+    annotations: &[SQLAnnotation],
+) -> Result<(), Error> {
+    if deferrable.is_some()
+        && !annotations
+            .iter()
+            .any(|x| matches!(x.annotation, Annotation::ForeignKey(_)))
+    {
+        return Err(Error::SQLBuildError(format!(
+            "Couldn't create column {name}: Only foreign keys can be deferrable"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that the default value isn't set by an annotation as well.
+fn check_default(
+    name: &str,
+    default: Option<Value>,
+    annotations: &[SQLAnnotation],
+) -> Result<(), Error> {
+    if default.is_some()
+        && annotations
+            .iter()
+            .any(|x| matches!(x.annotation, Annotation::DefaultValue(_)))
+    {
+        return Err(Error::SQLBuildError(format!(
+            "Couldn't create column {name}: The default value is set twice"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the DEFERRABLE clause to append to a foreign key.
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn deferrable_clause(deferrable: Option<bool>) -> &'static str {
+    match deferrable {
+        None => "",
+        Some(true) => " DEFERRABLE INITIALLY DEFERRED",
+        Some(false) => " DEFERRABLE INITIALLY IMMEDIATE",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rorm_declaration::imr::{Annotation, DbType, ForeignKey, ReferentialAction};
+
+    use crate::conditional::{BinaryCondition, Condition};
+    use crate::create_column::CreateColumn;
+    use crate::create_table::CreateTable;
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    use crate::error::Error;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn binary_sqlite() {
+        let max_length = [Annotation::MaxLength(255)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("file")
+                .add_column(DBImpl::SQLite.create_column(
+                    "file",
+                    "hash",
+                    DbType::Binary,
+                    &max_length
+                ))
+                .add_column(DBImpl::SQLite.create_column("file", "content", DbType::Binary, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"file\" (\"hash\" BLOB , \"content\" BLOB ) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn binary_mysql() {
+        let max_length = [Annotation::MaxLength(255)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("file")
+                .add_column(DBImpl::MySQL.create_column(
+                    "file",
+                    "hash",
+                    DbType::Binary,
+                    &max_length
+                ))
+                .add_column(DBImpl::MySQL.create_column("file", "content", DbType::Binary, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `file` (`hash` VARBINARY(255) , `content` LONGBLOB ); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn binary_postgres() {
+        let mut sql = String::new();
+        DBImpl::Postgres
+            .create_column("file", "content", DbType::Binary, &[])
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(sql, "\"content\" bytea ");
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn check_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Value(Value::Ident("status")),
+            Condition::Value(Value::String("it's deleted?")),
+        ])));
+        let annotations = [Annotation::NotNull];
+
+        let mut sql = String::new();
+        DBImpl::SQLite
+            .create_column("post", "status", DbType::VarChar, &annotations)
+            .check(&condition)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"status\" TEXT NOT NULL CHECK ((status <> 'it''s deleted?'))"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn check_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(0)),
+        ])));
+
+        let mut sql = String::new();
+        DBImpl::MySQL
+            .create_column("user", "age", DbType::Int64, &[])
+            .check(&condition)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(sql, "`age` BIGINT(255) CHECK ((age > 0))");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn check_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Value(Value::Ident("status")),
+            Condition::Value(Value::String("it's deleted?")),
+        ])));
+        let annotations = [Annotation::MaxLength(16), Annotation::NotNull];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("post")
+                .add_column(
+                    DBImpl::Postgres
+                        .create_column("post", "status", DbType::VarChar, &annotations)
+                        .check(&condition),
+                )
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"post\" (\"status\" character varying (16)  NOT NULL CHECK ((status <> 'it''s deleted?'))); "
+                    .to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn foreign_key_sqlite() {
+        let annotations = [Annotation::ForeignKey(ForeignKey {
+            table_name: "user".to_string(),
+            column_name: "id".to_string(),
+            on_delete: ReferentialAction::Cascade,
+            on_update: ReferentialAction::Restrict,
+        })];
+
+        let mut sql = String::new();
+        DBImpl::SQLite
+            .create_column("post", "author", DbType::Int64, &annotations)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"author\" INTEGER REFERENCES \"user\" (\"id\") ON DELETE CASCADE ON UPDATE RESTRICT"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn foreign_key_mysql() {
+        let annotations = [Annotation::ForeignKey(ForeignKey {
+            table_name: "user".to_string(),
+            column_name: "id".to_string(),
+            on_delete: ReferentialAction::SetNull,
+            on_update: ReferentialAction::Cascade,
+        })];
+
+        let mut sql = String::new();
+        DBImpl::MySQL
+            .create_column("post", "author", DbType::Int64, &annotations)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "`author` BIGINT(255) REFERENCES `user`(`id`) ON DELETE SET NULL ON UPDATE CASCADE"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn foreign_key_postgres() {
+        let annotations = [Annotation::ForeignKey(ForeignKey {
+            table_name: "user".to_string(),
+            column_name: "id".to_string(),
+            on_delete: ReferentialAction::SetDefault,
+            on_update: ReferentialAction::SetNull,
+        })];
+
+        let mut sql = String::new();
+        DBImpl::Postgres
+            .create_column("post", "author", DbType::Int64, &annotations)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"author\" bigint REFERENCES \"user\"(\"id\") ON DELETE SET DEFAULT ON UPDATE SET NULL"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn deferrable_sqlite() {
+        let annotations = [Annotation::ForeignKey(ForeignKey {
+            table_name: "user".to_string(),
+            column_name: "id".to_string(),
+            on_delete: ReferentialAction::Restrict,
+            on_update: ReferentialAction::SetDefault,
+        })];
+
+        let mut sql = String::new();
+        DBImpl::SQLite
+            .create_column("post", "author", DbType::Int64, &annotations)
+            .deferrable(true)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"author\" INTEGER REFERENCES \"user\" (\"id\") ON DELETE RESTRICT ON UPDATE SET DEFAULT DEFERRABLE INITIALLY DEFERRED"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn deferrable_mysql() {
+        let annotations = [Annotation::ForeignKey(ForeignKey {
+            table_name: "user".to_string(),
+            column_name: "id".to_string(),
+            on_delete: ReferentialAction::Cascade,
+            on_update: ReferentialAction::Cascade,
+        })];
+
+        assert!(matches!(
+            DBImpl::MySQL
+                .create_column("post", "author", DbType::Int64, &annotations)
+                .deferrable(true)
+                .build(&mut String::new()),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn deferrable_postgres() {
+        let annotations = [Annotation::ForeignKey(ForeignKey {
+            table_name: "user".to_string(),
+            column_name: "id".to_string(),
+            on_delete: ReferentialAction::Cascade,
+            on_update: ReferentialAction::Cascade,
+        })];
+
+        let mut sql = String::new();
+        DBImpl::Postgres
+            .create_column("post", "author", DbType::Int64, &annotations)
+            .deferrable(false)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"author\" bigint REFERENCES \"user\"(\"id\") ON DELETE CASCADE ON UPDATE CASCADE DEFERRABLE INITIALLY IMMEDIATE"
+        );
+        assert!(matches!(
+            DBImpl::Postgres
+                .create_column("post", "author", DbType::Int64, &[])
+                .deferrable(true)
+                .build(&mut String::new()),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn generated_sqlite() {
+        let not_null = [Annotation::NotNull];
+
+        let mut sql = String::new();
+        DBImpl::SQLite
+            .create_column("order", "total", DbType::Int64, &not_null)
+            .generated("price * amount", false)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"total\" INTEGER GENERATED ALWAYS AS (price * amount) VIRTUAL NOT NULL"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn generated_mysql() {
+        let mut sql = String::new();
+        DBImpl::MySQL
+            .create_column("order", "total", DbType::Int64, &[])
+            .generated("price * amount", true)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "`total` BIGINT(255) GENERATED ALWAYS AS (price * amount) STORED "
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn generated_postgres() {
+        let not_null = [Annotation::NotNull];
+
+        let mut sql = String::new();
+        DBImpl::Postgres
+            .create_column("order", "total", DbType::Int64, &not_null)
+            .generated("price * amount", true)
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"total\" bigint GENERATED ALWAYS AS (price * amount) STORED NOT NULL"
+        );
+        assert!(matches!(
+            DBImpl::Postgres
+                .create_column("order", "total", DbType::Int64, &not_null)
+                .generated("price * amount", false)
+                .build(&mut String::new()),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn collate_sqlite() {
+        let annotations = [Annotation::NotNull];
+
+        let mut sql = String::new();
+        DBImpl::SQLite
+            .create_column("user", "name", DbType::VarChar, &annotations)
+            .collate("NOCASE")
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(sql, "\"name\" TEXT COLLATE NOCASE NOT NULL");
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn collate_mysql() {
+        let annotations = [Annotation::MaxLength(255)];
+
+        let mut sql = String::new();
+        DBImpl::MySQL
+            .create_column("user", "name", DbType::VarChar, &annotations)
+            .collate("utf8mb4_unicode_ci")
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(sql, "`name` VARCHAR(255) COLLATE utf8mb4_unicode_ci ");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn collate_postgres() {
+        let annotations = [Annotation::MaxLength(255), Annotation::NotNull];
+
+        let mut sql = String::new();
+        DBImpl::Postgres
+            .create_column("user", "name", DbType::VarChar, &annotations)
+            .collate("en_US")
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "\"name\" character varying (255) COLLATE \"en_US\"  NOT NULL"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn default_value_sqlite() {
+        let column = |data_type, value| {
+            let mut sql = String::new();
+            DBImpl::SQLite
+                .create_column("user", "field", data_type, &[])
+                .default_value(value)
+                .build(&mut sql)
+                .unwrap();
+            sql
+        };
+
+        assert_eq!(
+            column(DbType::VarChar, Value::String("it's a \\ 'test'")),
+            "\"field\" TEXT DEFAULT 'it''s a \\ ''test'''"
+        );
+        assert_eq!(
+            column(DbType::Double, Value::F64(1.5)),
+            "\"field\" REAL DEFAULT 1.5"
+        );
+        assert_eq!(
+            column(DbType::Boolean, Value::Bool(true)),
+            "\"field\" INTEGER DEFAULT 1"
+        );
+        assert_eq!(
+            column(DbType::Binary, Value::Binary(&[0xde, 0xad, 0x01])),
+            "\"field\" BLOB DEFAULT X'dead01'"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn default_value_mysql() {
+        let max_length = [Annotation::MaxLength(32)];
+
+        let mut sql = String::new();
+        DBImpl::MySQL
+            .create_column("user", "name", DbType::VarChar, &max_length)
+            .default_value(Value::String("it's a \\ 'test'"))
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(sql, "`name` VARCHAR(32) DEFAULT 'it''s a \\\\ ''test'''");
+
+        let mut sql = String::new();
+        DBImpl::MySQL
+            .create_column("user", "active", DbType::Boolean, &[])
+            .default_value(Value::Bool(false))
+            .build(&mut sql)
+            .unwrap();
+        assert_eq!(sql, "`active` BOOL DEFAULT 0");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn default_value_postgres() {
+        let column = |data_type, value| {
+            let mut sql = String::new();
+            DBImpl::Postgres
+                .create_column("user", "field", data_type, &[])
+                .default_value(value)
+                .build(&mut sql)
+                .map(|_| sql)
+        };
+
+        assert_eq!(
+            column(DbType::Int32, Value::I32(42)).unwrap(),
+            "\"field\" integer DEFAULT 42"
+        );
+        assert_eq!(
+            column(DbType::Double, Value::F64(-0.25)).unwrap(),
+            "\"field\" double precision DEFAULT -0.25"
+        );
+        assert_eq!(
+            column(DbType::Boolean, Value::Bool(true)).unwrap(),
+            "\"field\" boolean DEFAULT TRUE"
+        );
+        assert_eq!(
+            column(DbType::Binary, Value::Binary(&[0xde, 0xad])).unwrap(),
+            "\"field\" bytea DEFAULT '\\xdead'"
+        );
+        assert!(matches!(
+            column(DbType::Double, Value::F64(f64::NAN)),
+            Err(Error::SQLBuildError(_))
+        ));
+        assert!(matches!(
+            DBImpl::Postgres
+                .create_column(
+                    "user",
+                    "field",
+                    DbType::Int64,
+                    &[Annotation::DefaultValue(
+                        rorm_declaration::imr::DefaultValue::Integer(1)
+                    )]
+                )
+                .default_value(Value::I64(2))
+                .build(&mut String::new()),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+}
@@ -1,4 +1,35 @@
+#[cfg(feature = "postgres")]
+use std::fmt::Write;
+
+use crate::conditional::Condition;
 use crate::error::Error;
+use crate::ordering::Ordering;
+use crate::table_ref::TableRef;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use crate::DBImpl;
+
+/**
+Representation of an entry of an index.
+*/
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IndexColumn<'until_build> {
+    /// Index a column
+    Column {
+        /// Name of the column
+        name: &'until_build str,
+        /// Optional ordering of the column in the index
+        ordering: Option<Ordering>,
+    },
+    /// Index the result of an expression.
+    ///
+    /// Requires MySQL 8.0.13 or newer.
+    Expression {
+        /// Expression to index, e.g. `LOWER(name)`
+        expr: &'until_build str,
+        /// Optional ordering of the expression in the index
+        ordering: Option<Ordering>,
+    },
+}
 
 /**
 Representation of a CREATE INDEX builder.
@@ -11,6 +42,15 @@ pub trait CreateIndex<'until_build> {
      */
     fn unique(self) -> Self;
 
+    /**
+    Creates a full-text index.
+
+    MySQL uses a `FULLTEXT` index. Postgres uses a GIN index over `to_tsvector`
+    with the `simple` text search configuration, so queries have to use the same expressions.
+    SQLite requires a FTS5 virtual table instead, which is not supported.
+     */
+    fn fulltext(self) -> Self;
+
     /**
     Creates the index only if it doesn't exist yet.
      */
@@ -24,6 +64,14 @@ pub trait CreateIndex<'until_build> {
      */
     fn add_column(self, column: &'until_build str) -> Self;
 
+    /**
+    Adds a column or expression with an optional ordering to the index.
+
+    **Parameter**:
+    - `column`: [IndexColumn] to index.
+     */
+    fn add_index_column(self, column: IndexColumn<'until_build>) -> Self;
+
     /**
     Sets the condition to apply. This will build a partial index.
 
@@ -32,6 +80,17 @@ pub trait CreateIndex<'until_build> {
      */
     fn set_condition(self, condition: String) -> Self;
 
+    /**
+    Sets the condition to apply. This will build a partial index.
+
+    The values of the condition are written as literals, as bind parameters
+    are not supported in index predicates. Not supported by MySQL.
+
+    **Parameter**:
+    - `condition`: Condition the indexed rows have to fulfill
+     */
+    fn where_clause(self, condition: &'until_build Condition<'until_build>) -> Self;
+
     /**
     This method is used to build the create index operation
      */
@@ -43,11 +102,85 @@ Representation of a create index operation
 */
 pub struct CreateIndexData<'until_build> {
     pub(crate) name: &'until_build str,
-    pub(crate) table_name: &'until_build str,
+    pub(crate) table_name: TableRef<'until_build>,
     pub(crate) unique: bool,
+    pub(crate) fulltext: bool,
     pub(crate) if_not_exists: bool,
-    pub(crate) columns: Vec<&'until_build str>,
+    pub(crate) columns: Vec<IndexColumn<'until_build>>,
     pub(crate) condition: Option<String>,
+    pub(crate) where_clause: Option<&'until_build Condition<'until_build>>,
+}
+
+impl<'until_build> CreateIndexData<'until_build> {
+    /// Render the list of indexed columns and expressions
+    fn build_columns(&self) -> String {
+        let mut s = String::new();
+        for (idx, column) in self.columns.iter().enumerate() {
+            let ordering = match column {
+                IndexColumn::Column { name, ordering } => {
+                    s.push_str(name);
+                    ordering
+                }
+                IndexColumn::Expression { expr, ordering } => {
+                    s.push('(');
+                    s.push_str(expr);
+                    s.push(')');
+                    ordering
+                }
+            };
+            match ordering {
+                Some(Ordering::Asc) => s.push_str(" ASC"),
+                Some(Ordering::Desc) => s.push_str(" DESC"),
+                None => {}
+            }
+            if idx != self.columns.len() - 1 {
+                s.push_str(", ");
+            }
+        }
+        s
+    }
+
+    /// Render the list of indexed columns and expressions as text search vectors
+    #[cfg(feature = "postgres")]
+    fn build_tsvectors(&self) -> Result<String, Error> {
+        let mut s = String::new();
+        for (idx, column) in self.columns.iter().enumerate() {
+            match column {
+                IndexColumn::Column {
+                    name,
+                    ordering: None,
+                } => write!(s, "to_tsvector('simple', {name})").unwrap(),
+                IndexColumn::Expression {
+                    expr,
+                    ordering: None,
+                } => write!(s, "to_tsvector('simple', ({expr}))").unwrap(),
+                _ => {
+                    return Err(Error::SQLBuildError(format!(
+                        "Couldn't create index on {}: Full-text indexes can't be ordered",
+                        self.table_name
+                    )))
+                }
+            }
+            if idx != self.columns.len() - 1 {
+                write!(s, ", ").unwrap();
+            }
+        }
+        Ok(s)
+    }
+
+    /// Render the condition of a partial index
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    fn predicate(&self, dialect: DBImpl) -> Result<Option<String>, Error> {
+        match (&self.condition, self.where_clause) {
+            (Some(_), Some(_)) => Err(Error::SQLBuildError(format!(
+                "Couldn't create index on {}: Both a condition and a where clause were set",
+                self.table_name
+            ))),
+            (Some(condition), None) => Ok(Some(condition.clone())),
+            (None, Some(condition)) => condition.build_inlined(dialect).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
 }
 
 /**
@@ -86,6 +219,18 @@ impl<'until_build> CreateIndex<'until_build> for CreateIndexImpl<'until_build> {
         self
     }
 
+    fn fulltext(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateIndexImpl::Sqlite(ref mut d) => d.fulltext = true,
+            #[cfg(feature = "mysql")]
+            CreateIndexImpl::MySQL(ref mut d) => d.fulltext = true,
+            #[cfg(feature = "postgres")]
+            CreateIndexImpl::Postgres(ref mut d) => d.fulltext = true,
+        };
+        self
+    }
+
     fn if_not_exists(mut self) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
@@ -98,7 +243,14 @@ impl<'until_build> CreateIndex<'until_build> for CreateIndexImpl<'until_build> {
         self
     }
 
-    fn add_column(mut self, column: &'until_build str) -> Self {
+    fn add_column(self, column: &'until_build str) -> Self {
+        self.add_index_column(IndexColumn::Column {
+            name: column,
+            ordering: None,
+        })
+    }
+
+    fn add_index_column(mut self, column: IndexColumn<'until_build>) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
             CreateIndexImpl::Sqlite(ref mut d) => d.columns.push(column),
@@ -122,43 +274,89 @@ impl<'until_build> CreateIndex<'until_build> for CreateIndexImpl<'until_build> {
         self
     }
 
+    fn where_clause(mut self, condition: &'until_build Condition<'until_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateIndexImpl::Sqlite(ref mut d) => d.where_clause = Some(condition),
+            #[cfg(feature = "mysql")]
+            CreateIndexImpl::MySQL(ref mut d) => d.where_clause = Some(condition),
+            #[cfg(feature = "postgres")]
+            CreateIndexImpl::Postgres(ref mut d) => d.where_clause = Some(condition),
+        }
+        self
+    }
+
     fn build(self) -> Result<String, Error> {
         match self {
             #[cfg(feature = "sqlite")]
             CreateIndexImpl::Sqlite(d) => {
                 if d.columns.is_empty() {
-                    return Err(Error::SQLBuildError(format!(
-                        "Couldn't create index on {}: Missing column(s) to create the index on",
-                        d.table_name
-                    )));
+                    return Err(Error::EmptyColumnList {
+                        statement: "CREATE INDEX",
+                    });
+                }
+
+                if d.fulltext {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite requires a FTS5 virtual table for full-text search".to_string(),
+                    ));
                 }
 
+                let condition = d.predicate(DBImpl::SQLite)?;
+
+                // SQLite qualifies the index instead of the table
+                let name = match d.table_name.schema {
+                    Some(schema) => format!("{schema}.{}", d.name),
+                    None => d.name.to_string(),
+                };
+
                 Ok(format!(
-                    "CREATE {} INDEX{} {} ON {} ({}) {};",
-                    if d.unique { "UNIQUE" } else { "" },
+                    "CREATE{} INDEX{} {} ON {} ({}){};",
+                    if d.unique { " UNIQUE" } else { "" },
                     if d.if_not_exists {
                         " IF NOT EXISTS"
                     } else {
                         ""
                     },
-                    d.name,
-                    d.table_name,
-                    d.columns.join(", "),
-                    d.condition.as_ref().map_or("", |x| x.as_str()),
+                    name,
+                    d.table_name.name,
+                    d.build_columns(),
+                    match condition {
+                        None => String::from(""),
+                        Some(cond) => format!(" WHERE {cond}"),
+                    }
                 ))
             }
             #[cfg(feature = "mysql")]
             CreateIndexImpl::MySQL(d) => {
                 if d.columns.is_empty() {
+                    return Err(Error::EmptyColumnList {
+                        statement: "CREATE INDEX",
+                    });
+                }
+
+                if d.condition.is_some() || d.where_clause.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support partial indexes".to_string(),
+                    ));
+                }
+
+                if d.unique && d.fulltext {
                     return Err(Error::SQLBuildError(format!(
-                        "Couldn't create index on {}: Missing column(s) to create the index on",
+                        "Couldn't create index on {}: Full-text indexes can't be unique",
                         d.table_name
                     )));
                 }
 
                 Ok(format!(
-                    "CREATE {} INDEX{} {} ON {} ({});",
-                    if d.unique { "UNIQUE" } else { "" },
+                    "CREATE{} INDEX{} {} ON {} ({});",
+                    if d.unique {
+                        " UNIQUE"
+                    } else if d.fulltext {
+                        " FULLTEXT"
+                    } else {
+                        ""
+                    },
                     if d.if_not_exists {
                         " IF NOT EXISTS"
                     } else {
@@ -166,20 +364,28 @@ impl<'until_build> CreateIndex<'until_build> for CreateIndexImpl<'until_build> {
                     },
                     d.name,
                     d.table_name,
-                    d.columns.join(", "),
+                    d.build_columns(),
                 ))
             }
             #[cfg(feature = "postgres")]
             CreateIndexImpl::Postgres(d) => {
                 if d.columns.is_empty() {
+                    return Err(Error::EmptyColumnList {
+                        statement: "CREATE INDEX",
+                    });
+                }
+
+                if d.unique && d.fulltext {
                     return Err(Error::SQLBuildError(format!(
-                        "Couldn't create index on {}: Missing column(s) to create the index on",
+                        "Couldn't create index on {}: Full-text indexes can't be unique",
                         d.table_name
                     )));
                 }
 
+                let condition = d.predicate(DBImpl::Postgres)?;
+
                 Ok(format!(
-                    "CREATE{} INDEX{} {} ON {} ({}){};",
+                    "CREATE{} INDEX{} {} ON {} {}({}){};",
                     if d.unique { " UNIQUE" } else { "" },
                     if d.if_not_exists {
                         " IF NOT EXISTS"
@@ -188,13 +394,222 @@ impl<'until_build> CreateIndex<'until_build> for CreateIndexImpl<'until_build> {
                     },
                     d.name,
                     d.table_name,
-                    d.columns.join(", "),
-                    match d.condition {
+                    if d.fulltext { "USING GIN " } else { "" },
+                    if d.fulltext {
+                        d.build_tsvectors()?
+                    } else {
+                        d.build_columns()
+                    },
+                    match condition {
                         None => String::from(""),
-                        Some(cond) => format!(" WHERE {}", cond.as_str()),
+                        Some(cond) => format!(" WHERE {cond}"),
                     }
                 ))
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "sqlite")]
+    use crate::conditional::BinaryCondition;
+    use crate::conditional::Condition;
+    #[cfg(feature = "postgres")]
+    use crate::conditional::UnaryCondition;
+    use crate::create_index::{CreateIndex, IndexColumn};
+    use crate::error::Error;
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    use crate::ordering::Ordering;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn partial_index_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("status")),
+            Condition::Value(Value::String("active")),
+        ])));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_index("active_user_email", "user")
+                .unique()
+                .add_column("email")
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            "CREATE UNIQUE INDEX active_user_email ON user (email) WHERE (status = 'active');"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn partial_index_mysql() {
+        let condition = Condition::Value(Value::Ident("active"));
+
+        assert!(matches!(
+            DBImpl::MySQL
+                .create_index("active_user_email", "user")
+                .add_column("email")
+                .where_clause(&condition)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn partial_index_postgres() {
+        let condition = Condition::UnaryCondition(UnaryCondition::IsNull(Box::new(
+            Condition::Value(Value::Ident("deleted_at")),
+        )));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .create_index("live_post_slug", "post")
+                .add_column("slug")
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            "CREATE INDEX live_post_slug ON post (slug) WHERE (deleted_at IS NULL);"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn ordered_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .create_index("user_lookup", "user")
+                .add_index_column(IndexColumn::Expression {
+                    expr: "LOWER(name)",
+                    ordering: None,
+                })
+                .add_column("id")
+                .build()
+                .unwrap(),
+            "CREATE INDEX user_lookup ON user ((LOWER(name)), id);"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn ordered_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_index("user_lookup", "user")
+                .add_index_column(IndexColumn::Column {
+                    name: "created_at",
+                    ordering: Some(Ordering::Desc),
+                })
+                .add_index_column(IndexColumn::Column {
+                    name: "id",
+                    ordering: Some(Ordering::Asc),
+                })
+                .build()
+                .unwrap(),
+            "CREATE INDEX user_lookup ON user (created_at DESC, id ASC);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn ordered_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_index("user_lookup", "user")
+                .add_index_column(IndexColumn::Expression {
+                    expr: "LOWER(name)",
+                    ordering: Some(Ordering::Desc),
+                })
+                .add_column("id")
+                .build()
+                .unwrap(),
+            "CREATE INDEX user_lookup ON user ((LOWER(name)) DESC, id);"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn fulltext_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .create_index("post_search", "post")
+                .fulltext()
+                .add_column("title")
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn fulltext_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_index("post_search", "post")
+                .fulltext()
+                .add_column("title")
+                .add_column("body")
+                .build()
+                .unwrap(),
+            "CREATE FULLTEXT INDEX post_search ON post (title, body);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn fulltext_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_index("post_search", "post")
+                .fulltext()
+                .add_column("title")
+                .add_column("body")
+                .build()
+                .unwrap(),
+            "CREATE INDEX post_search ON post USING GIN (to_tsvector('simple', title), to_tsvector('simple', body));"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn without_columns_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite.create_index("idx_user", "user").build(),
+            Err(Error::EmptyColumnList {
+                statement: "CREATE INDEX"
+            })
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn without_columns_mysql() {
+        assert!(matches!(
+            DBImpl::MySQL
+                .create_index("idx_user", "user")
+                .fulltext()
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "CREATE INDEX"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn without_columns_postgres() {
+        assert!(matches!(
+            DBImpl::Postgres
+                .create_index("idx_user", "user")
+                .unique()
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "CREATE INDEX"
+            })
+        ));
+    }
+}
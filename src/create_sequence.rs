@@ -0,0 +1,281 @@
+#[cfg(feature = "postgres")]
+use std::fmt::Write;
+
+use crate::error::Error;
+
+/**
+Trait representing a create sequence builder.
+
+Sequences are only supported by Postgres, SQLite and MySQL return
+[Error::UnsupportedFeature] on build.
+*/
+pub trait CreateSequence<'until_build> {
+    /**
+    Set the first value the sequence returns.
+
+    **Parameter**:
+    - `start`: Start value of the sequence.
+     */
+    fn start_with(self, start: i64) -> Self;
+
+    /**
+    Set the value that is added to the current value to get the next one.
+
+    **Parameter**:
+    - `increment`: Increment of the sequence, may be negative.
+     */
+    fn increment_by(self, increment: i64) -> Self;
+
+    /**
+    Set the minimum value of the sequence.
+
+    **Parameter**:
+    - `min_value`: Minimum value of the sequence.
+     */
+    fn min_value(self, min_value: i64) -> Self;
+
+    /**
+    Set the maximum value of the sequence.
+
+    **Parameter**:
+    - `max_value`: Maximum value of the sequence.
+     */
+    fn max_value(self, max_value: i64) -> Self;
+
+    /**
+    Wrap around when the minimum or maximum value is reached,
+    instead of returning an error.
+     */
+    fn cycle(self) -> Self;
+
+    /**
+    Creates the sequence only if it doesn't exist yet.
+     */
+    fn if_not_exists(self) -> Self;
+
+    /**
+    This method is used to build the create sequence operation.
+     */
+    fn build(self) -> Result<String, Error>;
+}
+
+/**
+Representation of the data of a CREATE SEQUENCE operation.
+*/
+#[derive(Debug, Copy, Clone)]
+pub struct CreateSequenceData<'until_build> {
+    pub(crate) name: &'until_build str,
+    pub(crate) start_with: Option<i64>,
+    pub(crate) increment_by: Option<i64>,
+    pub(crate) min_value: Option<i64>,
+    pub(crate) max_value: Option<i64>,
+    pub(crate) cycle: bool,
+    pub(crate) if_not_exists: bool,
+}
+
+/**
+Implementation of the [CreateSequence] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::create_sequence].
+*/
+#[derive(Debug)]
+pub enum CreateSequenceImpl<'until_build> {
+    /**
+    SQLite representation of the CREATE SEQUENCE operation.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(CreateSequenceData<'until_build>),
+    /**
+    MySQL representation of the CREATE SEQUENCE operation.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(CreateSequenceData<'until_build>),
+    /**
+    Postgres representation of the CREATE SEQUENCE operation.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(CreateSequenceData<'until_build>),
+}
+
+impl<'until_build> CreateSequence<'until_build> for CreateSequenceImpl<'until_build> {
+    fn start_with(mut self, start: i64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(ref mut d) => d.start_with = Some(start),
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(ref mut d) => d.start_with = Some(start),
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(ref mut d) => d.start_with = Some(start),
+        };
+        self
+    }
+
+    fn increment_by(mut self, increment: i64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(ref mut d) => d.increment_by = Some(increment),
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(ref mut d) => d.increment_by = Some(increment),
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(ref mut d) => d.increment_by = Some(increment),
+        };
+        self
+    }
+
+    fn min_value(mut self, min_value: i64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(ref mut d) => d.min_value = Some(min_value),
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(ref mut d) => d.min_value = Some(min_value),
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(ref mut d) => d.min_value = Some(min_value),
+        };
+        self
+    }
+
+    fn max_value(mut self, max_value: i64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(ref mut d) => d.max_value = Some(max_value),
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(ref mut d) => d.max_value = Some(max_value),
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(ref mut d) => d.max_value = Some(max_value),
+        };
+        self
+    }
+
+    fn cycle(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(ref mut d) => d.cycle = true,
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(ref mut d) => d.cycle = true,
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(ref mut d) => d.cycle = true,
+        };
+        self
+    }
+
+    fn if_not_exists(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(ref mut d) => d.if_not_exists = true,
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(ref mut d) => d.if_not_exists = true,
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(ref mut d) => d.if_not_exists = true,
+        };
+        self
+    }
+
+    fn build(self) -> Result<String, Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateSequenceImpl::SQLite(d) => Err(Error::UnsupportedFeature(format!(
+                "SQLite doesn't support sequences, couldn't create {}",
+                d.name
+            ))),
+            #[cfg(feature = "mysql")]
+            CreateSequenceImpl::MySQL(d) => Err(Error::UnsupportedFeature(format!(
+                "MySQL doesn't support sequences, couldn't create {}",
+                d.name
+            ))),
+            #[cfg(feature = "postgres")]
+            CreateSequenceImpl::Postgres(d) => {
+                let mut s = format!(
+                    "CREATE SEQUENCE{} {}",
+                    if d.if_not_exists {
+                        " IF NOT EXISTS"
+                    } else {
+                        ""
+                    },
+                    crate::DBImpl::Postgres.quote_identifier(d.name)
+                );
+                if let Some(start) = d.start_with {
+                    write!(s, " START WITH {start}").unwrap();
+                }
+                if let Some(increment) = d.increment_by {
+                    write!(s, " INCREMENT BY {increment}").unwrap();
+                }
+                if let Some(min_value) = d.min_value {
+                    write!(s, " MINVALUE {min_value}").unwrap();
+                }
+                if let Some(max_value) = d.max_value {
+                    write!(s, " MAXVALUE {max_value}").unwrap();
+                }
+                if d.cycle {
+                    write!(s, " CYCLE").unwrap();
+                }
+                write!(s, ";").unwrap();
+
+                Ok(s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::create_sequence::CreateSequence;
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    use crate::error::Error;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn create_sequence_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite.create_sequence("order_id").build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn create_sequence_mysql() {
+        assert!(matches!(
+            DBImpl::MySQL
+                .create_sequence("order_id")
+                .start_with(1000)
+                .cycle()
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn create_sequence_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_sequence("order_id")
+                .start_with(1000)
+                .increment_by(10)
+                .min_value(1000)
+                .max_value(99990)
+                .cycle()
+                .build()
+                .unwrap(),
+            "CREATE SEQUENCE \"order_id\" START WITH 1000 INCREMENT BY 10 MINVALUE 1000 MAXVALUE 99990 CYCLE;"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .create_sequence("s")
+                .start_with(1)
+                .increment_by(1)
+                .build()
+                .unwrap(),
+            "CREATE SEQUENCE \"s\" START WITH 1 INCREMENT BY 1;"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .create_sequence("s")
+                .if_not_exists()
+                .build()
+                .unwrap(),
+            "CREATE SEQUENCE IF NOT EXISTS \"s\";"
+        );
+    }
+}
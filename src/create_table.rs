@@ -1,8 +1,14 @@
 use std::fmt::Write;
 
+use crate::conditional::Condition;
 use crate::create_column::{CreateColumn, CreateColumnImpl};
+#[cfg(feature = "mysql")]
+use crate::db_specific::mysql;
+#[cfg(feature = "postgres")]
+use crate::db_specific::postgres;
 use crate::error::Error;
-use crate::Value;
+use crate::table_ref::TableRef;
+use crate::{DBImpl, Value};
 
 /**
 The trait representing a create table builder
@@ -18,6 +24,87 @@ pub trait CreateTable<'until_build, 'post_build> {
      */
     fn if_not_exists(self) -> Self;
 
+    /**
+    Create a temporary table, which is dropped at the end of the session.
+     */
+    fn temporary(self) -> Self;
+
+    /**
+    Drop the temporary table at the end of the current transaction.
+
+    Only supported by Postgres and requires [CreateTable::temporary].
+     */
+    fn on_commit_drop(self) -> Self;
+
+    /**
+    Create the table without the implicit rowid column.
+
+    Only used by SQLite, other dialects ignore it.
+    SQLite tables are always created as `STRICT`.
+     */
+    fn without_rowid(self) -> Self;
+
+    /**
+    Add a table option like `ENGINE=InnoDB`.
+
+    Only used by MySQL, other dialects ignore the options.
+
+    **Parameter**:
+    - `key`: Name of the option, e.g. `DEFAULT CHARSET`
+    - `value`: Value of the option, e.g. `utf8mb4`
+     */
+    fn table_option(self, key: &'until_build str, value: &'until_build str) -> Self;
+
+    /**
+    Set a primary key spanning multiple columns.
+
+    Can't be combined with columns annotated as primary key.
+
+    **Parameter**:
+    - `columns`: Names of the columns forming the primary key
+     */
+    fn primary_key(self, columns: &'until_build [&'until_build str]) -> Self;
+
+    /**
+    Add a UNIQUE constraint spanning multiple columns.
+
+    **Parameter**:
+    - `name`: Optional name of the constraint
+    - `columns`: Names of the columns whose combination has to be unique
+     */
+    fn unique(
+        self,
+        name: Option<&'until_build str>,
+        columns: &'until_build [&'until_build str],
+    ) -> Self;
+
+    /**
+    Add a CHECK constraint to the table.
+
+    The values of the condition are written as literals, as bind parameters
+    are not supported in constraints.
+
+    **Parameter**:
+    - `name`: Optional name of the constraint
+    - `condition`: Condition every row has to fulfill
+     */
+    fn check(
+        self,
+        name: Option<&'until_build str>,
+        condition: &'until_build Condition<'post_build>,
+    ) -> Self;
+
+    /**
+    Attach a comment to the table.
+
+    MySQL writes the comment as table option, Postgres adds a `COMMENT ON TABLE` statement
+    after the table is created. SQLite doesn't support comments and ignores it.
+
+    **Parameter**:
+    - `comment`: Comment describing the table
+     */
+    fn comment(self, comment: &'until_build str) -> Self;
+
     /**
     This method is used to convert the current state for the given dialect in a
     list of tuples.
@@ -31,9 +118,20 @@ pub trait CreateTable<'until_build, 'post_build> {
 The representation of an create table operation.
 */
 pub struct CreateTableData<'until_build, 'post_build> {
-    pub(crate) name: &'until_build str,
+    pub(crate) name: TableRef<'until_build>,
     pub(crate) columns: Vec<CreateColumnImpl<'until_build, 'post_build>>,
     pub(crate) if_not_exists: bool,
+    pub(crate) temporary: bool,
+    pub(crate) on_commit_drop: bool,
+    pub(crate) without_rowid: bool,
+    pub(crate) options: Vec<(&'until_build str, &'until_build str)>,
+    pub(crate) primary_key: &'until_build [&'until_build str],
+    pub(crate) uniques: Vec<(Option<&'until_build str>, &'until_build [&'until_build str])>,
+    pub(crate) checks: Vec<(
+        Option<&'until_build str>,
+        &'until_build Condition<'post_build>,
+    )>,
+    pub(crate) comment: Option<&'until_build str>,
     pub(crate) lookup: Vec<Value<'post_build>>,
     pub(crate) pre_statements: Vec<(String, Vec<Value<'post_build>>)>,
     pub(crate) statements: Vec<(String, Vec<Value<'post_build>>)>,
@@ -89,18 +187,143 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
         self
     }
 
+    fn temporary(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.temporary = true,
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.temporary = true,
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.temporary = true,
+        }
+        self
+    }
+
+    fn on_commit_drop(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.on_commit_drop = true,
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.on_commit_drop = true,
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.on_commit_drop = true,
+        }
+        self
+    }
+
+    fn without_rowid(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.without_rowid = true,
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.without_rowid = true,
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.without_rowid = true,
+        }
+        self
+    }
+
+    fn table_option(mut self, key: &'until_build str, value: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.options.push((key, value)),
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.options.push((key, value)),
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.options.push((key, value)),
+        }
+        self
+    }
+
+    fn primary_key(mut self, columns: &'until_build [&'until_build str]) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.primary_key = columns,
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.primary_key = columns,
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.primary_key = columns,
+        }
+        self
+    }
+
+    fn unique(
+        mut self,
+        name: Option<&'until_build str>,
+        columns: &'until_build [&'until_build str],
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.uniques.push((name, columns)),
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.uniques.push((name, columns)),
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.uniques.push((name, columns)),
+        }
+        self
+    }
+
+    fn check(
+        mut self,
+        name: Option<&'until_build str>,
+        condition: &'until_build Condition<'post_build>,
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.checks.push((name, condition)),
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.checks.push((name, condition)),
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.checks.push((name, condition)),
+        }
+        self
+    }
+
+    fn comment(mut self, comment: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(ref mut d) => d.comment = Some(comment),
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(ref mut d) => d.comment = Some(comment),
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(ref mut d) => d.comment = Some(comment),
+        }
+        self
+    }
+
     fn build(self) -> Result<Vec<(String, Vec<Value<'post_build>>)>, Error> {
+        let (name, primary_key, columns) = match &self {
+            #[cfg(feature = "sqlite")]
+            CreateTableImpl::SQLite(d) => (d.name, d.primary_key, &d.columns),
+            #[cfg(feature = "mysql")]
+            CreateTableImpl::MySQL(d) => (d.name, d.primary_key, &d.columns),
+            #[cfg(feature = "postgres")]
+            CreateTableImpl::Postgres(d) => (d.name, d.primary_key, &d.columns),
+        };
+        if !primary_key.is_empty() && columns.iter().any(CreateColumnImpl::is_primary_key) {
+            return Err(Error::SQLBuildError(format!(
+                "Couldn't create table {name}: Composite primary key conflicts with primary key column"
+            )));
+        }
+
         match self {
             #[cfg(feature = "sqlite")]
             CreateTableImpl::SQLite(mut d) => {
+                if d.on_commit_drop {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support ON COMMIT DROP".to_string(),
+                    ));
+                }
+
                 let mut s = format!(
-                    "CREATE TABLE{} \"{}\" (",
+                    "CREATE{} TABLE{} {} (",
+                    if d.temporary { " TEMP" } else { "" },
                     if d.if_not_exists {
                         " IF NOT EXISTS"
                     } else {
                         ""
                     },
-                    d.name
+                    d.name.quoted(DBImpl::SQLite)
                 );
 
                 let columns_len = d.columns.len() - 1;
@@ -122,7 +345,36 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
                     }
                 }
 
-                write!(s, ") STRICT; ").unwrap();
+                if !d.primary_key.is_empty() {
+                    write!(s, ", PRIMARY KEY (").unwrap();
+                    for (idx, column) in d.primary_key.iter().enumerate() {
+                        write!(s, "\"{column}\"").unwrap();
+                        if idx != d.primary_key.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ")").unwrap();
+                }
+
+                for (name, columns) in d.uniques {
+                    write!(s, ", ").unwrap();
+                    write_unique(&mut s, DBImpl::SQLite, name, columns)?;
+                }
+
+                for (name, condition) in d.checks {
+                    if let Some(name) = name {
+                        write!(s, ", CONSTRAINT \"{name}\"").unwrap();
+                    } else {
+                        write!(s, ",").unwrap();
+                    }
+                    write!(s, " CHECK ({})", condition.build_inlined(DBImpl::SQLite)?).unwrap();
+                }
+
+                write!(s, ")").unwrap();
+                if d.without_rowid {
+                    write!(s, " WITHOUT ROWID,").unwrap();
+                }
+                write!(s, " STRICT; ").unwrap();
 
                 let mut statements = vec![(s, d.lookup)];
                 statements.extend(d.statements);
@@ -131,14 +383,21 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
             }
             #[cfg(feature = "mysql")]
             CreateTableImpl::MySQL(mut d) => {
+                if d.on_commit_drop {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support ON COMMIT DROP".to_string(),
+                    ));
+                }
+
                 let mut s = format!(
-                    "CREATE TABLE{} `{}` (",
+                    "CREATE{} TABLE{} {} (",
+                    if d.temporary { " TEMPORARY" } else { "" },
                     if d.if_not_exists {
                         " IF NOT EXISTS"
                     } else {
                         ""
                     },
-                    d.name
+                    d.name.quoted(DBImpl::MySQL)
                 );
 
                 let columns_len = d.columns.len() - 1;
@@ -160,7 +419,39 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
                     }
                 }
 
-                write!(s, "); ").unwrap();
+                if !d.primary_key.is_empty() {
+                    write!(s, ", PRIMARY KEY (").unwrap();
+                    for (idx, column) in d.primary_key.iter().enumerate() {
+                        write!(s, "`{column}`").unwrap();
+                        if idx != d.primary_key.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ")").unwrap();
+                }
+
+                for (name, columns) in d.uniques {
+                    write!(s, ", ").unwrap();
+                    write_unique(&mut s, DBImpl::MySQL, name, columns)?;
+                }
+
+                for (name, condition) in d.checks {
+                    if let Some(name) = name {
+                        write!(s, ", CONSTRAINT `{name}`").unwrap();
+                    } else {
+                        write!(s, ",").unwrap();
+                    }
+                    write!(s, " CHECK ({})", condition.build_inlined(DBImpl::MySQL)?).unwrap();
+                }
+
+                write!(s, ")").unwrap();
+                if let Some(comment) = d.comment {
+                    write!(s, " COMMENT={}", mysql::fmt(comment)).unwrap();
+                }
+                for (key, value) in d.options {
+                    write!(s, " {key}={value}").unwrap();
+                }
+                write!(s, "; ").unwrap();
 
                 let mut statements = vec![(s, d.lookup)];
                 statements.extend(d.statements);
@@ -169,16 +460,35 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
             }
             #[cfg(feature = "postgres")]
             CreateTableImpl::Postgres(mut d) => {
+                if d.on_commit_drop && !d.temporary {
+                    return Err(Error::SQLBuildError(format!(
+                        "Couldn't create table {}: ON COMMIT DROP requires a temporary table",
+                        d.name
+                    )));
+                }
+
                 let mut s = format!(
-                    "CREATE TABLE{} \"{}\" (",
+                    "CREATE{} TABLE{} {} (",
+                    if d.temporary { " TEMPORARY" } else { "" },
                     if d.if_not_exists {
                         " IF NOT EXISTS"
                     } else {
                         ""
                     },
-                    d.name
+                    d.name.quoted(DBImpl::Postgres)
                 );
 
+                if let Some(comment) = d.comment {
+                    d.statements.push((
+                        format!(
+                            "COMMENT ON TABLE {} IS {};",
+                            d.name.quoted(DBImpl::Postgres),
+                            postgres::fmt(comment)
+                        ),
+                        vec![],
+                    ));
+                }
+
                 let columns_len = d.columns.len() - 1;
                 for (idx, mut x) in d.columns.into_iter().enumerate() {
                     #[cfg(any(feature = "sqlite", feature = "mysql"))]
@@ -200,7 +510,36 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
                     }
                 }
 
-                write!(s, "); ").unwrap();
+                if !d.primary_key.is_empty() {
+                    write!(s, ", PRIMARY KEY (").unwrap();
+                    for (idx, column) in d.primary_key.iter().enumerate() {
+                        write!(s, "\"{column}\"").unwrap();
+                        if idx != d.primary_key.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ")").unwrap();
+                }
+
+                for (name, columns) in d.uniques {
+                    write!(s, ", ").unwrap();
+                    write_unique(&mut s, DBImpl::Postgres, name, columns)?;
+                }
+
+                for (name, condition) in d.checks {
+                    if let Some(name) = name {
+                        write!(s, ", CONSTRAINT \"{name}\"").unwrap();
+                    } else {
+                        write!(s, ",").unwrap();
+                    }
+                    write!(s, " CHECK ({})", condition.build_inlined(DBImpl::Postgres)?).unwrap();
+                }
+
+                write!(s, ")").unwrap();
+                if d.on_commit_drop {
+                    write!(s, " ON COMMIT DROP").unwrap();
+                }
+                write!(s, "; ").unwrap();
 
                 let mut statements = d.pre_statements;
                 statements.push((s, d.lookup));
@@ -211,3 +550,520 @@ impl<'until_build, 'post_build> CreateTable<'until_build, 'post_build>
         }
     }
 }
+
+/**
+Writes a UNIQUE constraint over the given columns, e.g. `CONSTRAINT "name" UNIQUE ("a", "b")`.
+*/
+pub(crate) fn write_unique(
+    s: &mut String,
+    dialect: DBImpl,
+    name: Option<&str>,
+    columns: &[&str],
+) -> Result<(), Error> {
+    if columns.is_empty() {
+        return Err(Error::EmptyColumnList {
+            statement: "UNIQUE",
+        });
+    }
+
+    if let Some(name) = name {
+        write!(s, "CONSTRAINT {} ", dialect.quote_identifier(name)).unwrap();
+    }
+    write!(s, "UNIQUE (").unwrap();
+    for (idx, column) in columns.iter().enumerate() {
+        if idx > 0 {
+            write!(s, ", ").unwrap();
+        }
+        write!(s, "{}", dialect.quote_identifier(column)).unwrap();
+    }
+    write!(s, ")").unwrap();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use rorm_declaration::imr::{Annotation, DbType};
+
+    use crate::conditional::{BinaryCondition, Condition};
+    use crate::create_table::CreateTable;
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    use crate::error::Error;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn check_sqlite() {
+        let ordered = Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+            Condition::Value(Value::Ident("start")),
+            Condition::Value(Value::Ident("end")),
+        ])));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("range")
+                .add_column(DBImpl::SQLite.create_column("range", "start", DbType::Int64, &[]))
+                .add_column(DBImpl::SQLite.create_column("range", "end", DbType::Int64, &[]))
+                .check(Some("ordered"), &ordered)
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"range\" (\"start\" INTEGER , \"end\" INTEGER , CONSTRAINT \"ordered\" CHECK ((start < end))) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn check_mysql() {
+        let positive = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("start")),
+            Condition::Value(Value::I64(0)),
+        ])));
+
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("range")
+                .add_column(DBImpl::MySQL.create_column("range", "start", DbType::Int64, &[]))
+                .check(None, &positive)
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `range` (`start` BIGINT(255) , CHECK ((start >= 0))); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn check_postgres() {
+        let ordered = Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+            Condition::Value(Value::Ident("start")),
+            Condition::Value(Value::Ident("end")),
+        ])));
+        let positive = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("start")),
+            Condition::Value(Value::I64(0)),
+        ])));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("range")
+                .add_column(DBImpl::Postgres.create_column("range", "start", DbType::Int64, &[]))
+                .add_column(DBImpl::Postgres.create_column("range", "end", DbType::Int64, &[]))
+                .check(Some("ordered"), &ordered)
+                .check(None, &positive)
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"range\" (\"start\" bigint , \"end\" bigint , CONSTRAINT \"ordered\" CHECK ((start < end)), CHECK ((start >= 0))); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn primary_key_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("membership")
+                .add_column(DBImpl::SQLite.create_column(
+                    "membership",
+                    "user_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .add_column(DBImpl::SQLite.create_column(
+                    "membership",
+                    "group_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .primary_key(&["user_id", "group_id"])
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"membership\" (\"user_id\" INTEGER , \"group_id\" INTEGER , PRIMARY KEY (\"user_id\", \"group_id\")) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn primary_key_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("membership")
+                .add_column(DBImpl::MySQL.create_column(
+                    "membership",
+                    "user_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .primary_key(&["user_id"])
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `membership` (`user_id` BIGINT(255) , PRIMARY KEY (`user_id`)); "
+                    .to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn primary_key_postgres() {
+        let primary_key = [Annotation::PrimaryKey];
+
+        assert!(matches!(
+            DBImpl::Postgres
+                .create_table("membership")
+                .add_column(DBImpl::Postgres.create_column(
+                    "membership",
+                    "user_id",
+                    DbType::Int64,
+                    &primary_key
+                ))
+                .primary_key(&["user_id", "group_id"])
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn temporary_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("scratch")
+                .temporary()
+                .if_not_exists()
+                .add_column(DBImpl::SQLite.create_column("scratch", "id", DbType::Int64, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TEMP TABLE IF NOT EXISTS \"scratch\" (\"id\" INTEGER ) STRICT; "
+                    .to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn temporary_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("scratch")
+                .temporary()
+                .add_column(DBImpl::MySQL.create_column("scratch", "id", DbType::Int64, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TEMPORARY TABLE `scratch` (`id` BIGINT(255) ); ".to_string(),
+                vec![]
+            )]
+        );
+        assert!(matches!(
+            DBImpl::MySQL
+                .create_table("scratch")
+                .temporary()
+                .on_commit_drop()
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn temporary_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("scratch")
+                .temporary()
+                .on_commit_drop()
+                .add_column(DBImpl::Postgres.create_column("scratch", "id", DbType::Int64, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TEMPORARY TABLE \"scratch\" (\"id\" bigint ) ON COMMIT DROP; ".to_string(),
+                vec![]
+            )]
+        );
+        assert!(matches!(
+            DBImpl::Postgres
+                .create_table("scratch")
+                .on_commit_drop()
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn table_option_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("user")
+                .add_column(DBImpl::SQLite.create_column("user", "id", DbType::Int64, &[]))
+                .table_option("ENGINE", "InnoDB")
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"user\" (\"id\" INTEGER ) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn table_option_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("user")
+                .add_column(DBImpl::MySQL.create_column("user", "id", DbType::Int64, &[]))
+                .table_option("ENGINE", "InnoDB")
+                .table_option("DEFAULT CHARSET", "utf8mb4")
+                .table_option("COLLATE", "utf8mb4_unicode_ci")
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `user` (`id` BIGINT(255) ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn table_option_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("user")
+                .add_column(DBImpl::Postgres.create_column("user", "id", DbType::Int64, &[]))
+                .table_option("DEFAULT CHARSET", "utf8mb4")
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"user\" (\"id\" bigint ); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn without_rowid_sqlite() {
+        let primary_key = [Annotation::PrimaryKey];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("setting")
+                .add_column(DBImpl::SQLite.create_column(
+                    "setting",
+                    "key",
+                    DbType::Int64,
+                    &primary_key
+                ))
+                .without_rowid()
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"setting\" (\"key\" INTEGER PRIMARY KEY) WITHOUT ROWID, STRICT; "
+                    .to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn without_rowid_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("setting")
+                .add_column(DBImpl::MySQL.create_column("setting", "key", DbType::Int64, &[]))
+                .without_rowid()
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `setting` (`key` BIGINT(255) ); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn without_rowid_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("setting")
+                .add_column(DBImpl::Postgres.create_column("setting", "key", DbType::Int64, &[]))
+                .without_rowid()
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"setting\" (\"key\" bigint ); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn comment_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("user")
+                .comment("Registered users")
+                .add_column(
+                    DBImpl::SQLite
+                        .create_column("user", "id", DbType::Int64, &[])
+                        .comment("Internal id"),
+                )
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"user\" (\"id\" INTEGER ) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn comment_mysql() {
+        let not_null = [Annotation::NotNull];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("user")
+                .comment("Registered users")
+                .add_column(
+                    DBImpl::MySQL
+                        .create_column("user", "id", DbType::Int64, &not_null)
+                        .comment("Internal id"),
+                )
+                .add_column(DBImpl::MySQL.create_column("user", "name", DbType::Int64, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `user` (`id` BIGINT(255) NOT NULL COMMENT 'Internal id', `name` BIGINT(255) ) COMMENT='Registered users'; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn comment_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("user")
+                .comment("Registered users")
+                .add_column(
+                    DBImpl::Postgres
+                        .create_column("user", "id", DbType::Int64, &[])
+                        .comment("Internal id"),
+                )
+                .build()
+                .unwrap(),
+            [
+                (
+                    "CREATE TABLE \"user\" (\"id\" bigint ); ".to_string(),
+                    vec![]
+                ),
+                (
+                    "COMMENT ON TABLE \"user\" IS 'Registered users';".to_string(),
+                    vec![]
+                ),
+                (
+                    "COMMENT ON COLUMN \"user\".\"id\" IS 'Internal id';".to_string(),
+                    vec![]
+                )
+            ]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn unique_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table("membership")
+                .add_column(DBImpl::SQLite.create_column(
+                    "membership",
+                    "user_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .add_column(DBImpl::SQLite.create_column(
+                    "membership",
+                    "group_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .unique(Some("unique_membership"), &["user_id", "group_id"])
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"membership\" (\"user_id\" INTEGER , \"group_id\" INTEGER , CONSTRAINT \"unique_membership\" UNIQUE (\"user_id\", \"group_id\")) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn unique_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table("user")
+                .add_column(DBImpl::MySQL.create_column("user", "email", DbType::Int64, &[]))
+                .unique(None, &["email"])
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `user` (`email` BIGINT(255) , UNIQUE (`email`)); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn unique_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_table("membership")
+                .add_column(DBImpl::Postgres.create_column(
+                    "membership",
+                    "user_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .add_column(DBImpl::Postgres.create_column(
+                    "membership",
+                    "group_id",
+                    DbType::Int64,
+                    &[]
+                ))
+                .unique(Some("unique_membership"), &["user_id", "group_id"])
+                .unique(None, &["group_id"])
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"membership\" (\"user_id\" bigint , \"group_id\" bigint , CONSTRAINT \"unique_membership\" UNIQUE (\"user_id\", \"group_id\"), UNIQUE (\"group_id\")); ".to_string(),
+                vec![]
+            )]
+        );
+    }
+}
@@ -5,7 +5,9 @@ use rorm_declaration::imr::Annotation;
 #[cfg(feature = "sqlite")]
 use rorm_declaration::imr::DbType;
 
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use crate::table_ref::TableRef;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
 use crate::DBImpl;
 #[cfg(any(feature = "sqlite", feature = "postgres"))]
 use crate::Value;
@@ -64,29 +66,32 @@ impl Display for SQLCreateTriggerOperation {
 #[cfg(feature = "postgres")]
 pub(crate) fn trigger_annotation_to_trigger_postgres(
     annotation: &Annotation,
-    table_name: &str,
+    table: TableRef,
     column_name: &str,
     statements: &mut Vec<(String, Vec<Value>)>,
 ) {
     if annotation == &Annotation::AutoUpdateTime {
+        // The function is qualified by the schema, the trigger always lives in the table's schema
+        let table_name = table.name;
+        let quoted_table = table.quoted(DBImpl::Postgres);
         statements.push(
             (
                 format!(
-                    "CREATE OR REPLACE FUNCTION {table_name}_{column_name}_auto_update_time_update_procedure() RETURNS TRIGGER AS $$ BEGIN NEW.{column_name} = now(); RETURN NEW; END; $$ language 'plpgsql';"
+                    "CREATE OR REPLACE FUNCTION {table}_{column_name}_auto_update_time_update_procedure() RETURNS TRIGGER AS $$ BEGIN NEW.{column_name} = now(); RETURN NEW; END; $$ language 'plpgsql';"
                 ),
                 vec![],
             )
         );
         statements.push((
             format!(
-                "DROP TRIGGER IF EXISTS {table_name}_{column_name}_auto_update_time_update ON \"{table_name}\";"
+                "DROP TRIGGER IF EXISTS {table_name}_{column_name}_auto_update_time_update ON {quoted_table};"
             ),
             vec![],
         ));
         statements.push(
             (
                 format!(
-                    "CREATE TRIGGER {table_name}_{column_name}_auto_update_time_update BEFORE UPDATE ON \"{table_name}\" FOR EACH ROW WHEN (OLD IS DISTINCT FROM NEW) EXECUTE PROCEDURE {table_name}_{column_name}_auto_update_time_update_procedure();"
+                    "CREATE TRIGGER {table_name}_{column_name}_auto_update_time_update BEFORE UPDATE ON {quoted_table} FOR EACH ROW WHEN (OLD IS DISTINCT FROM NEW) EXECUTE PROCEDURE {table}_{column_name}_auto_update_time_update_procedure();"
                 ),
                 vec![],
             )
@@ -99,11 +104,13 @@ pub(crate) fn trigger_annotation_to_trigger_postgres(
 pub(crate) fn trigger_annotation_to_trigger_sqlite(
     annotation: &Annotation,
     db_type: &DbType,
-    table_name: &str,
+    table: TableRef,
     column_name: &str,
     statements: &mut Vec<(String, Vec<Value>)>,
 ) {
     if annotation == &Annotation::AutoUpdateTime {
+        // SQLite qualifies the trigger, the table has to be in the same database
+        let table_name = table.name;
         let update_statement = format!(
             "UPDATE {} SET {} = {} WHERE ROWID = NEW.ROWID;",
             table_name,
@@ -119,7 +126,7 @@ pub(crate) fn trigger_annotation_to_trigger_sqlite(
         statements.push((
             DBImpl::SQLite
                 .create_trigger(
-                    format!("{table_name}_{column_name}_auto_update_time").as_str(),
+                    format!("{table}_{column_name}_auto_update_time").as_str(),
                     table_name,
                     Some(SQLCreateTriggerPointInTime::After),
                     SQLCreateTriggerOperation::Update { columns: None },
@@ -140,6 +147,7 @@ pub struct SQLCreateTrigger {
     pub(crate) name: String,
     pub(crate) table_name: String,
     pub(crate) if_not_exists: bool,
+    pub(crate) or_replace: bool,
     pub(crate) point_in_time: Option<SQLCreateTriggerPointInTime>,
     pub(crate) operation: SQLCreateTriggerOperation,
     pub(crate) statements: Vec<String>,
@@ -155,6 +163,17 @@ impl SQLCreateTrigger {
         self
     }
 
+    /**
+    Replace the trigger if it already exists.
+
+    Supported by Postgres 14 and newer and MariaDB, but not by SQLite and MySQL.
+    Takes precedence over [SQLCreateTrigger::if_not_exists].
+    */
+    pub fn or_replace(mut self) -> Self {
+        self.or_replace = true;
+        self
+    }
+
     /**
     Adds a statement to a create trigger operation
     */
@@ -176,9 +195,10 @@ impl SQLCreateTrigger {
     */
     pub fn build(self) -> String {
         format!(
-            "CREATE TRIGGER {} {} {} {} ON {}{} BEGIN {} END;",
-            if self.if_not_exists {
-                "IF NOT EXISTS"
+            "CREATE{} TRIGGER{} {} {} {} ON {}{} BEGIN {} END;",
+            if self.or_replace { " OR REPLACE" } else { "" },
+            if self.if_not_exists && !self.or_replace {
+                " IF NOT EXISTS"
             } else {
                 ""
             },
@@ -197,3 +217,56 @@ impl SQLCreateTrigger {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use crate::create_trigger::{SQLCreateTriggerOperation, SQLCreateTriggerPointInTime};
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn if_not_exists_sqlite() {
+        let touch = || {
+            DBImpl::SQLite
+                .create_trigger(
+                    "touch",
+                    "user",
+                    Some(SQLCreateTriggerPointInTime::After),
+                    SQLCreateTriggerOperation::Insert,
+                )
+                .for_each_row()
+                .add_statement("SELECT 1;".to_string())
+        };
+
+        assert_eq!(
+            touch().build(),
+            "CREATE TRIGGER touch AFTER INSERT ON user FOR EACH ROW BEGIN SELECT 1; END;"
+        );
+        assert_eq!(
+            touch().if_not_exists().build(),
+            "CREATE TRIGGER IF NOT EXISTS touch AFTER INSERT ON user FOR EACH ROW BEGIN SELECT 1; END;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn or_replace_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .create_trigger(
+                    "audit",
+                    "post",
+                    Some(SQLCreateTriggerPointInTime::Before),
+                    SQLCreateTriggerOperation::Delete,
+                )
+                .for_each_row()
+                .add_statement("SELECT 1;".to_string())
+                .or_replace()
+                .if_not_exists()
+                .build(),
+            "CREATE OR REPLACE TRIGGER audit BEFORE DELETE ON post FOR EACH ROW BEGIN SELECT 1; END;"
+        );
+    }
+}
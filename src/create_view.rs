@@ -0,0 +1,474 @@
+use std::fmt::Write;
+
+use crate::error::Error;
+use crate::query::{inline_values, Query};
+use crate::select::SelectImpl;
+use crate::value::Value;
+use crate::DBImpl;
+
+/**
+Trait representing a create view builder.
+*/
+pub trait CreateView<'until_build, 'post_build> {
+    /**
+    Set the names of the columns of the view.
+
+    If not set, the column names are derived from the query.
+
+    **Parameter**:
+    - `columns`: Names of the columns.
+     */
+    fn columns(self, columns: &'until_build [&'until_build str]) -> Self;
+
+    /**
+    Set the query the view is defined by.
+
+    **Parameter**:
+    - `query`: Query to define the view.
+     */
+    #[allow(clippy::wrong_self_convention)]
+    fn as_select(self, query: SelectImpl<'until_build, 'post_build>) -> Self;
+
+    /**
+    Replace the view if it already exists.
+
+    Not supported by SQLite, drop the view before recreating it instead.
+     */
+    fn or_replace(self) -> Self;
+
+    /**
+    Creates the view only if it doesn't exist yet.
+
+    Only supported by SQLite.
+     */
+    fn if_not_exists(self) -> Self;
+
+    /**
+    Build the create view operation.
+
+    Views don't support bind parameters, so the values of the query are written as literals,
+    see [Value::to_sql_literal].
+    Returns an error, if a value has no literal representation
+    or the query was built for another dialect.
+
+    **Returns**:
+    - SQL query string
+    - List of [Value] parameters to bind to the query, always empty.
+     */
+    fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error>;
+
+    /**
+    Same as [CreateView::build], but the statement and its values are returned as [Query].
+     */
+    fn build_query(self) -> Result<Query<'post_build>, Error>
+    where
+        Self: Sized,
+    {
+        self.build().map(Query::from)
+    }
+}
+
+/**
+Representation of the data of a CREATE VIEW operation.
+*/
+#[derive(Debug)]
+pub struct CreateViewData<'until_build, 'post_build> {
+    pub(crate) name: &'until_build str,
+    pub(crate) columns: &'until_build [&'until_build str],
+    pub(crate) query: Option<SelectImpl<'until_build, 'post_build>>,
+    pub(crate) or_replace: bool,
+    pub(crate) if_not_exists: bool,
+}
+
+/**
+Implementation of the [CreateView] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::create_view].
+*/
+#[derive(Debug)]
+pub enum CreateViewImpl<'until_build, 'post_build> {
+    /**
+    SQLite representation of the CREATE VIEW operation.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(CreateViewData<'until_build, 'post_build>),
+    /**
+    MySQL representation of the CREATE VIEW operation.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(CreateViewData<'until_build, 'post_build>),
+    /**
+    Postgres representation of the CREATE VIEW operation.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(CreateViewData<'until_build, 'post_build>),
+}
+
+impl<'until_build, 'post_build> CreateView<'until_build, 'post_build>
+    for CreateViewImpl<'until_build, 'post_build>
+{
+    fn columns(mut self, columns: &'until_build [&'until_build str]) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateViewImpl::SQLite(ref mut d) => d.columns = columns,
+            #[cfg(feature = "mysql")]
+            CreateViewImpl::MySQL(ref mut d) => d.columns = columns,
+            #[cfg(feature = "postgres")]
+            CreateViewImpl::Postgres(ref mut d) => d.columns = columns,
+        };
+        self
+    }
+
+    fn as_select(mut self, query: SelectImpl<'until_build, 'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateViewImpl::SQLite(ref mut d) => d.query = Some(query),
+            #[cfg(feature = "mysql")]
+            CreateViewImpl::MySQL(ref mut d) => d.query = Some(query),
+            #[cfg(feature = "postgres")]
+            CreateViewImpl::Postgres(ref mut d) => d.query = Some(query),
+        };
+        self
+    }
+
+    fn or_replace(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateViewImpl::SQLite(ref mut d) => d.or_replace = true,
+            #[cfg(feature = "mysql")]
+            CreateViewImpl::MySQL(ref mut d) => d.or_replace = true,
+            #[cfg(feature = "postgres")]
+            CreateViewImpl::Postgres(ref mut d) => d.or_replace = true,
+        };
+        self
+    }
+
+    fn if_not_exists(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateViewImpl::SQLite(ref mut d) => d.if_not_exists = true,
+            #[cfg(feature = "mysql")]
+            CreateViewImpl::MySQL(ref mut d) => d.if_not_exists = true,
+            #[cfg(feature = "postgres")]
+            CreateViewImpl::Postgres(ref mut d) => d.if_not_exists = true,
+        };
+        self
+    }
+
+    fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            CreateViewImpl::SQLite(d) => {
+                if d.or_replace {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support CREATE OR REPLACE VIEW, drop the view first"
+                            .to_string(),
+                    ));
+                }
+                let query = d.query.ok_or_else(|| missing_query(d.name))?;
+
+                let mut s = format!(
+                    "CREATE VIEW{} \"{}\" ",
+                    if d.if_not_exists {
+                        " IF NOT EXISTS"
+                    } else {
+                        ""
+                    },
+                    d.name
+                );
+                if !d.columns.is_empty() {
+                    write!(s, "(").unwrap();
+                    for (idx, column) in d.columns.iter().enumerate() {
+                        write!(s, "\"{column}\"").unwrap();
+                        if idx != d.columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ") ").unwrap();
+                }
+                write!(s, "AS ").unwrap();
+                write_query(&mut s, &query, DBImpl::SQLite)?;
+                write!(s, ";").unwrap();
+
+                Ok((s, vec![]))
+            }
+            #[cfg(feature = "mysql")]
+            CreateViewImpl::MySQL(d) => {
+                if d.if_not_exists {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support CREATE VIEW IF NOT EXISTS".to_string(),
+                    ));
+                }
+                let query = d.query.ok_or_else(|| missing_query(d.name))?;
+
+                let mut s = format!(
+                    "CREATE{} VIEW `{}` ",
+                    if d.or_replace { " OR REPLACE" } else { "" },
+                    d.name
+                );
+                if !d.columns.is_empty() {
+                    write!(s, "(").unwrap();
+                    for (idx, column) in d.columns.iter().enumerate() {
+                        write!(s, "`{column}`").unwrap();
+                        if idx != d.columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ") ").unwrap();
+                }
+                write!(s, "AS ").unwrap();
+                write_query(&mut s, &query, DBImpl::MySQL)?;
+                write!(s, ";").unwrap();
+
+                Ok((s, vec![]))
+            }
+            #[cfg(feature = "postgres")]
+            CreateViewImpl::Postgres(d) => {
+                if d.if_not_exists {
+                    return Err(Error::UnsupportedFeature(
+                        "Postgres doesn't support CREATE VIEW IF NOT EXISTS".to_string(),
+                    ));
+                }
+                let query = d.query.ok_or_else(|| missing_query(d.name))?;
+
+                let mut s = format!(
+                    "CREATE{} VIEW \"{}\" ",
+                    if d.or_replace { " OR REPLACE" } else { "" },
+                    d.name
+                );
+                if !d.columns.is_empty() {
+                    write!(s, "(").unwrap();
+                    for (idx, column) in d.columns.iter().enumerate() {
+                        write!(s, "\"{column}\"").unwrap();
+                        if idx != d.columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ") ").unwrap();
+                }
+                write!(s, "AS ").unwrap();
+                write_query(&mut s, &query, DBImpl::Postgres)?;
+                write!(s, ";").unwrap();
+
+                Ok((s, vec![]))
+            }
+        }
+    }
+}
+
+/**
+Write the query with its values as literals, as views don't support bind parameters.
+ */
+fn write_query(s: &mut String, query: &SelectImpl, dialect: DBImpl) -> Result<(), Error> {
+    query.check_dialect(dialect)?;
+    let mut sql = String::new();
+    let mut lookup = vec![];
+    query.build_to_writer(&mut sql, &mut lookup)?;
+    s.push_str(&inline_values(&sql, &lookup, dialect)?);
+    Ok(())
+}
+
+fn missing_query(name: &str) -> Error {
+    Error::SQLBuildError(format!("Couldn't create view {name}: Missing query"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conditional::BinaryCondition;
+    use crate::conditional::Condition;
+    use crate::create_view::CreateView;
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use crate::error::Error;
+    use crate::select::Select;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn create_view_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let columns = [
+            DBImpl::SQLite.select_column(None, "id", None, None),
+            DBImpl::SQLite.select_column(None, "name", None, None),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_view("active_user")
+                .columns(&["user_id", "user_name"])
+                .as_select(
+                    DBImpl::SQLite
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .build()
+                .unwrap(),
+            (
+                "CREATE VIEW \"active_user\" (\"user_id\", \"user_name\") AS SELECT id, name FROM user WHERE (active = 1);".to_string(),
+                vec![]
+            )
+        );
+        assert!(matches!(
+            DBImpl::SQLite
+                .create_view("active_user")
+                .or_replace()
+                .as_select(DBImpl::SQLite.select(&columns, "user", &[], &[]))
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn create_view_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let columns = [
+            DBImpl::MySQL.select_column(None, "id", None, None),
+            DBImpl::MySQL.select_column(None, "name", None, None),
+        ];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .create_view("active_user")
+                .or_replace()
+                .columns(&["user_id", "user_name"])
+                .as_select(
+                    DBImpl::MySQL
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .build()
+                .unwrap(),
+            (
+                "CREATE OR REPLACE VIEW `active_user` (`user_id`, `user_name`) AS SELECT `id`, `name` FROM user WHERE (active = 1);".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn create_view_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .create_view("adult")
+                .columns(&["user_id"])
+                .as_select(
+                    DBImpl::Postgres
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .build()
+                .unwrap(),
+            (
+                "CREATE VIEW \"adult\" (\"user_id\") AS SELECT \"id\" FROM \"user\" WHERE (age >= 18);".to_string(),
+                vec![]
+            )
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .create_view("adult")
+                .or_replace()
+                .as_select(DBImpl::Postgres.select(&columns, "user", &[], &[]))
+                .build()
+                .unwrap(),
+            (
+                "CREATE OR REPLACE VIEW \"adult\" AS SELECT \"id\" FROM \"user\";".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn bound_constants_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("O'Brien")),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_view("obrien")
+                .as_select(
+                    DBImpl::SQLite
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .build()
+                .unwrap(),
+            (
+                "CREATE VIEW \"obrien\" AS SELECT id FROM user WHERE (name = 'O''Brien');"
+                    .to_string(),
+                vec![]
+            )
+        );
+
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("score")),
+            Condition::Value(Value::F64(f64::NAN)),
+        ])));
+        assert!(matches!(
+            DBImpl::SQLite
+                .create_view("nan")
+                .as_select(
+                    DBImpl::SQLite
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(all(feature = "sqlite", feature = "postgres"))]
+    #[test]
+    fn other_dialect() {
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::SQLite
+                .create_view("ids")
+                .as_select(DBImpl::Postgres.select(&columns, "user", &[], &[]))
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn if_not_exists_sqlite() {
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+        let (sql, _) = DBImpl::SQLite
+            .create_view("ids")
+            .if_not_exists()
+            .as_select(DBImpl::SQLite.select(&columns, "user", &[], &[]))
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE VIEW IF NOT EXISTS \"ids\" AS SELECT id FROM user;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn missing_query_postgres() {
+        assert!(matches!(
+            DBImpl::Postgres.create_view("empty").build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+}
@@ -1,6 +1,26 @@
+use rorm_declaration::imr::DbType;
+
+use crate::error::Error;
+use crate::DBImpl;
+
 #[cfg(feature = "mysql")]
 pub(crate) mod mysql;
 #[cfg(feature = "postgres")]
 pub(crate) mod postgres;
 #[cfg(feature = "sqlite")]
 pub(crate) mod sqlite;
+
+/**
+Returns the type to CAST the given [DbType] to in the given dialect.
+*/
+pub(crate) fn cast_type(dialect: DBImpl, db_type: DbType) -> Result<&'static str, Error> {
+    let cast_type = match dialect {
+        #[cfg(feature = "sqlite")]
+        DBImpl::SQLite => sqlite::data_type(db_type),
+        #[cfg(feature = "mysql")]
+        DBImpl::MySQL => mysql::cast_type(db_type),
+        #[cfg(feature = "postgres")]
+        DBImpl::Postgres => Some(postgres::cast_type(db_type)),
+    };
+    cast_type.ok_or_else(|| Error::UnsupportedFeature(format!("Can't cast to {db_type:?}")))
+}
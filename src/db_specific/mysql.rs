@@ -1,8 +1,35 @@
+use rorm_declaration::imr::DbType;
+
 /// Formats the given input to a escaped mariadb string.
+///
+/// Quotes are doubled and backslashes escaped, so the string is terminated
+/// correctly with and without `NO_BACKSLASH_ESCAPES`.
 pub(crate) fn fmt(input: &str) -> String {
-    if input.contains('\'') {
-        format!("'{}'", input.replace('\'', "\\'"))
+    if input.contains(['\'', '\\']) {
+        format!("'{}'", input.replace('\\', "\\\\").replace('\'', "''"))
     } else {
         format!("'{input}'")
     }
 }
+
+/**
+Returns the type to CAST the given [DbType] to.
+
+CAST only accepts a subset of the column types, so integers are cast to `SIGNED`.
+BitVec, MacAddress and IpNetwork are not available for MySQL.
+*/
+pub(crate) fn cast_type(db_type: DbType) -> Option<&'static str> {
+    match db_type {
+        DbType::VarChar | DbType::Choices => Some("CHAR"),
+        DbType::Binary | DbType::Uuid => Some("BINARY"),
+        DbType::Int8 | DbType::Int16 | DbType::Int32 | DbType::Int64 | DbType::Boolean => {
+            Some("SIGNED")
+        }
+        DbType::Float => Some("FLOAT"),
+        DbType::Double => Some("DOUBLE"),
+        DbType::Date => Some("DATE"),
+        DbType::DateTime | DbType::Timestamp => Some("DATETIME"),
+        DbType::Time => Some("TIME"),
+        DbType::BitVec | DbType::MacAddress | DbType::IpNetwork => None,
+    }
+}
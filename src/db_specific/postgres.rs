@@ -1,8 +1,46 @@
+use rorm_declaration::imr::DbType;
+
 /// Formats the given input to a escaped postgres string.
+///
+/// Quotes are doubled, backslashes are taken literally by standard conforming strings.
 pub(crate) fn fmt(input: &str) -> String {
     if input.contains('\'') {
-        format!("'{}'", input.replace('\'', "\\'"))
+        format!("'{}'", input.replace('\'', "''"))
     } else {
         format!("'{input}'")
     }
 }
+
+/// Formats a call to `nextval` of the sequence with the given name.
+pub(crate) fn nextval(sequence: &str) -> String {
+    format!(
+        "nextval({})",
+        fmt(&crate::DBImpl::Postgres.quote_identifier(sequence))
+    )
+}
+
+/**
+Returns the type to CAST the given [DbType] to.
+
+Choices are cast to varchar, as their enum type is specific to the column.
+*/
+pub(crate) fn cast_type(db_type: DbType) -> &'static str {
+    match db_type {
+        DbType::VarChar | DbType::Choices => "varchar",
+        DbType::Binary => "bytea",
+        DbType::Int8 | DbType::Int16 => "smallint",
+        DbType::Int32 => "integer",
+        DbType::Int64 => "bigint",
+        DbType::Float => "real",
+        DbType::Double => "double precision",
+        DbType::Boolean => "boolean",
+        DbType::Date => "date",
+        DbType::DateTime => "timestamptz",
+        DbType::Timestamp => "timestamp",
+        DbType::Time => "time",
+        DbType::Uuid => "uuid",
+        DbType::MacAddress => "macaddr",
+        DbType::IpNetwork => "inet",
+        DbType::BitVec => "varbit",
+    }
+}
@@ -1,5 +1,7 @@
 use std::ffi::{c_char, c_void, CStr, CString};
 
+use rorm_declaration::imr::DbType;
+
 /**
 This function formats a string into a SQLite quoted string.
 */
@@ -23,3 +25,25 @@ pub(crate) fn fmt(input: &str) -> String {
 
     formatted
 }
+
+/**
+Returns the SQLite type of the given [DbType].
+
+BitVec, MacAddress and IpNetwork are not available for SQLite.
+*/
+pub(crate) fn data_type(db_type: DbType) -> Option<&'static str> {
+    match db_type {
+        DbType::Binary | DbType::Uuid => Some("BLOB"),
+        DbType::VarChar
+        | DbType::Date
+        | DbType::DateTime
+        | DbType::Timestamp
+        | DbType::Time
+        | DbType::Choices => Some("TEXT"),
+        DbType::Int8 | DbType::Int16 | DbType::Int32 | DbType::Int64 | DbType::Boolean => {
+            Some("INTEGER")
+        }
+        DbType::Float | DbType::Double => Some("REAL"),
+        DbType::BitVec | DbType::MacAddress | DbType::IpNetwork => None,
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::Write;
 
-use crate::conditional::{BuildCondition, Condition};
+use crate::conditional::{build_where, Condition, WhereGroup};
+use crate::error::Error;
+use crate::query::Query;
+use crate::table_ref::TableRef;
 use crate::{DBImpl, Value};
 
 /**
@@ -8,13 +11,86 @@ Trait representing a delete builder.
 */
 pub trait Delete<'until_build, 'post_query> {
     /**
-    Adds the a [Condition] to the delete query.
+    Add a [Condition] to the where clause of the query.
+
+    If the query already has a where clause, the condition is combined with it using AND.
 
     **Parameter**:
-    - `condition`: Condition to apply to the delete operation
+    - `condition`: Condition to add.
      */
     fn where_clause(self, condition: &'until_build Condition<'post_query>) -> Self;
 
+    /**
+    Add a [Condition] to the where clause of the query, combined with
+    the existing where clause using OR.
+
+    Everything added before is wrapped in parentheses, so
+    `a AND b OR c` is built as `((a AND b) OR c)`.
+
+    **Parameter**:
+    - `condition`: Condition to add.
+     */
+    fn or_where(self, condition: &'until_build Condition<'post_query>) -> Self;
+
+    /**
+    Replace the where clause of the query, discarding all conditions added before.
+
+    **Parameter**:
+    - `condition`: The new condition.
+     */
+    fn replace_where(self, condition: &'until_build Condition<'post_query>) -> Self;
+
+    /**
+    Add conditions to the where clause, which all have to be met.
+
+    The conditions are combined with the existing where clause using AND.
+    An empty slice doesn't change the query.
+
+    **Parameter**:
+    - `conditions`: The conditions to add.
+     */
+    fn where_all(self, conditions: &'until_build [Condition<'post_query>]) -> Self;
+
+    /**
+    Add conditions to the where clause, of which any has to be met.
+
+    See [Delete::where_all] for further details.
+
+    **Parameter**:
+    - `conditions`: The conditions to add.
+     */
+    fn where_any(self, conditions: &'until_build [Condition<'post_query>]) -> Self;
+
+    /**
+    Add a table to the delete query, which can be referenced in the [Delete::where_clause].
+
+    Postgres uses `DELETE FROM a USING b`, MySQL uses `DELETE a FROM a JOIN b`.
+    Not supported by SQLite.
+
+    **Parameter**:
+    - `table_name`: Name of the table to add.
+     */
+    fn using(self, table_name: &'until_build str) -> Self;
+
+    /**
+    Limit the number of deleted rows.
+
+    Not supported by Postgres and by MySQL in combination with [Delete::using].
+    SQLite requires to be compiled with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`.
+
+    **Parameter**:
+    - `limit`: Maximum number of rows to delete.
+     */
+    fn limit(self, limit: u64) -> Self;
+
+    /**
+    Returns the number of values [Delete::build] binds to the query.
+
+    Can be used to allocate the parameters before building the query.
+    If the query can't be built, 0 is returned.
+     */
+    fn value_count(&self) -> usize;
+
     /**
     Build the delete operation.
 
@@ -22,17 +98,43 @@ pub trait Delete<'until_build, 'post_query> {
     - SQL query string
     - List of [Value] parameters to bind to the query.
     */
-    fn build(self) -> (String, Vec<Value<'post_query>>);
+    fn build(self) -> Result<(String, Vec<Value<'post_query>>), Error>;
+
+    /**
+    Same as [Delete::build], but the query is formatted over multiple lines
+    with every clause on its own line.
+
+    Meant for logging, the bound values are the same as the ones of [Delete::build].
+     */
+    fn build_pretty(self) -> Result<(String, Vec<Value<'post_query>>), Error>
+    where
+        Self: Sized,
+    {
+        self.build()
+            .map(|(query, lookup)| (crate::pretty::format(&query), lookup))
+    }
+
+    /**
+    Same as [Delete::build], but the statement and its values are returned as [Query].
+     */
+    fn build_query(self) -> Result<Query<'post_query>, Error>
+    where
+        Self: Sized,
+    {
+        self.build().map(Query::from)
+    }
 }
 
 /**
 Representation of the data of a DELETE operation.
 */
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DeleteData<'until_build, 'post_query> {
-    pub(crate) model: &'until_build str,
+    pub(crate) model: TableRef<'until_build>,
+    pub(crate) using: Option<&'until_build str>,
+    pub(crate) limit: Option<u64>,
     pub(crate) lookup: Vec<Value<'post_query>>,
-    pub(crate) where_clause: Option<&'until_build Condition<'post_query>>,
+    pub(crate) where_groups: Vec<WhereGroup<'until_build, 'post_query>>,
 }
 
 /**
@@ -40,7 +142,7 @@ Implementation of the [Delete] trait for the different implementations
 
 Should only be constructed via [DBImpl::delete].
 */
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DeleteImpl<'until_build, 'post_query> {
     /**
     SQLite representation of the DELETE operation.
@@ -65,67 +167,308 @@ impl<'until_build, 'post_query> Delete<'until_build, 'post_query>
     fn where_clause(mut self, condition: &'until_build Condition<'post_query>) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
-            DeleteImpl::SQLite(ref mut data) => data.where_clause = Some(condition),
+            DeleteImpl::SQLite(ref mut data) => data.where_groups.push(WhereGroup::And(condition)),
+            #[cfg(feature = "mysql")]
+            DeleteImpl::MySQL(ref mut data) => data.where_groups.push(WhereGroup::And(condition)),
+            #[cfg(feature = "postgres")]
+            DeleteImpl::Postgres(ref mut data) => {
+                data.where_groups.push(WhereGroup::And(condition))
+            }
+        };
+        self
+    }
+
+    fn or_where(mut self, condition: &'until_build Condition<'post_query>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DeleteImpl::SQLite(ref mut data) => data.where_groups.push(WhereGroup::Or(condition)),
+            #[cfg(feature = "mysql")]
+            DeleteImpl::MySQL(ref mut data) => data.where_groups.push(WhereGroup::Or(condition)),
+            #[cfg(feature = "postgres")]
+            DeleteImpl::Postgres(ref mut data) => data.where_groups.push(WhereGroup::Or(condition)),
+        };
+        self
+    }
+
+    fn replace_where(mut self, condition: &'until_build Condition<'post_query>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DeleteImpl::SQLite(ref mut data) => {
+                data.where_groups = vec![WhereGroup::And(condition)]
+            }
             #[cfg(feature = "mysql")]
-            DeleteImpl::MySQL(ref mut data) => data.where_clause = Some(condition),
+            DeleteImpl::MySQL(ref mut data) => data.where_groups = vec![WhereGroup::And(condition)],
             #[cfg(feature = "postgres")]
-            DeleteImpl::Postgres(ref mut data) => data.where_clause = Some(condition),
+            DeleteImpl::Postgres(ref mut data) => {
+                data.where_groups = vec![WhereGroup::And(condition)]
+            }
         };
         self
     }
 
-    fn build(self) -> (String, Vec<Value<'post_query>>) {
+    fn where_all(mut self, conditions: &'until_build [Condition<'post_query>]) -> Self {
+        if conditions.is_empty() {
+            return self;
+        }
+        match self {
+            #[cfg(feature = "sqlite")]
+            DeleteImpl::SQLite(ref mut data) => data.where_groups.push(WhereGroup::All(conditions)),
+            #[cfg(feature = "mysql")]
+            DeleteImpl::MySQL(ref mut data) => data.where_groups.push(WhereGroup::All(conditions)),
+            #[cfg(feature = "postgres")]
+            DeleteImpl::Postgres(ref mut data) => {
+                data.where_groups.push(WhereGroup::All(conditions))
+            }
+        };
+        self
+    }
+
+    fn where_any(mut self, conditions: &'until_build [Condition<'post_query>]) -> Self {
+        if conditions.is_empty() {
+            return self;
+        }
+        match self {
+            #[cfg(feature = "sqlite")]
+            DeleteImpl::SQLite(ref mut data) => data.where_groups.push(WhereGroup::Any(conditions)),
+            #[cfg(feature = "mysql")]
+            DeleteImpl::MySQL(ref mut data) => data.where_groups.push(WhereGroup::Any(conditions)),
+            #[cfg(feature = "postgres")]
+            DeleteImpl::Postgres(ref mut data) => {
+                data.where_groups.push(WhereGroup::Any(conditions))
+            }
+        };
+        self
+    }
+
+    fn using(mut self, table_name: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DeleteImpl::SQLite(ref mut data) => data.using = Some(table_name),
+            #[cfg(feature = "mysql")]
+            DeleteImpl::MySQL(ref mut data) => data.using = Some(table_name),
+            #[cfg(feature = "postgres")]
+            DeleteImpl::Postgres(ref mut data) => data.using = Some(table_name),
+        };
+        self
+    }
+
+    fn limit(mut self, limit: u64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DeleteImpl::SQLite(ref mut data) => data.limit = Some(limit),
+            #[cfg(feature = "mysql")]
+            DeleteImpl::MySQL(ref mut data) => data.limit = Some(limit),
+            #[cfg(feature = "postgres")]
+            DeleteImpl::Postgres(ref mut data) => data.limit = Some(limit),
+        };
+        self
+    }
+
+    fn value_count(&self) -> usize {
+        self.clone().build().map_or(0, |(_, lookup)| lookup.len())
+    }
+
+    fn build(self) -> Result<(String, Vec<Value<'post_query>>), Error> {
         match self {
             #[cfg(feature = "sqlite")]
             DeleteImpl::SQLite(mut d) => {
+                if d.using.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support deleting with multiple tables".to_string(),
+                    ));
+                }
+
                 let mut s = format!("DELETE FROM {} ", d.model);
 
-                if d.where_clause.is_some() {
-                    write!(
-                        s,
-                        "WHERE {} ",
-                        d.where_clause.unwrap().build(DBImpl::SQLite, &mut d.lookup)
-                    )
-                    .unwrap();
+                if let Some(condition) =
+                    build_where(&d.where_groups, DBImpl::SQLite, &mut d.lookup)?
+                {
+                    write!(s, "WHERE {condition} ").unwrap();
+                }
+
+                if let Some(limit) = d.limit {
+                    write!(s, "LIMIT {limit} ").unwrap();
                 }
 
                 write!(s, ";").unwrap();
-                (s, d.lookup)
+                Ok((s, d.lookup))
             }
             #[cfg(feature = "mysql")]
             DeleteImpl::MySQL(mut d) => {
-                let mut s = format!("DELETE FROM {} ", d.model);
+                if d.using.is_some() && d.limit.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support LIMIT when deleting with multiple tables"
+                            .to_string(),
+                    ));
+                }
+
+                let mut s = match d.using {
+                    Some(table_name) => format!("DELETE {0} FROM {0} JOIN {table_name} ", d.model),
+                    None => format!("DELETE FROM {} ", d.model),
+                };
 
-                if d.where_clause.is_some() {
-                    write!(
-                        s,
-                        "WHERE {} ",
-                        d.where_clause.unwrap().build(DBImpl::MySQL, &mut d.lookup)
-                    )
-                    .unwrap();
+                if let Some(condition) = build_where(&d.where_groups, DBImpl::MySQL, &mut d.lookup)?
+                {
+                    write!(s, "WHERE {condition} ").unwrap();
+                }
+
+                if let Some(limit) = d.limit {
+                    write!(s, "LIMIT {limit} ").unwrap();
                 }
 
                 write!(s, ";").unwrap();
-                (s, d.lookup)
+                Ok((s, d.lookup))
             }
             #[cfg(feature = "postgres")]
             DeleteImpl::Postgres(mut d) => {
-                let mut s = format!("DELETE FROM \"{}\" ", d.model);
-
-                if d.where_clause.is_some() {
-                    write!(
-                        s,
-                        "WHERE {} ",
-                        d.where_clause
-                            .unwrap()
-                            .build(DBImpl::Postgres, &mut d.lookup)
-                    )
-                    .unwrap();
+                if d.limit.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "Postgres doesn't support LIMIT in DELETE statements".to_string(),
+                    ));
+                }
+
+                let mut s = format!("DELETE FROM {} ", d.model.quoted(DBImpl::Postgres));
+
+                if let Some(table_name) = d.using {
+                    write!(s, "USING \"{table_name}\" ").unwrap();
+                }
+
+                if let Some(condition) =
+                    build_where(&d.where_groups, DBImpl::Postgres, &mut d.lookup)?
+                {
+                    write!(s, "WHERE {condition} ").unwrap();
                 }
 
                 write!(s, ";").unwrap();
-                (s, d.lookup)
+                Ok((s, d.lookup))
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::conditional::{BinaryCondition, Condition};
+    use crate::delete::Delete;
+    use crate::error::Error;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn using_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite.delete("session").using("user").build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn using_mysql() {
+        let condition = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("session"),
+                    column_name: "user_id",
+                }),
+                Condition::Value(Value::Column {
+                    table_name: Some("user"),
+                    column_name: "id",
+                }),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("user"),
+                    column_name: "active",
+                }),
+                Condition::Value(Value::Bool(false)),
+            ]))),
+        ]);
+        let query = DBImpl::MySQL
+            .delete("session")
+            .using("user")
+            .where_clause(&condition);
+
+        assert_eq!(query.value_count(), 1);
+        assert_eq!(
+            query.build().unwrap(),
+            (
+                "DELETE session FROM session JOIN user WHERE ((session.user_id = user.id) AND (user.active = ?)) ;".to_string(),
+                vec![Value::Bool(false)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn using_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("session"),
+                column_name: "user_id",
+            }),
+            Condition::Value(Value::Column {
+                table_name: Some("user"),
+                column_name: "id",
+            }),
+        ])));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .delete("session")
+                .using("user")
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "DELETE FROM \"session\" USING \"user\" WHERE (\"session\".user_id = \"user\".id) ;".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn limit_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("level")),
+            Condition::Value(Value::String("debug")),
+        ])));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .delete("log")
+                .where_clause(&condition)
+                .limit(100)
+                .build()
+                .unwrap(),
+            (
+                "DELETE FROM log WHERE (level = ?) LIMIT 100 ;".to_string(),
+                vec![Value::String("debug")]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn limit_mysql() {
+        assert_eq!(
+            DBImpl::MySQL.delete("log").limit(10).build().unwrap(),
+            ("DELETE FROM log LIMIT 10 ;".to_string(), vec![])
+        );
+        assert!(matches!(
+            DBImpl::MySQL.delete("log").using("user").limit(100).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn limit_postgres() {
+        assert!(matches!(
+            DBImpl::Postgres.delete("log").limit(100).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::Error;
+
+/**
+Trait representing a drop index builder.
+*/
+pub trait DropIndex {
+    /**
+    Drops the index only, if it exists.
+
+    For MySQL this is only supported by MariaDB.
+     */
+    fn if_exists(self) -> Self;
+
+    /**
+    This method is used to build the drop index statement.
+     */
+    fn build(self) -> Result<String, Error>;
+}
+
+/**
+The representation of data of the drop index statement.
+*/
+#[derive(Debug, Copy, Clone)]
+pub struct DropIndexData<'until_build> {
+    pub(crate) name: &'until_build str,
+    #[cfg_attr(not(feature = "mysql"), allow(dead_code))]
+    pub(crate) table_name: Option<&'until_build str>,
+    pub(crate) if_exists: bool,
+}
+
+/**
+Implementation of the [DropIndex] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::drop_index].
+*/
+#[derive(Debug)]
+pub enum DropIndexImpl<'until_build> {
+    /**
+    SQLite representation of the DROP INDEX operation.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(DropIndexData<'until_build>),
+    /**
+    MySQL representation of the DROP INDEX operation.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(DropIndexData<'until_build>),
+    /**
+    Postgres representation of the DROP INDEX operation.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(DropIndexData<'until_build>),
+}
+
+impl<'until_build> DropIndex for DropIndexImpl<'until_build> {
+    fn if_exists(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropIndexImpl::SQLite(ref mut d) => d.if_exists = true,
+            #[cfg(feature = "mysql")]
+            DropIndexImpl::MySQL(ref mut d) => d.if_exists = true,
+            #[cfg(feature = "postgres")]
+            DropIndexImpl::Postgres(ref mut d) => d.if_exists = true,
+        };
+        self
+    }
+
+    fn build(self) -> Result<String, Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropIndexImpl::SQLite(d) => Ok(format!(
+                "DROP INDEX{} {};",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name,
+            )),
+
+            #[cfg(feature = "mysql")]
+            DropIndexImpl::MySQL(d) => {
+                let Some(table_name) = d.table_name else {
+                    return Err(Error::SQLBuildError(format!(
+                        "Couldn't drop index {}: MySQL requires the table of the index",
+                        d.name
+                    )));
+                };
+
+                Ok(format!(
+                    "DROP INDEX{} {} ON {};",
+                    if d.if_exists { " IF EXISTS" } else { "" },
+                    d.name,
+                    table_name,
+                ))
+            }
+
+            #[cfg(feature = "postgres")]
+            DropIndexImpl::Postgres(d) => Ok(format!(
+                "DROP INDEX{} {};",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::drop_index::DropIndex;
+    #[cfg(feature = "mysql")]
+    use crate::error::Error;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn drop_index_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite.drop_index("idx", None).build().unwrap(),
+            "DROP INDEX idx;"
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .drop_index("idx", Some("user"))
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP INDEX IF EXISTS idx;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn drop_index_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .drop_index("idx", Some("user"))
+                .build()
+                .unwrap(),
+            "DROP INDEX idx ON user;"
+        );
+        assert_eq!(
+            DBImpl::MySQL
+                .drop_index("idx", Some("user"))
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP INDEX IF EXISTS idx ON user;"
+        );
+        assert!(matches!(
+            DBImpl::MySQL.drop_index("idx", None).build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn drop_index_postgres() {
+        assert_eq!(
+            DBImpl::Postgres.drop_index("idx", None).build().unwrap(),
+            "DROP INDEX idx;"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_index("idx", None)
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP INDEX IF EXISTS idx;"
+        );
+    }
+}
@@ -1,25 +1,48 @@
+use crate::error::Error;
+use crate::table_ref::TableRef;
+#[cfg(feature = "postgres")]
+use crate::DBImpl;
+
 /**
 Trait representing a drop table builder.
 */
-pub trait DropTable {
+pub trait DropTable<'until_build> {
     /**
     Drops the table only, if it exists.
      */
     fn if_exists(self) -> Self;
 
+    /**
+    Add another table to drop in the same statement.
+
+    Not supported by SQLite.
+
+    **Parameter**:
+    - `name`: Name of the table to drop.
+     */
+    fn add_table(self, name: impl Into<TableRef<'until_build>>) -> Self;
+
+    /**
+    Drop objects depending on the table as well, like views and foreign keys.
+
+    Not supported by SQLite. MySQL accepts the keyword, but ignores it.
+     */
+    fn cascade(self) -> Self;
+
     /**
     This method is used to build the drop table statement.
      */
-    fn build(self) -> String;
+    fn build(self) -> Result<String, Error>;
 }
 
 /**
 The representation of data of the drop table statement.
 */
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct DropTableData<'until_build> {
-    pub(crate) name: &'until_build str,
+    pub(crate) names: Vec<TableRef<'until_build>>,
     pub(crate) if_exists: bool,
+    pub(crate) cascade: bool,
 }
 
 /**
@@ -46,7 +69,7 @@ pub enum DropTableImpl<'until_build> {
     Postgres(DropTableData<'until_build>),
 }
 
-impl<'until_build> DropTable for DropTableImpl<'until_build> {
+impl<'until_build> DropTable<'until_build> for DropTableImpl<'until_build> {
     fn if_exists(mut self) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
@@ -59,28 +82,146 @@ impl<'until_build> DropTable for DropTableImpl<'until_build> {
         self
     }
 
-    fn build(self) -> String {
+    fn add_table(mut self, name: impl Into<TableRef<'until_build>>) -> Self {
+        let name = name.into();
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropTableImpl::SQLite(ref mut d) => d.names.push(name),
+            #[cfg(feature = "mysql")]
+            DropTableImpl::MySQL(ref mut d) => d.names.push(name),
+            #[cfg(feature = "postgres")]
+            DropTableImpl::Postgres(ref mut d) => d.names.push(name),
+        };
+        self
+    }
+
+    fn cascade(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropTableImpl::SQLite(ref mut d) => d.cascade = true,
+            #[cfg(feature = "mysql")]
+            DropTableImpl::MySQL(ref mut d) => d.cascade = true,
+            #[cfg(feature = "postgres")]
+            DropTableImpl::Postgres(ref mut d) => d.cascade = true,
+        };
+        self
+    }
+
+    fn build(self) -> Result<String, Error> {
         match self {
             #[cfg(feature = "sqlite")]
-            DropTableImpl::SQLite(d) => format!(
-                "DROP TABLE {}{};",
-                d.name,
-                if d.if_exists { " IF EXISTS" } else { "" }
-            ),
+            DropTableImpl::SQLite(d) => {
+                if d.names.len() > 1 {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support dropping multiple tables at once".to_string(),
+                    ));
+                }
+                if d.cascade {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support DROP TABLE with CASCADE".to_string(),
+                    ));
+                }
+
+                Ok(format!(
+                    "DROP TABLE{} {};",
+                    if d.if_exists { " IF EXISTS" } else { "" },
+                    d.names[0],
+                ))
+            }
 
             #[cfg(feature = "mysql")]
-            DropTableImpl::MySQL(d) => format!(
-                "DROP TABLE {}{};",
-                d.name,
-                if d.if_exists { " IF EXISTS" } else { "" }
-            ),
+            DropTableImpl::MySQL(d) => Ok(format!(
+                "DROP TABLE{} {}{};",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.names
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                if d.cascade { " CASCADE" } else { "" },
+            )),
 
             #[cfg(feature = "postgres")]
-            DropTableImpl::Postgres(d) => format!(
-                "DROP TABLE \"{}\"{};",
-                d.name,
-                if d.if_exists { " IF EXISTS" } else { "" }
-            ),
+            DropTableImpl::Postgres(d) => {
+                let names: Vec<_> = d
+                    .names
+                    .iter()
+                    .map(|name| name.quoted(DBImpl::Postgres))
+                    .collect();
+
+                Ok(format!(
+                    "DROP TABLE{} {}{};",
+                    if d.if_exists { " IF EXISTS" } else { "" },
+                    names.join(", "),
+                    if d.cascade { " CASCADE" } else { "" },
+                ))
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::drop_table::DropTable;
+    #[cfg(feature = "sqlite")]
+    use crate::error::Error;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn drop_table_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .drop_table("user")
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP TABLE IF EXISTS user;"
+        );
+        assert!(matches!(
+            DBImpl::SQLite.drop_table("user").add_table("post").build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+        assert!(matches!(
+            DBImpl::SQLite.drop_table("user").cascade().build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn drop_table_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .drop_table("user")
+                .add_table("post")
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP TABLE IF EXISTS user, post;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn drop_table_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_table("user")
+                .cascade()
+                .build()
+                .unwrap(),
+            "DROP TABLE \"user\" CASCADE;"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_table("user")
+                .add_table("post")
+                .add_table("comment")
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP TABLE IF EXISTS \"user\", \"post\", \"comment\";"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::error::Error;
+
+/**
+Trait representing a drop trigger builder.
+*/
+pub trait DropTrigger {
+    /**
+    Drops the trigger only, if it exists.
+     */
+    fn if_exists(self) -> Self;
+
+    /**
+    This method is used to build the drop trigger statement.
+     */
+    fn build(self) -> Result<String, Error>;
+}
+
+/**
+The representation of data of the drop trigger statement.
+*/
+#[derive(Debug, Copy, Clone)]
+pub struct DropTriggerData<'until_build> {
+    pub(crate) name: &'until_build str,
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub(crate) table_name: Option<&'until_build str>,
+    pub(crate) if_exists: bool,
+}
+
+/**
+Implementation of the [DropTrigger] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::drop_trigger].
+*/
+#[derive(Debug)]
+pub enum DropTriggerImpl<'until_build> {
+    /**
+    SQLite representation of the DROP TRIGGER operation.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(DropTriggerData<'until_build>),
+    /**
+    MySQL representation of the DROP TRIGGER operation.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(DropTriggerData<'until_build>),
+    /**
+    Postgres representation of the DROP TRIGGER operation.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(DropTriggerData<'until_build>),
+}
+
+impl<'until_build> DropTrigger for DropTriggerImpl<'until_build> {
+    fn if_exists(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropTriggerImpl::SQLite(ref mut d) => d.if_exists = true,
+            #[cfg(feature = "mysql")]
+            DropTriggerImpl::MySQL(ref mut d) => d.if_exists = true,
+            #[cfg(feature = "postgres")]
+            DropTriggerImpl::Postgres(ref mut d) => d.if_exists = true,
+        };
+        self
+    }
+
+    fn build(self) -> Result<String, Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropTriggerImpl::SQLite(d) => Ok(format!(
+                "DROP TRIGGER{} {};",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name,
+            )),
+
+            #[cfg(feature = "mysql")]
+            DropTriggerImpl::MySQL(d) => Ok(format!(
+                "DROP TRIGGER{} {};",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name,
+            )),
+
+            #[cfg(feature = "postgres")]
+            DropTriggerImpl::Postgres(d) => {
+                let Some(table_name) = d.table_name else {
+                    return Err(Error::SQLBuildError(format!(
+                        "Couldn't drop trigger {}: Postgres requires the table of the trigger",
+                        d.name
+                    )));
+                };
+
+                Ok(format!(
+                    "DROP TRIGGER{} {} ON \"{}\";",
+                    if d.if_exists { " IF EXISTS" } else { "" },
+                    d.name,
+                    table_name,
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::drop_trigger::DropTrigger;
+    #[cfg(feature = "postgres")]
+    use crate::error::Error;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn drop_trigger_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite.drop_trigger("audit", None).build().unwrap(),
+            "DROP TRIGGER audit;"
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .drop_trigger("audit", Some("user"))
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP TRIGGER IF EXISTS audit;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn drop_trigger_mysql() {
+        assert_eq!(
+            DBImpl::MySQL.drop_trigger("audit", None).build().unwrap(),
+            "DROP TRIGGER audit;"
+        );
+        assert_eq!(
+            DBImpl::MySQL
+                .drop_trigger("audit", None)
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP TRIGGER IF EXISTS audit;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn drop_trigger_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_trigger("audit", Some("user"))
+                .build()
+                .unwrap(),
+            "DROP TRIGGER audit ON \"user\";"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_trigger("audit", Some("user"))
+                .if_exists()
+                .build()
+                .unwrap(),
+            "DROP TRIGGER IF EXISTS audit ON \"user\";"
+        );
+        assert!(matches!(
+            DBImpl::Postgres.drop_trigger("audit", None).build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+}
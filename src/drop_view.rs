@@ -0,0 +1,134 @@
+/**
+Trait representing a drop view builder.
+*/
+pub trait DropView {
+    /**
+    Drops the view only, if it exists.
+     */
+    fn if_exists(self) -> Self;
+
+    /**
+    This method is used to build the drop view statement.
+     */
+    fn build(self) -> String;
+}
+
+/**
+The representation of data of the drop view statement.
+*/
+#[derive(Debug, Copy, Clone)]
+pub struct DropViewData<'until_build> {
+    pub(crate) name: &'until_build str,
+    pub(crate) if_exists: bool,
+}
+
+/**
+Implementation of the [DropView] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::drop_view].
+*/
+#[derive(Debug)]
+pub enum DropViewImpl<'until_build> {
+    /**
+    SQLite representation of the DROP VIEW operation.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(DropViewData<'until_build>),
+    /**
+    MySQL representation of the DROP VIEW operation.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(DropViewData<'until_build>),
+    /**
+    Postgres representation of the DROP VIEW operation.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(DropViewData<'until_build>),
+}
+
+impl<'until_build> DropView for DropViewImpl<'until_build> {
+    fn if_exists(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropViewImpl::SQLite(ref mut d) => d.if_exists = true,
+            #[cfg(feature = "mysql")]
+            DropViewImpl::MySQL(ref mut d) => d.if_exists = true,
+            #[cfg(feature = "postgres")]
+            DropViewImpl::Postgres(ref mut d) => d.if_exists = true,
+        };
+        self
+    }
+
+    fn build(self) -> String {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DropViewImpl::SQLite(d) => format!(
+                "DROP VIEW{} \"{}\";",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name
+            ),
+
+            #[cfg(feature = "mysql")]
+            DropViewImpl::MySQL(d) => format!(
+                "DROP VIEW{} `{}`;",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name
+            ),
+
+            #[cfg(feature = "postgres")]
+            DropViewImpl::Postgres(d) => format!(
+                "DROP VIEW{} \"{}\";",
+                if d.if_exists { " IF EXISTS" } else { "" },
+                d.name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::drop_view::DropView;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn drop_view_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite.drop_view("active_user").build(),
+            "DROP VIEW \"active_user\";"
+        );
+        assert_eq!(
+            DBImpl::SQLite.drop_view("active_user").if_exists().build(),
+            "DROP VIEW IF EXISTS \"active_user\";"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn drop_view_mysql() {
+        assert_eq!(
+            DBImpl::MySQL.drop_view("active_user").build(),
+            "DROP VIEW `active_user`;"
+        );
+        assert_eq!(
+            DBImpl::MySQL.drop_view("active_user").if_exists().build(),
+            "DROP VIEW IF EXISTS `active_user`;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn drop_view_postgres() {
+        assert_eq!(
+            DBImpl::Postgres.drop_view("active_user").build(),
+            "DROP VIEW \"active_user\";"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_view("active_user")
+                .if_exists()
+                .build(),
+            "DROP VIEW IF EXISTS \"active_user\";"
+        );
+    }
+}
@@ -6,16 +6,54 @@ use std::{error, fmt};
 pub enum Error {
     /// Error while building sql.
     SQLBuildError(String),
+    /// The requested feature is not supported by the dialect.
+    UnsupportedFeature(String),
+    /// A statement requires at least one column, but none were given.
+    EmptyColumnList {
+        /// The statement missing the columns, e.g. `"SELECT"`
+        statement: &'static str,
+    },
+    /// A row of an INSERT has a different number of values than columns are inserted.
+    ColumnValueCountMismatch {
+        /// Number of inserted columns
+        expected: usize,
+        /// Number of values in the row
+        found: usize,
+        /// Index of the offending row
+        row_index: usize,
+    },
 }
 
 impl error::Error for Error {}
 
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::SQLBuildError("Writing the SQL failed".to_string())
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Error::SQLBuildError(error) => {
                 write!(f, "sql build error: {error}")
             }
+            Error::UnsupportedFeature(error) => {
+                write!(f, "unsupported feature: {error}")
+            }
+            Error::EmptyColumnList { statement } => {
+                write!(f, "{statement} requires at least one column")
+            }
+            Error::ColumnValueCountMismatch {
+                expected,
+                found,
+                row_index,
+            } => {
+                write!(
+                    f,
+                    "row {row_index} has {found} values, but {expected} columns are inserted"
+                )
+            }
         }
     }
 }
@@ -0,0 +1,110 @@
+use std::fmt::Write;
+
+use crate::error::Error;
+use crate::DBImpl;
+
+/**
+Representation of the groups of a GROUP BY clause in SQL.
+
+SQLite only supports [Grouping::Columns].
+MySQL supports [Grouping::Rollup] via `WITH ROLLUP` as well,
+Postgres supports all of them.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Grouping<'until_build> {
+    /// Group by the given columns, no GROUP BY clause is emitted if empty
+    Columns(&'until_build [&'until_build str]),
+    /// Representation of ROLLUP in SQL, adds subtotals for every prefix of the columns
+    Rollup(&'until_build [&'until_build str]),
+    /// Representation of CUBE in SQL, adds subtotals for every combination of the columns
+    Cube(&'until_build [&'until_build str]),
+    /// Representation of GROUPING SETS in SQL, groups by each of the sets separately.
+    ///
+    /// An empty set groups all rows into a single group.
+    GroupingSets(&'until_build [&'until_build [&'until_build str]]),
+}
+
+impl Grouping<'_> {
+    /**
+    Write the GROUP BY clause, starting with a space.
+
+    Returns an error, if the dialect doesn't support the grouping
+    or if the columns of a ROLLUP, CUBE or GROUPING SETS are empty.
+     */
+    pub(crate) fn build(&self, s: &mut String, dialect: DBImpl) -> Result<(), Error> {
+        let empty = match self {
+            Grouping::Columns(columns) => {
+                if !columns.is_empty() {
+                    write!(s, " GROUP BY {}", join_columns(columns, dialect)).unwrap();
+                }
+                return Ok(());
+            }
+            Grouping::Rollup(columns) | Grouping::Cube(columns) => columns.is_empty(),
+            Grouping::GroupingSets(sets) => sets.is_empty(),
+        };
+        if empty {
+            return Err(Error::EmptyColumnList {
+                statement: "GROUP BY",
+            });
+        }
+
+        match dialect {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => Err(Error::UnsupportedFeature(
+                "SQLite doesn't support ROLLUP, CUBE or GROUPING SETS".to_string(),
+            )),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => match self {
+                Grouping::Rollup(columns) => {
+                    write!(
+                        s,
+                        " GROUP BY {} WITH ROLLUP",
+                        join_columns(columns, dialect)
+                    )
+                    .unwrap();
+                    Ok(())
+                }
+                _ => Err(Error::UnsupportedFeature(
+                    "MySQL doesn't support CUBE or GROUPING SETS".to_string(),
+                )),
+            },
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => {
+                match self {
+                    Grouping::Columns(_) => {}
+                    Grouping::Rollup(columns) => {
+                        write!(s, " GROUP BY ROLLUP ({})", join_columns(columns, dialect)).unwrap()
+                    }
+                    Grouping::Cube(columns) => {
+                        write!(s, " GROUP BY CUBE ({})", join_columns(columns, dialect)).unwrap()
+                    }
+                    Grouping::GroupingSets(sets) => {
+                        write!(s, " GROUP BY GROUPING SETS (").unwrap();
+                        for (idx, set) in sets.iter().enumerate() {
+                            if idx > 0 {
+                                write!(s, ", ").unwrap();
+                            }
+                            write!(s, "({})", join_columns(set, dialect)).unwrap();
+                        }
+                        write!(s, ")").unwrap();
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Join the columns with commas, Postgres quotes them
+fn join_columns(columns: &[&str], dialect: DBImpl) -> String {
+    match dialect {
+        #[cfg(feature = "postgres")]
+        DBImpl::Postgres => columns
+            .iter()
+            .map(|x| format!("\"{x}\""))
+            .collect::<Vec<_>>()
+            .join(", "),
+        #[allow(unreachable_patterns)]
+        _ => columns.join(", "),
+    }
+}
@@ -1,19 +1,25 @@
 use std::fmt::Write;
 
+use crate::conditional::{BuildCondition, Condition};
 #[cfg(feature = "mysql")]
 use crate::db_specific::mysql;
 #[cfg(feature = "postgres")]
 use crate::db_specific::postgres;
 #[cfg(feature = "sqlite")]
 use crate::db_specific::sqlite;
-use crate::on_conflict::OnConflict;
+use crate::error::Error;
+use crate::on_conflict::{ConflictTarget, OnConflict, UpsertValue};
+use crate::query::Query;
+use crate::select::SelectImpl;
+use crate::table_ref::TableRef;
 use crate::value::NullType;
+use crate::DBImpl;
 use crate::Value;
 
 /**
 Trait representing a insert builder.
  */
-pub trait Insert<'post_build> {
+pub trait Insert<'until_build, 'post_build> {
     /**
     Turns on ROLLBACK mode.
 
@@ -25,32 +31,146 @@ pub trait Insert<'post_build> {
      */
     fn rollback_transaction(self) -> Self;
 
+    /**
+    Update the existing row if the insert conflicts with it.
+
+    SQLite and Postgres use `ON CONFLICT (...) DO UPDATE SET ...`,
+    MySQL uses `ON DUPLICATE KEY UPDATE ...` and ignores the conflict columns.
+    The bound values of the updates are placed after the ones of the inserted rows.
+    A RETURNING clause is placed after the updates.
+
+    **Parameter**:
+    - `conflict_columns`: The columns of the unique constraint to check for conflicts.
+    - `updates`: The columns to update with their new values.
+     */
+    fn on_conflict_do_update(
+        self,
+        conflict_columns: &'until_build [&'until_build str],
+        updates: &'until_build [(&'until_build str, UpsertValue<'until_build, 'post_build>)],
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_conflict_target_do_update(ConflictTarget::Columns(conflict_columns), updates)
+    }
+
+    /**
+    Same as [Insert::on_conflict_do_update], but the conflict target may be a named constraint.
+
+    [ConflictTarget::Constraint] is rendered as `ON CONFLICT ON CONSTRAINT "name"` by Postgres.
+    SQLite doesn't support it and returns [Error::UnsupportedFeature] on build,
+    MySQL ignores the target.
+
+    **Parameter**:
+    - `target`: The columns or the constraint to check for conflicts.
+    - `updates`: The columns to update with their new values.
+     */
+    fn on_conflict_target_do_update(
+        self,
+        target: ConflictTarget<'until_build>,
+        updates: &'until_build [(&'until_build str, UpsertValue<'until_build, 'post_build>)],
+    ) -> Self;
+
+    /**
+    Only update the conflicting row if the condition holds, the row is left unchanged otherwise.
+
+    Rendered as `WHERE` after the SET list of the ON CONFLICT DO UPDATE clause,
+    its bound values are placed after the ones of the updates.
+    MySQL doesn't support it and returns [Error::UnsupportedFeature] on build.
+
+    **Parameter**:
+    - `condition`: Condition the conflicting row has to fulfill.
+     */
+    fn on_conflict_update_where(self, condition: &'until_build Condition<'post_build>) -> Self;
+
+    /**
+    Build the INSERT query split into multiple statements, each binding at most `max_params` values.
+
+    The rows are split into chunks of whole rows, which are inserted in the original order.
+    [DBImpl::max_params](crate::DBImpl::max_params) provides the limit of the database.
+    INSERT ... SELECT queries are not split.
+
+    This function returns an error, if a single row exceeds the limit
+    or if [Insert::build] would return an error.
+     */
+    fn build_chunked(
+        &self,
+        max_params: usize,
+    ) -> Result<Vec<(String, Vec<Value<'post_build>>)>, Error>;
+
+    /**
+    Returns the number of values [Insert::build] binds to the query.
+
+    Can be used to allocate the parameters before building the query.
+    If the query can't be built, 0 is returned.
+     */
+    fn value_count(&self) -> usize;
+
     /**
     This method is used to build the INSERT query.
     It returns the build query as well as a vector of values to bind to it.
+
+    This function returns an error, if a RETURNING clause is set for MySQL
+    or an ON CONFLICT DO UPDATE clause is set without columns to insert or updates.
+    Setting [Insert::on_conflict_update_where] without an ON CONFLICT DO UPDATE clause
+    returns an error as well.
+     */
+    fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error>;
+
+    /**
+    Same as [Insert::build], but the query is formatted over multiple lines
+    with every clause on its own line.
+
+    Meant for logging, the bound values are the same as the ones of [Insert::build].
      */
-    fn build(self) -> (String, Vec<Value<'post_build>>);
+    fn build_pretty(self) -> Result<(String, Vec<Value<'post_build>>), Error>
+    where
+        Self: Sized,
+    {
+        self.build()
+            .map(|(query, lookup)| (crate::pretty::format(&query), lookup))
+    }
+
+    /**
+    Same as [Insert::build], but the statement and its values are returned as [Query].
+     */
+    fn build_query(self) -> Result<Query<'post_build>, Error>
+    where
+        Self: Sized,
+    {
+        self.build().map(Query::from)
+    }
 }
 
+/// Conflict target and updates of an ON CONFLICT DO UPDATE clause
+pub(crate) type Upsert<'until_build, 'post_build> = (
+    ConflictTarget<'until_build>,
+    &'until_build [(&'until_build str, UpsertValue<'until_build, 'post_build>)],
+);
+
 /**
 Representation of the data of a INSERT operation in SQL.
 */
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct InsertData<'until_build, 'post_build> {
-    pub(crate) into_clause: &'until_build str,
+    pub(crate) into_clause: TableRef<'until_build>,
     pub(crate) columns: &'until_build [&'until_build str],
     pub(crate) row_values: &'until_build [&'until_build [Value<'post_build>]],
     pub(crate) lookup: Vec<Value<'post_build>>,
     pub(crate) on_conflict: OnConflict,
     pub(crate) returning_clause: Option<&'until_build [&'until_build str]>,
+    pub(crate) select: Option<&'until_build SelectImpl<'until_build, 'post_build>>,
+    pub(crate) upsert: Option<Upsert<'until_build, 'post_build>>,
+    pub(crate) upsert_where: Option<&'until_build Condition<'post_build>>,
 }
 
 /**
 Implementation of the [Insert] trait for the different implementations.
 
-Should only be constructed via [DBImpl::insert](crate::DBImpl::insert).
+Should only be constructed via [DBImpl::insert](crate::DBImpl::insert)
+or [DBImpl::insert_select](crate::DBImpl::insert_select).
  */
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum InsertImpl<'until_build, 'post_build> {
     /**
     SQLite representation of the INSERT operation.
@@ -69,7 +189,9 @@ pub enum InsertImpl<'until_build, 'post_build> {
     Postgres(InsertData<'until_build, 'post_build>),
 }
 
-impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build, 'post_build> {
+impl<'until_build, 'post_build> Insert<'until_build, 'post_build>
+    for InsertImpl<'until_build, 'post_build>
+{
     fn rollback_transaction(mut self) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
@@ -82,12 +204,132 @@ impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build,
         self
     }
 
-    fn build(self) -> (String, Vec<Value<'post_build>>) {
+    fn on_conflict_target_do_update(
+        mut self,
+        target: ConflictTarget<'until_build>,
+        updates: &'until_build [(&'until_build str, UpsertValue<'until_build, 'post_build>)],
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            InsertImpl::SQLite(ref mut d) => d.upsert = Some((target, updates)),
+            #[cfg(feature = "mysql")]
+            InsertImpl::MySQL(ref mut d) => d.upsert = Some((target, updates)),
+            #[cfg(feature = "postgres")]
+            InsertImpl::Postgres(ref mut d) => d.upsert = Some((target, updates)),
+        };
+        self
+    }
+
+    fn on_conflict_update_where(mut self, condition: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            InsertImpl::SQLite(ref mut d) => d.upsert_where = Some(condition),
+            #[cfg(feature = "mysql")]
+            InsertImpl::MySQL(ref mut d) => d.upsert_where = Some(condition),
+            #[cfg(feature = "postgres")]
+            InsertImpl::Postgres(ref mut d) => d.upsert_where = Some(condition),
+        };
+        self
+    }
+
+    fn build_chunked(
+        &self,
+        max_params: usize,
+    ) -> Result<Vec<(String, Vec<Value<'post_build>>)>, Error> {
+        // The match is infallible, if only a single dialect is enabled
+        #[allow(clippy::infallible_destructuring_match)]
+        let (d, dialect) = match self {
+            #[cfg(feature = "sqlite")]
+            InsertImpl::SQLite(d) => (d, DBImpl::SQLite),
+            #[cfg(feature = "mysql")]
+            InsertImpl::MySQL(d) => (d, DBImpl::MySQL),
+            #[cfg(feature = "postgres")]
+            InsertImpl::Postgres(d) => (d, DBImpl::Postgres),
+        };
+        let rows = d.row_values;
+        if d.select.is_some() || rows.is_empty() {
+            return Ok(vec![self.clone().build()?]);
+        }
+
+        // The values of the upsert are bound once per statement
+        let mut fixed = d.upsert.map_or(0, |(_, updates)| {
+            updates
+                .iter()
+                .filter(|(_, value)| matches!(value, UpsertValue::Value(value) if is_bound(value)))
+                .count()
+        });
+        if let Some(condition) = d.upsert_where {
+            let mut lookup = vec![];
+            condition.build(dialect, &mut lookup)?;
+            fixed += lookup.len();
+        }
+
+        let mut statements = vec![];
+        let mut start = 0;
+        let mut params = fixed;
+        for (idx, row) in rows.iter().enumerate() {
+            let row_params = row.iter().filter(|value| is_bound(value)).count();
+            if fixed + row_params > max_params {
+                return Err(Error::SQLBuildError(format!(
+                    "A single row binds {} values, which exceeds the limit of {max_params}",
+                    fixed + row_params
+                )));
+            }
+            if params + row_params > max_params {
+                statements.push(self.with_rows(&rows[start..idx]).build()?);
+                start = idx;
+                params = fixed;
+            }
+            params += row_params;
+        }
+        statements.push(self.with_rows(&rows[start..]).build()?);
+
+        Ok(statements)
+    }
+
+    fn value_count(&self) -> usize {
+        self.clone().build().map_or(0, |(_, lookup)| lookup.len())
+    }
+
+    fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error> {
+        match &self {
+            #[cfg(feature = "sqlite")]
+            InsertImpl::SQLite(d) => check_row_lengths(d)?,
+            #[cfg(feature = "mysql")]
+            InsertImpl::MySQL(d) => check_row_lengths(d)?,
+            #[cfg(feature = "postgres")]
+            InsertImpl::Postgres(d) => check_row_lengths(d)?,
+        };
+        match &self {
+            #[cfg(feature = "sqlite")]
+            InsertImpl::SQLite(d) => check_upsert_where(d)?,
+            #[cfg(feature = "mysql")]
+            InsertImpl::MySQL(d) => check_upsert_where(d)?,
+            #[cfg(feature = "postgres")]
+            InsertImpl::Postgres(d) => check_upsert_where(d)?,
+        };
+
         match self {
             #[cfg(feature = "sqlite")]
             InsertImpl::SQLite(mut d) => {
+                if let Some((target, updates)) = d.upsert {
+                    if let ConflictTarget::Constraint(_) = target {
+                        return Err(Error::UnsupportedFeature(String::from(
+                            "SQLite doesn't support ON CONFLICT ON CONSTRAINT, use the conflict columns instead",
+                        )));
+                    }
+                    if (d.columns.is_empty() && d.select.is_none())
+                        || matches!(target, ConflictTarget::Columns(columns) if columns.is_empty())
+                        || updates.is_empty()
+                    {
+                        return Err(Error::SQLBuildError(String::from(
+                            "ON CONFLICT DO UPDATE requires columns to insert, conflict columns and updates",
+                        )));
+                    }
+                }
+
                 // Handle case, if no columns should be inserted, aka an empty insert
-                if d.columns.is_empty() {
+                if d.columns.is_empty() && d.select.is_none() {
                     let mut s = format!(
                         "INSERT {}INTO {} DEFAULT VALUES",
                         match d.on_conflict {
@@ -110,44 +352,112 @@ impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build,
                     }
                     write!(s, ";").unwrap();
 
-                    return (s, d.lookup);
+                    return Ok((s, d.lookup));
                 }
 
                 let mut s = format!(
-                    "INSERT {}INTO {} (",
+                    "INSERT {}INTO {} ",
                     match d.on_conflict {
                         OnConflict::ABORT => "OR ABORT ",
                         OnConflict::ROLLBACK => "OR ROLLBACK ",
                     },
                     d.into_clause,
                 );
-                for (idx, x) in d.columns.iter().enumerate() {
-                    write!(s, "{x}").unwrap();
-                    if idx != d.columns.len() - 1 {
-                        write!(s, ", ").unwrap();
+                if !d.columns.is_empty() {
+                    write!(s, "(").unwrap();
+                    for (idx, x) in d.columns.iter().enumerate() {
+                        write!(s, "{x}").unwrap();
+                        if idx != d.columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
                     }
+                    write!(s, ") ").unwrap();
                 }
-                write!(s, ") VALUES ").unwrap();
 
-                for (idx, x) in d.row_values.iter().enumerate() {
-                    write!(s, "(").unwrap();
-                    for (idx_2, y) in x.iter().enumerate() {
-                        match y {
-                            Value::Ident(st) => write!(s, "{}", *st).unwrap(),
-                            Value::Choice(c) => write!(s, "{}", sqlite::fmt(c)).unwrap(),
-                            Value::Null(NullType::Choice) => write!(s, "NULL").unwrap(),
-                            _ => {
-                                d.lookup.push(*y);
-                                write!(s, "?").unwrap();
+                if let Some(select) = d.select {
+                    select.build_to_writer(&mut s, &mut d.lookup)?;
+                    // Without a WHERE clause, SQLite parses the ON CONFLICT as join constraint
+                    if d.upsert.is_some() && select.ends_with_sqlite_from_clause() {
+                        write!(s, " WHERE true").unwrap();
+                    }
+                } else {
+                    write!(s, "VALUES ").unwrap();
+                    for (idx, x) in d.row_values.iter().enumerate() {
+                        write!(s, "(").unwrap();
+                        for (idx_2, y) in x.iter().enumerate() {
+                            match y {
+                                Value::Ident(st) => write!(s, "{}", *st).unwrap(),
+                                Value::Choice(c) => write!(s, "{}", sqlite::fmt(c)).unwrap(),
+                                Value::Null(NullType::Choice) => write!(s, "NULL").unwrap(),
+                                Value::Default => {
+                                    return Err(Error::UnsupportedFeature(String::from(
+                                        "SQLite doesn't support DEFAULT as value",
+                                    )))
+                                }
+                                Value::NextVal(_) => {
+                                    return Err(Error::UnsupportedFeature(String::from(
+                                        "SQLite doesn't support sequences",
+                                    )))
+                                }
+                                _ => {
+                                    d.lookup.push(*y);
+                                    write!(s, "?").unwrap();
+                                }
+                            }
+                            if idx_2 != x.len() - 1 {
+                                write!(s, ", ").unwrap();
+                            }
+                        }
+                        write!(s, ")").unwrap();
+                        if idx != d.row_values.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                }
+
+                if let Some((ConflictTarget::Columns(conflict_columns), updates)) = d.upsert {
+                    write!(s, " ON CONFLICT (").unwrap();
+                    for (idx, c) in conflict_columns.iter().enumerate() {
+                        write!(s, "{c}").unwrap();
+                        if idx != conflict_columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ") DO UPDATE SET ").unwrap();
+                    for (idx, (column, value)) in updates.iter().enumerate() {
+                        match value {
+                            UpsertValue::Value(Value::Choice(c)) => {
+                                write!(s, "{column} = {}", sqlite::fmt(c)).unwrap()
+                            }
+                            UpsertValue::Value(Value::Null(NullType::Choice)) => {
+                                write!(s, "{column} = NULL").unwrap()
+                            }
+                            UpsertValue::Value(Value::Default) => {
+                                return Err(Error::UnsupportedFeature(String::from(
+                                    "SQLite doesn't support DEFAULT as value",
+                                )))
+                            }
+                            UpsertValue::Value(Value::NextVal(_)) => {
+                                return Err(Error::UnsupportedFeature(String::from(
+                                    "SQLite doesn't support sequences",
+                                )))
+                            }
+                            UpsertValue::Value(value) => {
+                                d.lookup.push(*value);
+                                write!(s, "{column} = ?").unwrap();
+                            }
+                            UpsertValue::Excluded(excluded) => {
+                                write!(s, "{column} = excluded.{excluded}").unwrap()
                             }
                         }
-                        if idx_2 != x.len() - 1 {
+                        if idx != updates.len() - 1 {
                             write!(s, ", ").unwrap();
                         }
                     }
-                    write!(s, ")").unwrap();
-                    if idx != d.row_values.len() - 1 {
-                        write!(s, ", ").unwrap();
+                    if let Some(condition) = d.upsert_where {
+                        condition.check_sqlite()?;
+                        let condition = condition.build(DBImpl::SQLite, &mut d.lookup)?;
+                        write!(s, " WHERE {condition}").unwrap();
                     }
                 }
 
@@ -165,74 +475,112 @@ impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build,
 
                 write!(s, ";").unwrap();
 
-                (s, d.lookup)
+                Ok((s, d.lookup))
             }
             #[cfg(feature = "mysql")]
             InsertImpl::MySQL(mut d) => {
-                if d.columns.is_empty() {
-                    let mut s = format!(
-                        "INSERT {}INTO `{}` DEFAULT VALUES",
-                        match d.on_conflict {
-                            OnConflict::ABORT => "OR ABORT ",
-                            OnConflict::ROLLBACK => "OR ROLLBACK ",
-                        },
-                        d.into_clause,
-                    );
-
-                    if let Some(ret_clause) = d.returning_clause {
-                        write!(s, " RETURNING ").unwrap();
-
-                        for (idx, c) in ret_clause.iter().enumerate() {
-                            write!(s, "`{c}`").unwrap();
-
-                            if idx != ret_clause.len() - 1 {
-                                write!(s, ", ").unwrap();
-                            }
-                        }
+                if let Some((_, updates)) = d.upsert {
+                    if (d.columns.is_empty() && d.select.is_none()) || updates.is_empty() {
+                        return Err(Error::SQLBuildError(String::from(
+                            "ON DUPLICATE KEY UPDATE requires columns to insert and updates",
+                        )));
                     }
-                    write!(s, ";").unwrap();
+                }
 
-                    return (s, d.lookup);
+                if d.returning_clause.is_some() {
+                    return Err(Error::UnsupportedFeature(String::from(
+                        "MySQL doesn't support RETURNING clauses",
+                    )));
+                }
+                if d.upsert_where.is_some() {
+                    return Err(Error::UnsupportedFeature(String::from(
+                        "MySQL doesn't support conditions on ON DUPLICATE KEY UPDATE",
+                    )));
                 }
 
-                let mut s = format!("INSERT INTO `{}` (", d.into_clause);
-                for (idx, x) in d.columns.iter().enumerate() {
-                    write!(s, "`{x}`").unwrap();
-                    if idx != d.columns.len() - 1 {
-                        write!(s, ", ").unwrap();
-                    }
+                // MySQL doesn't support DEFAULT VALUES, but accepts empty lists instead
+                if d.columns.is_empty() && d.select.is_none() {
+                    return Ok((
+                        format!(
+                            "INSERT INTO {} () VALUES ();",
+                            d.into_clause.quoted(DBImpl::MySQL)
+                        ),
+                        d.lookup,
+                    ));
                 }
-                write!(s, ") VALUES ").unwrap();
 
-                for (idx, x) in d.row_values.iter().enumerate() {
+                let mut s = format!("INSERT INTO {} ", d.into_clause.quoted(DBImpl::MySQL));
+                if !d.columns.is_empty() {
                     write!(s, "(").unwrap();
-                    for (idx_2, y) in x.iter().enumerate() {
-                        match y {
-                            Value::Ident(st) => write!(s, "{}", *st).unwrap(),
-                            Value::Choice(c) => write!(s, "{}", mysql::fmt(c)).unwrap(),
-                            Value::Null(NullType::Choice) => write!(s, "NULL").unwrap(),
-                            _ => {
-                                d.lookup.push(*y);
-                                write!(s, "?").unwrap();
+                    for (idx, x) in d.columns.iter().enumerate() {
+                        write!(s, "`{x}`").unwrap();
+                        if idx != d.columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    write!(s, ") ").unwrap();
+                }
+
+                if let Some(select) = d.select {
+                    select.build_to_writer(&mut s, &mut d.lookup)?;
+                } else {
+                    write!(s, "VALUES ").unwrap();
+                    for (idx, x) in d.row_values.iter().enumerate() {
+                        write!(s, "(").unwrap();
+                        for (idx_2, y) in x.iter().enumerate() {
+                            match y {
+                                Value::Ident(st) => write!(s, "{}", *st).unwrap(),
+                                Value::Choice(c) => write!(s, "{}", mysql::fmt(c)).unwrap(),
+                                Value::Null(NullType::Choice) => write!(s, "NULL").unwrap(),
+                                Value::Default => write!(s, "DEFAULT").unwrap(),
+                                Value::NextVal(_) => {
+                                    return Err(Error::UnsupportedFeature(String::from(
+                                        "MySQL doesn't support sequences",
+                                    )))
+                                }
+                                _ => {
+                                    d.lookup.push(*y);
+                                    write!(s, "?").unwrap();
+                                }
+                            }
+                            if idx_2 != x.len() - 1 {
+                                write!(s, ", ").unwrap();
                             }
                         }
-                        if idx_2 != x.len() - 1 {
+                        write!(s, ")").unwrap();
+                        if idx != d.row_values.len() - 1 {
                             write!(s, ", ").unwrap();
                         }
                     }
-                    write!(s, ")").unwrap();
-                    if idx != d.row_values.len() - 1 {
-                        write!(s, ", ").unwrap();
-                    }
                 }
 
-                if let Some(ret_clause) = d.returning_clause {
-                    write!(s, " RETURNING ").unwrap();
-
-                    for (idx, c) in ret_clause.iter().enumerate() {
-                        write!(s, "`{c}`").unwrap();
-
-                        if idx != ret_clause.len() - 1 {
+                if let Some((_, updates)) = d.upsert {
+                    write!(s, " ON DUPLICATE KEY UPDATE ").unwrap();
+                    for (idx, (column, value)) in updates.iter().enumerate() {
+                        match value {
+                            UpsertValue::Value(Value::Choice(c)) => {
+                                write!(s, "`{column}` = {}", mysql::fmt(c)).unwrap()
+                            }
+                            UpsertValue::Value(Value::Null(NullType::Choice)) => {
+                                write!(s, "`{column}` = NULL").unwrap()
+                            }
+                            UpsertValue::Value(Value::Default) => {
+                                write!(s, "`{column}` = DEFAULT").unwrap()
+                            }
+                            UpsertValue::Value(Value::NextVal(_)) => {
+                                return Err(Error::UnsupportedFeature(String::from(
+                                    "MySQL doesn't support sequences",
+                                )))
+                            }
+                            UpsertValue::Value(value) => {
+                                d.lookup.push(*value);
+                                write!(s, "`{column}` = ?").unwrap();
+                            }
+                            UpsertValue::Excluded(excluded) => {
+                                write!(s, "`{column}` = VALUES(`{excluded}`)").unwrap()
+                            }
+                        }
+                        if idx != updates.len() - 1 {
                             write!(s, ", ").unwrap();
                         }
                     }
@@ -240,12 +588,26 @@ impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build,
 
                 write!(s, ";").unwrap();
 
-                (s, d.lookup)
+                Ok((s, d.lookup))
             }
             #[cfg(feature = "postgres")]
             InsertImpl::Postgres(mut d) => {
-                if d.columns.is_empty() {
-                    let mut s = format!("INSERT INTO \"{}\" DEFAULT VALUES", d.into_clause);
+                if let Some((target, updates)) = d.upsert {
+                    if (d.columns.is_empty() && d.select.is_none())
+                        || matches!(target, ConflictTarget::Columns(columns) if columns.is_empty())
+                        || updates.is_empty()
+                    {
+                        return Err(Error::SQLBuildError(String::from(
+                            "ON CONFLICT DO UPDATE requires columns to insert, conflict columns and updates",
+                        )));
+                    }
+                }
+
+                if d.columns.is_empty() && d.select.is_none() {
+                    let mut s = format!(
+                        "INSERT INTO {} DEFAULT VALUES",
+                        d.into_clause.quoted(DBImpl::Postgres)
+                    );
 
                     if let Some(ret_clause) = d.returning_clause {
                         write!(s, " RETURNING ").unwrap();
@@ -260,37 +622,98 @@ impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build,
                     }
                     write!(s, ";").unwrap();
 
-                    return (s, d.lookup);
+                    return Ok((s, d.lookup));
                 }
 
-                let mut s = format!("INSERT INTO \"{}\" (", d.into_clause);
-                for (idx, x) in d.columns.iter().enumerate() {
-                    write!(s, "\"{x}\"").unwrap();
-                    if idx != d.columns.len() - 1 {
-                        write!(s, ", ").unwrap();
+                let mut s = format!("INSERT INTO {} ", d.into_clause.quoted(DBImpl::Postgres));
+                if !d.columns.is_empty() {
+                    write!(s, "(").unwrap();
+                    for (idx, x) in d.columns.iter().enumerate() {
+                        write!(s, "\"{x}\"").unwrap();
+                        if idx != d.columns.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
                     }
+                    write!(s, ") ").unwrap();
                 }
-                write!(s, ") VALUES ").unwrap();
 
-                for (idx, x) in d.row_values.iter().enumerate() {
-                    write!(s, "(").unwrap();
-                    for (idx_2, y) in x.iter().enumerate() {
-                        match y {
-                            Value::Ident(st) => write!(s, "\"{}\"", *st).unwrap(),
-                            Value::Choice(c) => write!(s, "{}", postgres::fmt(c)).unwrap(),
-                            Value::Null(NullType::Choice) => write!(s, "NULL").unwrap(),
-                            _ => {
-                                d.lookup.push(*y);
-                                write!(s, "${}", d.lookup.len()).unwrap();
+                if let Some(select) = d.select {
+                    select.build_to_writer(&mut s, &mut d.lookup)?;
+                } else {
+                    write!(s, "VALUES ").unwrap();
+                    for (idx, x) in d.row_values.iter().enumerate() {
+                        write!(s, "(").unwrap();
+                        for (idx_2, y) in x.iter().enumerate() {
+                            match y {
+                                Value::Ident(st) => write!(s, "\"{}\"", *st).unwrap(),
+                                Value::Choice(c) => write!(s, "{}", postgres::fmt(c)).unwrap(),
+                                Value::Null(NullType::Choice) => write!(s, "NULL").unwrap(),
+                                Value::Default => write!(s, "DEFAULT").unwrap(),
+                                Value::NextVal(seq) => {
+                                    write!(s, "{}", postgres::nextval(seq)).unwrap()
+                                }
+                                _ => {
+                                    d.lookup.push(*y);
+                                    write!(s, "${}", d.lookup.len()).unwrap();
+                                }
+                            }
+                            if idx_2 != x.len() - 1 {
+                                write!(s, ", ").unwrap();
                             }
                         }
-                        if idx_2 != x.len() - 1 {
+                        write!(s, ")").unwrap();
+                        if idx != d.row_values.len() - 1 {
                             write!(s, ", ").unwrap();
                         }
                     }
-                    write!(s, ")").unwrap();
-                    if idx != d.row_values.len() - 1 {
-                        write!(s, ", ").unwrap();
+                }
+
+                if let Some((target, updates)) = d.upsert {
+                    match target {
+                        ConflictTarget::Columns(conflict_columns) => {
+                            write!(s, " ON CONFLICT (").unwrap();
+                            for (idx, c) in conflict_columns.iter().enumerate() {
+                                write!(s, "\"{c}\"").unwrap();
+                                if idx != conflict_columns.len() - 1 {
+                                    write!(s, ", ").unwrap();
+                                }
+                            }
+                            write!(s, ")").unwrap();
+                        }
+                        ConflictTarget::Constraint(constraint) => {
+                            write!(s, " ON CONFLICT ON CONSTRAINT \"{constraint}\"").unwrap()
+                        }
+                    }
+                    write!(s, " DO UPDATE SET ").unwrap();
+                    for (idx, (column, value)) in updates.iter().enumerate() {
+                        match value {
+                            UpsertValue::Value(Value::Choice(c)) => {
+                                write!(s, "\"{column}\" = {}", postgres::fmt(c)).unwrap()
+                            }
+                            UpsertValue::Value(Value::Null(NullType::Choice)) => {
+                                write!(s, "\"{column}\" = NULL").unwrap()
+                            }
+                            UpsertValue::Value(Value::Default) => {
+                                write!(s, "\"{column}\" = DEFAULT").unwrap()
+                            }
+                            UpsertValue::Value(Value::NextVal(seq)) => {
+                                write!(s, "\"{column}\" = {}", postgres::nextval(seq)).unwrap()
+                            }
+                            UpsertValue::Value(value) => {
+                                d.lookup.push(*value);
+                                write!(s, "\"{column}\" = ${}", d.lookup.len()).unwrap();
+                            }
+                            UpsertValue::Excluded(excluded) => {
+                                write!(s, "\"{column}\" = excluded.\"{excluded}\"").unwrap()
+                            }
+                        }
+                        if idx != updates.len() - 1 {
+                            write!(s, ", ").unwrap();
+                        }
+                    }
+                    if let Some(condition) = d.upsert_where {
+                        let condition = condition.build(DBImpl::Postgres, &mut d.lookup)?;
+                        write!(s, " WHERE {condition}").unwrap();
                     }
                 }
 
@@ -308,8 +731,1206 @@ impl<'until_build, 'post_build> Insert<'post_build> for InsertImpl<'until_build,
 
                 write!(s, ";").unwrap();
 
-                (s, d.lookup)
+                Ok((s, d.lookup))
             }
         }
     }
 }
+
+impl<'until_build, 'post_build> InsertImpl<'until_build, 'post_build> {
+    /// Copy of the query inserting the given rows instead
+    fn with_rows(&self, rows: &'until_build [&'until_build [Value<'post_build>]]) -> Self {
+        let mut insert = self.clone();
+        match insert {
+            #[cfg(feature = "sqlite")]
+            InsertImpl::SQLite(ref mut d) => d.row_values = rows,
+            #[cfg(feature = "mysql")]
+            InsertImpl::MySQL(ref mut d) => d.row_values = rows,
+            #[cfg(feature = "postgres")]
+            InsertImpl::Postgres(ref mut d) => d.row_values = rows,
+        };
+        insert
+    }
+}
+
+/// Returns an error, if a row doesn't have a value for every column
+///
+/// Without columns, only a single row of default values can be inserted.
+fn check_row_lengths(d: &InsertData) -> Result<(), Error> {
+    if d.select.is_some() {
+        return Ok(());
+    }
+    if d.columns.is_empty() && d.row_values.len() > 1 {
+        return Err(Error::EmptyColumnList {
+            statement: "INSERT",
+        });
+    }
+    for (row_index, row) in d.row_values.iter().enumerate() {
+        if row.len() != d.columns.len() {
+            return Err(Error::ColumnValueCountMismatch {
+                expected: d.columns.len(),
+                found: row.len(),
+                row_index,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error, if a condition for the upsert is set without an ON CONFLICT DO UPDATE clause
+fn check_upsert_where(d: &InsertData) -> Result<(), Error> {
+    if d.upsert_where.is_some() && d.upsert.is_none() {
+        return Err(Error::SQLBuildError(String::from(
+            "A condition for ON CONFLICT DO UPDATE requires an ON CONFLICT DO UPDATE clause",
+        )));
+    }
+    Ok(())
+}
+
+/// Whether the value is bound as parameter, instead of being written into the query
+fn is_bound(value: &Value) -> bool {
+    !matches!(
+        value,
+        Value::Ident(_)
+            | Value::Choice(_)
+            | Value::Null(NullType::Choice)
+            | Value::Default
+            | Value::NextVal(_)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    #[cfg(feature = "postgres")]
+    use crate::conditional::UnaryCondition;
+    use crate::conditional::{BinaryCondition, Condition};
+    use crate::error::Error;
+    use crate::insert::Insert;
+    use crate::on_conflict::{ConflictTarget, UpsertValue};
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    use crate::select::Select;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    const COLUMNS: &[&str] = &["name", "age"];
+    const ROWS: &[&[Value<'static>]] = &[&[Value::String("foo"), Value::I64(23)]];
+    const RETURNING: &[&str] = &["id", "created_at"];
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn returning_sqlite() {
+        let (sql, values) = DBImpl::SQLite
+            .insert("user", COLUMNS, ROWS, Some(RETURNING))
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT OR ABORT INTO user (name, age) VALUES (?, ?) RETURNING \"id\", \"created_at\";"
+        );
+        assert_eq!(values, vec![Value::String("foo"), Value::I64(23)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn returning_mysql() {
+        assert!(DBImpl::MySQL
+            .insert("user", COLUMNS, ROWS, Some(RETURNING))
+            .build()
+            .is_err());
+        assert!(DBImpl::MySQL
+            .insert("user", &[], &[], Some(RETURNING))
+            .build()
+            .is_err());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn returning_postgres() {
+        let (sql, values) = DBImpl::Postgres
+            .insert("user", COLUMNS, ROWS, Some(RETURNING))
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2) RETURNING \"id\", \"created_at\";"
+        );
+        assert_eq!(values, vec![Value::String("foo"), Value::I64(23)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn insert_select_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let columns = [
+            DBImpl::SQLite.select_column(None, "name", None, None),
+            DBImpl::SQLite.select_column(None, "age", None, None),
+        ];
+        let select = DBImpl::SQLite
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition);
+
+        assert_eq!(
+            DBImpl::SQLite
+                .insert_select("adult", COLUMNS, &select, None)
+                .rollback_transaction()
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ROLLBACK INTO adult (name, age) SELECT name, age FROM user WHERE (age > ?);".to_string(),
+                vec![Value::I64(18)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn insert_select_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let columns = [
+            DBImpl::MySQL.select_column(None, "name", None, None),
+            DBImpl::MySQL.select_column(None, "age", None, None),
+        ];
+        let select = DBImpl::MySQL
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition);
+
+        assert_eq!(
+            DBImpl::MySQL
+                .insert_select("adult", COLUMNS, &select, None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO `adult` (`name`, `age`) SELECT `name`, `age` FROM user WHERE (age > ?);".to_string(),
+                vec![Value::I64(18)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn insert_select_postgres() {
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let select = DBImpl::Postgres.select(&columns, "user", &[], &[]);
+
+        assert_eq!(
+            DBImpl::Postgres
+                .insert_select("archive", &["name"], &select, Some(&["id"]))
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"archive\" (\"name\") SELECT \"name\" FROM \"user\" RETURNING \"id\";".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    const UPSERT: &[(&str, UpsertValue<'static, 'static>)] = &[
+        ("age", UpsertValue::Excluded("age")),
+        ("updated", UpsertValue::Value(Value::Bool(true))),
+    ];
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn upsert_sqlite() {
+        let (sql, values) = DBImpl::SQLite
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_do_update(&["name"], UPSERT)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT OR ABORT INTO user (name, age) VALUES (?, ?) ON CONFLICT (name) DO UPDATE SET age = excluded.age, updated = ?;"
+        );
+        assert_eq!(
+            values,
+            vec![Value::String("foo"), Value::I64(23), Value::Bool(true)]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn upsert_select_sqlite() {
+        let columns = [
+            DBImpl::SQLite.select_column(None, "name", None, None),
+            DBImpl::SQLite.select_column(None, "age", None, None),
+        ];
+        let select = DBImpl::SQLite.select(&columns, "staging", &[], &[]);
+        assert_eq!(
+            DBImpl::SQLite
+                .insert_select("user", COLUMNS, &select, None)
+                .on_conflict_do_update(&["name"], &[("age", UpsertValue::Excluded("age"))])
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO user (name, age) SELECT name, age FROM staging WHERE true ON CONFLICT (name) DO UPDATE SET age = excluded.age;".to_string(),
+                vec![]
+            )
+        );
+
+        let condition = younger_than(30);
+        let select = DBImpl::SQLite
+            .select(&columns, "staging", &[], &[])
+            .where_clause(&condition);
+        assert_eq!(
+            DBImpl::SQLite
+                .insert_select("user", COLUMNS, &select, None)
+                .on_conflict_do_update(&["name"], &[("age", UpsertValue::Excluded("age"))])
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO user (name, age) SELECT name, age FROM staging WHERE (age < ?) ON CONFLICT (name) DO UPDATE SET age = excluded.age;".to_string(),
+                vec![Value::I64(30)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn upsert_mysql() {
+        let (sql, values) = DBImpl::MySQL
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_do_update(&["name"], UPSERT)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO `user` (`name`, `age`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `age` = VALUES(`age`), `updated` = ?;"
+        );
+        assert_eq!(
+            values,
+            vec![Value::String("foo"), Value::I64(23), Value::Bool(true)]
+        );
+        assert!(DBImpl::MySQL
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_do_update(&["name"], &[])
+            .build()
+            .is_err());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn upsert_postgres() {
+        let (sql, values) = DBImpl::Postgres
+            .insert("user", COLUMNS, ROWS, Some(RETURNING))
+            .on_conflict_do_update(&["name"], UPSERT)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2) ON CONFLICT (\"name\") DO UPDATE SET \"age\" = excluded.\"age\", \"updated\" = $3 RETURNING \"id\", \"created_at\";"
+        );
+        assert_eq!(
+            values,
+            vec![Value::String("foo"), Value::I64(23), Value::Bool(true)]
+        );
+        assert!(DBImpl::Postgres
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_do_update(&[], UPSERT)
+            .build()
+            .is_err());
+    }
+
+    fn younger_than(age: i64) -> Condition<'static> {
+        Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(age)),
+        ])))
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn upsert_where_sqlite() {
+        let condition = younger_than(100);
+        let (sql, values) = DBImpl::SQLite
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_do_update(&["name"], UPSERT)
+            .on_conflict_update_where(&condition)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT OR ABORT INTO user (name, age) VALUES (?, ?) ON CONFLICT (name) DO UPDATE SET age = excluded.age, updated = ? WHERE (age < ?);"
+        );
+        assert_eq!(
+            values,
+            vec![
+                Value::String("foo"),
+                Value::I64(23),
+                Value::Bool(true),
+                Value::I64(100)
+            ]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn upsert_where_mysql() {
+        let condition = younger_than(100);
+        assert!(matches!(
+            DBImpl::MySQL
+                .insert("user", COLUMNS, ROWS, None)
+                .on_conflict_do_update(&["name"], UPSERT)
+                .on_conflict_update_where(&condition)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn upsert_where_postgres() {
+        let condition = younger_than(100);
+        let (sql, values) = DBImpl::Postgres
+            .insert("user", COLUMNS, ROWS, Some(RETURNING))
+            .on_conflict_target_do_update(ConflictTarget::Constraint("user_name_key"), UPSERT)
+            .on_conflict_update_where(&condition)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2) ON CONFLICT ON CONSTRAINT \"user_name_key\" DO UPDATE SET \"age\" = excluded.\"age\", \"updated\" = $3 WHERE (age < $4) RETURNING \"id\", \"created_at\";"
+        );
+        assert_eq!(
+            values,
+            vec![
+                Value::String("foo"),
+                Value::I64(23),
+                Value::Bool(true),
+                Value::I64(100)
+            ]
+        );
+
+        let rows: &[&[Value]] = &[
+            &[Value::String("foo"), Value::I64(23)],
+            &[Value::String("bar"), Value::I64(42)],
+        ];
+        let chunks = DBImpl::Postgres
+            .insert("user", COLUMNS, rows, None)
+            .on_conflict_do_update(&["name"], UPSERT)
+            .on_conflict_update_where(&condition)
+            .build_chunked(4)
+            .unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|(_, values)| values.len() == 4));
+
+        assert!(matches!(
+            DBImpl::Postgres
+                .insert("user", COLUMNS, ROWS, None)
+                .on_conflict_update_where(&condition)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn upsert_constraint_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .insert("user", COLUMNS, ROWS, None)
+                .on_conflict_target_do_update(ConflictTarget::Constraint("user_name_key"), UPSERT)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn upsert_constraint_mysql() {
+        let (sql, _) = DBImpl::MySQL
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_target_do_update(ConflictTarget::Constraint("user_name_key"), UPSERT)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO `user` (`name`, `age`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `age` = VALUES(`age`), `updated` = ?;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn upsert_constraint_postgres() {
+        let (sql, values) = DBImpl::Postgres
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_target_do_update(ConflictTarget::Constraint("user_name_key"), UPSERT)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2) ON CONFLICT ON CONSTRAINT \"user_name_key\" DO UPDATE SET \"age\" = excluded.\"age\", \"updated\" = $3;"
+        );
+        assert_eq!(
+            values,
+            vec![Value::String("foo"), Value::I64(23), Value::Bool(true)]
+        );
+        assert!(DBImpl::Postgres
+            .insert("user", COLUMNS, ROWS, None)
+            .on_conflict_target_do_update(ConflictTarget::Constraint("user_name_key"), &[])
+            .build()
+            .is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn upsert_returning_sqlite() {
+        let updates = [
+            ("age", UpsertValue::Excluded("age")),
+            ("note", UpsertValue::Value(Value::String("upserted"))),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .insert(
+                    "user",
+                    COLUMNS,
+                    &[
+                        &[Value::String("foo"), Value::I64(23)],
+                        &[Value::String("bar"), Value::I64(42)],
+                    ],
+                    Some(RETURNING),
+                )
+                .on_conflict_do_update(&["name"], &updates)
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO user (name, age) VALUES (?, ?), (?, ?) ON CONFLICT (name) DO UPDATE SET age = excluded.age, note = ? RETURNING \"id\", \"created_at\";".to_string(),
+                vec![
+                    Value::String("foo"),
+                    Value::I64(23),
+                    Value::String("bar"),
+                    Value::I64(42),
+                    Value::String("upserted"),
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn upsert_returning_postgres() {
+        let updates = [
+            ("age", UpsertValue::Excluded("age")),
+            ("updated", UpsertValue::Value(Value::Bool(true))),
+            ("note", UpsertValue::Value(Value::String("upserted"))),
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .insert(
+                    "user",
+                    COLUMNS,
+                    &[
+                        &[Value::String("foo"), Value::I64(23)],
+                        &[Value::String("bar"), Value::I64(42)],
+                    ],
+                    Some(&["id"]),
+                )
+                .on_conflict_do_update(&["name"], &updates)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2), ($3, $4) ON CONFLICT (\"name\") DO UPDATE SET \"age\" = excluded.\"age\", \"updated\" = $5, \"note\" = $6 RETURNING \"id\";".to_string(),
+                vec![
+                    Value::String("foo"),
+                    Value::I64(23),
+                    Value::String("bar"),
+                    Value::I64(42),
+                    Value::Bool(true),
+                    Value::String("upserted"),
+                ]
+            )
+        );
+    }
+
+    const UUID: Uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn uuid_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .insert(
+                    "session",
+                    &["id", "token"],
+                    &[&[Value::Uuid(UUID), Value::UuidHyphenated(UUID)]],
+                    None,
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO session (id, token) VALUES (?, ?);".to_string(),
+                vec![Value::Uuid(UUID), Value::UuidHyphenated(UUID)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn uuid_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .insert("session", &["id"], &[&[Value::Uuid(UUID)]], None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO `session` (`id`) VALUES (?);".to_string(),
+                vec![Value::Uuid(UUID)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn uuid_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .insert(
+                    "session",
+                    &["id", "token"],
+                    &[&[Value::Uuid(UUID), Value::UuidHyphenated(UUID)]],
+                    None,
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"session\" (\"id\", \"token\") VALUES ($1, $2);".to_string(),
+                vec![Value::Uuid(UUID), Value::UuidHyphenated(UUID)]
+            )
+        );
+    }
+
+    #[cfg(all(feature = "decimal", feature = "sqlite"))]
+    #[test]
+    fn decimal_sqlite() {
+        let price = "12345678901234567890.123456789".parse().unwrap();
+        assert_eq!(
+            DBImpl::SQLite
+                .insert("product", &["price"], &[&[Value::Decimal(price)]], None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO product (price) VALUES (?);".to_string(),
+                vec![Value::Decimal(price)]
+            )
+        );
+    }
+
+    #[cfg(all(feature = "decimal", feature = "mysql"))]
+    #[test]
+    fn decimal_mysql() {
+        let price = "-0.000000001".parse().unwrap();
+        assert_eq!(
+            DBImpl::MySQL
+                .insert("product", &["price"], &[&[Value::Decimal(price)]], None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO `product` (`price`) VALUES (?);".to_string(),
+                vec![Value::Decimal(price)]
+            )
+        );
+    }
+
+    #[cfg(all(feature = "decimal", feature = "postgres"))]
+    #[test]
+    fn decimal_postgres() {
+        let price = "12345678901234567890.123456789".parse().unwrap();
+        assert_eq!(
+            DBImpl::Postgres
+                .insert(
+                    "product",
+                    &["name", "price"],
+                    &[&[Value::String("foo"), Value::Decimal(price)]],
+                    None,
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"product\" (\"name\", \"price\") VALUES ($1, $2);".to_string(),
+                vec![
+                    Value::String("foo"),
+                    Value::Decimal(rust_decimal::Decimal::from_i128_with_scale(
+                        12345678901234567890123456789,
+                        9
+                    ))
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres-only")]
+    #[test]
+    fn array_postgres() {
+        let tags = [Value::String("rust"), Value::String("sql")];
+        let (sql, values) = DBImpl::Postgres
+            .insert(
+                "post",
+                &["title", "tags"],
+                &[&[Value::String("foo"), Value::Array(&tags)]],
+                None,
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO \"post\" (\"title\", \"tags\") VALUES ($1, $2);"
+        );
+        assert_eq!(values, vec![Value::String("foo"), Value::Array(&tags)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn binary_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .insert(
+                    "file",
+                    &["name", "content"],
+                    &[&[Value::String("foo"), Value::Binary(&[0xde, 0xad])]],
+                    None,
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO file (name, content) VALUES (?, ?);".to_string(),
+                vec![Value::String("foo"), Value::Binary(&[0xde, 0xad])]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn binary_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .insert("file", &["content"], &[&[Value::Binary(&[])]], None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO `file` (`content`) VALUES (?);".to_string(),
+                vec![Value::Binary(&[])]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn binary_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .insert(
+                    "file",
+                    &["name", "content"],
+                    &[&[Value::String("foo"), Value::Binary(&[0xbe, 0xef])]],
+                    None,
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"file\" (\"name\", \"content\") VALUES ($1, $2);".to_string(),
+                vec![Value::String("foo"), Value::Binary(&[0xbe, 0xef])]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn temporal_sqlite() {
+        use chrono::NaiveDate;
+
+        let day = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+        assert_eq!(
+            DBImpl::SQLite
+                .insert("event", &["day"], &[&[Value::ChronoNaiveDate(day)]], None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO event (day) VALUES (?);".to_string(),
+                vec![Value::ChronoNaiveDate(day)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn temporal_mysql() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let start = NaiveTime::from_hms_opt(8, 30, 0).unwrap();
+        let local_time = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap().and_time(start);
+        assert_eq!(
+            DBImpl::MySQL
+                .insert(
+                    "event",
+                    &["start", "local_time"],
+                    &[&[
+                        Value::ChronoNaiveTime(start),
+                        Value::ChronoNaiveDateTime(local_time)
+                    ]],
+                    None,
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO `event` (`start`, `local_time`) VALUES (?, ?);".to_string(),
+                vec![
+                    Value::ChronoNaiveTime(start),
+                    Value::ChronoNaiveDateTime(local_time)
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn temporal_postgres() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let utc_time = Utc.from_utc_datetime(
+            &NaiveDate::from_ymd_opt(2023, 1, 2)
+                .unwrap()
+                .and_hms_opt(8, 30, 0)
+                .unwrap(),
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .insert(
+                    "event",
+                    &["utc_time"],
+                    &[&[Value::ChronoDateTime(utc_time)]],
+                    None
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"event\" (\"utc_time\") VALUES ($1);".to_string(),
+                vec![Value::ChronoDateTime(utc_time)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn multiple_rows_sqlite() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("foo"), Value::I64(23)],
+            &[Value::String("bar"), Value::I64(42)],
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .insert("user", COLUMNS, rows, None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO user (name, age) VALUES (?, ?), (?, ?);".to_string(),
+                vec![
+                    Value::String("foo"),
+                    Value::I64(23),
+                    Value::String("bar"),
+                    Value::I64(42)
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn multiple_rows_mysql() {
+        let rows: &[&[Value]] = &[&[Value::I64(1)], &[Value::I64(2)], &[Value::I64(3)]];
+        let query = DBImpl::MySQL.insert("user", &["id"], rows, None);
+
+        assert_eq!(query.value_count(), 3);
+        assert_eq!(
+            query.build().unwrap(),
+            (
+                "INSERT INTO `user` (`id`) VALUES (?), (?), (?);".to_string(),
+                vec![Value::I64(1), Value::I64(2), Value::I64(3)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn multiple_rows_postgres() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("foo"), Value::I64(23)],
+            &[Value::String("bar"), Value::I64(42)],
+            &[Value::String("baz"), Value::I64(7)],
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .insert("user", COLUMNS, rows, None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2), ($3, $4), ($5, $6);"
+                    .to_string(),
+                vec![
+                    Value::String("foo"),
+                    Value::I64(23),
+                    Value::String("bar"),
+                    Value::I64(42),
+                    Value::String("baz"),
+                    Value::I64(7)
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn chunked_sqlite() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("a"), Value::I64(1)],
+            &[Value::String("b"), Value::I64(2)],
+            &[Value::String("c"), Value::I64(3)],
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .insert("user", COLUMNS, rows, None)
+                .build_chunked(5)
+                .unwrap(),
+            [
+                (
+                    "INSERT OR ABORT INTO user (name, age) VALUES (?, ?), (?, ?);".to_string(),
+                    vec![
+                        Value::String("a"),
+                        Value::I64(1),
+                        Value::String("b"),
+                        Value::I64(2)
+                    ]
+                ),
+                (
+                    "INSERT OR ABORT INTO user (name, age) VALUES (?, ?);".to_string(),
+                    vec![Value::String("c"), Value::I64(3)]
+                )
+            ]
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .insert("user", COLUMNS, rows, None)
+                .build_chunked(DBImpl::SQLite.max_params())
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(matches!(
+            DBImpl::SQLite
+                .insert("user", COLUMNS, rows, None)
+                .build_chunked(1),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn chunked_mysql() {
+        let rows: &[&[Value]] = &[&[Value::I64(1)], &[Value::I64(2)], &[Value::I64(3)]];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .insert("user", &["id"], rows, None)
+                .build_chunked(2)
+                .unwrap(),
+            [
+                (
+                    "INSERT INTO `user` (`id`) VALUES (?), (?);".to_string(),
+                    vec![Value::I64(1), Value::I64(2)]
+                ),
+                (
+                    "INSERT INTO `user` (`id`) VALUES (?);".to_string(),
+                    vec![Value::I64(3)]
+                )
+            ]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn chunked_postgres() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("a"), Value::I64(1)],
+            &[Value::String("b"), Value::I64(2)],
+            &[Value::String("c"), Value::I64(3)],
+        ];
+
+        // Placeholders restart at $1 in every chunk
+        assert_eq!(
+            DBImpl::Postgres
+                .insert("user", COLUMNS, rows, None)
+                .build_chunked(4)
+                .unwrap(),
+            [
+                (
+                    "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2), ($3, $4);"
+                        .to_string(),
+                    vec![
+                        Value::String("a"),
+                        Value::I64(1),
+                        Value::String("b"),
+                        Value::I64(2)
+                    ]
+                ),
+                (
+                    "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, $2);".to_string(),
+                    vec![Value::String("c"), Value::I64(3)]
+                )
+            ]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn default_values_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .insert("user", &[], &[], None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT OR ABORT INTO user DEFAULT VALUES;".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn default_values_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .insert("user", &[], &[], None)
+                .build()
+                .unwrap(),
+            ("INSERT INTO `user` () VALUES ();".to_string(), vec![])
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn default_values_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .insert("user", &[], &[], None)
+                .build()
+                .unwrap(),
+            ("INSERT INTO \"user\" DEFAULT VALUES;".to_string(), vec![])
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn default_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .insert(
+                    "user",
+                    COLUMNS,
+                    &[&[Value::String("foo"), Value::Default]],
+                    None
+                )
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn default_mysql() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("foo"), Value::Default],
+            &[Value::Default, Value::I64(42)],
+        ];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .insert("user", COLUMNS, rows, None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO `user` (`name`, `age`) VALUES (?, DEFAULT), (DEFAULT, ?);".to_string(),
+                vec![Value::String("foo"), Value::I64(42)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn default_postgres() {
+        let rows: &[&[Value]] = &[&[Value::Default, Value::I64(42)]];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .insert("user", COLUMNS, rows, None)
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"user\" (\"name\", \"age\") VALUES (DEFAULT, $1);".to_string(),
+                vec![Value::I64(42)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn next_val_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .insert("user", &["id"], &[&[Value::NextVal("user_id")]], None)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn next_val_mysql() {
+        assert!(matches!(
+            DBImpl::MySQL
+                .insert("user", &["id"], &[&[Value::NextVal("user_id")]], None)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn next_val_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .insert(
+                    "user",
+                    COLUMNS,
+                    &[&[Value::String("foo"), Value::NextVal("user_age")]],
+                    None
+                )
+                .build()
+                .unwrap(),
+            (
+                "INSERT INTO \"user\" (\"name\", \"age\") VALUES ($1, nextval('\"user_age\"'));"
+                    .to_string(),
+                vec![Value::String("foo")]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn mismatched_sqlite() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("foo"), Value::I64(42)],
+            &[Value::String("bar")],
+        ];
+
+        assert!(matches!(
+            DBImpl::SQLite.insert("user", COLUMNS, rows, None).build(),
+            Err(Error::ColumnValueCountMismatch {
+                expected: 2,
+                found: 1,
+                row_index: 1,
+            })
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn mismatched_mysql() {
+        let rows: &[&[Value]] = &[&[Value::I64(1), Value::I64(2)]];
+
+        assert!(matches!(
+            DBImpl::MySQL.insert("user", &["id"], rows, None).build(),
+            Err(Error::ColumnValueCountMismatch {
+                expected: 1,
+                found: 2,
+                row_index: 0,
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn mismatched_postgres() {
+        let rows: &[&[Value]] = &[
+            &[Value::String("foo"), Value::I64(42)],
+            &[Value::String("bar"), Value::I64(7)],
+            &[Value::String("baz")],
+        ];
+
+        assert!(matches!(
+            DBImpl::Postgres.insert("user", COLUMNS, rows, None).build(),
+            Err(Error::ColumnValueCountMismatch {
+                expected: 2,
+                found: 1,
+                row_index: 2,
+            })
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn empty_columns_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite
+                .insert("user", &[], &[&[], &[]], None)
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "INSERT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn empty_columns_mysql() {
+        assert!(matches!(
+            DBImpl::MySQL.insert("user", &[], &[&[], &[]], None).build(),
+            Err(Error::EmptyColumnList {
+                statement: "INSERT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn empty_columns_postgres() {
+        assert!(matches!(
+            DBImpl::Postgres
+                .insert("user", &[], &[&[], &[]], Some(RETURNING))
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "INSERT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn invalid_subquery_in_upsert_where_postgres() {
+        let subquery = DBImpl::Postgres.select(&[], "post", &[], &[]);
+        let condition = Condition::UnaryCondition(UnaryCondition::Exists(Box::new(
+            Condition::Subquery(Box::new(subquery)),
+        )));
+        assert!(matches!(
+            DBImpl::Postgres
+                .insert("user", COLUMNS, ROWS, None)
+                .on_conflict_do_update(&["name"], UPSERT)
+                .on_conflict_update_where(&condition)
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+}
@@ -1,13 +1,16 @@
 use std::fmt::{Display, Formatter, Write};
 
 use crate::conditional::{BuildCondition, Condition};
+use crate::error::Error;
+use crate::select::SelectImpl;
+use crate::table_ref::TableRef;
 use crate::value::Value;
 use crate::DBImpl;
 
 /**
 Definition of all available Join types
 */
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum JoinType {
     /// Normal join operation.
     ///
@@ -28,6 +31,8 @@ pub enum JoinType {
     /// First, an inner join is performed.
     /// Then, for each row in T2 that does not satisfy the join condition with any row in T1,
     /// a joined row is added with null values in columns of T1.
+    ///
+    /// Requires SQLite 3.39.0 or newer.
     RightJoin,
     /// Given:
     /// T1 FULL JOIN T2 ON ..
@@ -37,6 +42,8 @@ pub enum JoinType {
     /// a joined row is added with null values in columns of T1.
     /// Also, for each row in T1 that does not satisfy the join condition with any row in T2,
     /// a joined row is added with null values in columns of T2.
+    ///
+    /// Requires SQLite 3.39.0 or newer.
     FullJoin,
 }
 
@@ -52,10 +59,45 @@ impl Display for JoinType {
     }
 }
 
+/**
+Representation of what is joined.
+*/
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JoinTarget<'until_build, 'post_query> {
+    /// Name of a table, optionally qualified by its schema
+    Table(TableRef<'until_build>),
+    /// A subquery, its bound values are placed before the ones of the join condition
+    Subquery(&'until_build SelectImpl<'until_build, 'post_query>),
+}
+
+/**
+Representation of how the rows of a join are matched.
+*/
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JoinOn<'until_build, 'post_query> {
+    /// Match the rows satisfying the condition, `JOIN b ON ..`
+    On(&'until_build Condition<'post_query>),
+    /// Match the rows with equal values in the given columns of both tables, `JOIN b USING (..)`
+    Using(&'until_build [&'until_build str]),
+    /// Match the rows with equal values in all columns with the same name, `NATURAL JOIN b`
+    ///
+    /// NATURAL RIGHT JOIN and NATURAL FULL JOIN require SQLite 3.39.0 or newer.
+    Natural,
+}
+
 /**
 Trait representing a join table builder.
  */
 pub trait JoinTable<'post_query> {
+    /**
+    Mark the join as LATERAL, so the joined subquery can reference
+    columns of the preceding tables.
+
+    Only valid for subqueries, requires MySQL 8.0.14 or newer.
+    Not supported by SQLite.
+     */
+    fn lateral(self) -> Self;
+
     /**
     Method to build a join table expression.
 
@@ -63,22 +105,24 @@ pub trait JoinTable<'post_query> {
     - `s`: Mutable reference to String to write to.
     - `lookup`: List of values for bind parameter.
     */
-    fn build(self, s: &mut String, lookup: &mut Vec<Value<'post_query>>);
+    fn build(self, s: &mut String, lookup: &mut Vec<Value<'post_query>>) -> Result<(), Error>;
 }
 
 /**
 Data of a JOIN expression.
 */
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct JoinTableData<'until_build, 'post_query> {
     /// Type of the join operation
     pub join_type: JoinType,
-    /// Name of the join table
-    pub table_name: &'until_build str,
+    /// The table or subquery to join
+    pub join_target: JoinTarget<'until_build, 'post_query>,
+    /// Whether the join is LATERAL
+    pub lateral: bool,
     /// Alias for the join table
     pub join_alias: &'until_build str,
-    /// Condition to apply the join on
-    pub join_condition: &'until_build Condition<'post_query>,
+    /// How the rows are matched
+    pub join_on: JoinOn<'until_build, 'post_query>,
 }
 
 /**
@@ -86,7 +130,7 @@ Representation of the JOIN expression
 
 Should only be constructed via [DBImpl::join_table].
 */
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum JoinTableImpl<'until_build, 'post_query> {
     /**
     SQLite representation of a JOIN expression.
@@ -108,38 +152,122 @@ pub enum JoinTableImpl<'until_build, 'post_query> {
 impl<'until_build, 'post_query> JoinTable<'post_query>
     for JoinTableImpl<'until_build, 'post_query>
 {
-    fn build(self, s: &mut String, lookup: &mut Vec<Value<'post_query>>) {
+    fn lateral(mut self) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
-            JoinTableImpl::SQLite(d) => write!(
-                s,
-                "{} {} AS {} ON {}",
-                d.join_type,
-                d.table_name,
-                d.join_alias,
-                d.join_condition.build(DBImpl::SQLite, lookup)
-            )
-            .unwrap(),
+            JoinTableImpl::SQLite(ref mut d) => d.lateral = true,
             #[cfg(feature = "mysql")]
-            JoinTableImpl::MySQL(d) => write!(
-                s,
-                "{} {} AS {} ON {}",
-                d.join_type,
-                d.table_name,
-                d.join_alias,
-                d.join_condition.build(DBImpl::MySQL, lookup)
-            )
-            .unwrap(),
+            JoinTableImpl::MySQL(ref mut d) => d.lateral = true,
             #[cfg(feature = "postgres")]
-            JoinTableImpl::Postgres(d) => write!(
-                s,
-                "{} \"{}\" AS {} ON {}",
-                d.join_type,
-                d.table_name,
-                d.join_alias,
-                d.join_condition.build(DBImpl::Postgres, lookup)
-            )
-            .unwrap(),
+            JoinTableImpl::Postgres(ref mut d) => d.lateral = true,
+        };
+        self
+    }
+
+    fn build(self, s: &mut String, lookup: &mut Vec<Value<'post_query>>) -> Result<(), Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            JoinTableImpl::SQLite(d) => {
+                if d.lateral {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support LATERAL joins".to_string(),
+                    ));
+                }
+                write_join_type(s, &d);
+                match d.join_target {
+                    JoinTarget::Table(table_name) => write!(s, "{table_name}").unwrap(),
+                    JoinTarget::Subquery(query) => {
+                        write!(s, "(").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ")").unwrap();
+                    }
+                }
+                write_join_on(s, DBImpl::SQLite, &d, lookup)?;
+            }
+            #[cfg(feature = "mysql")]
+            JoinTableImpl::MySQL(d) => {
+                write_join_type(s, &d);
+                match d.join_target {
+                    JoinTarget::Table(table_name) => {
+                        check_lateral(&d)?;
+                        write!(s, "{table_name}").unwrap()
+                    }
+                    JoinTarget::Subquery(query) => {
+                        if d.lateral {
+                            write!(s, "LATERAL ").unwrap();
+                        }
+                        write!(s, "(").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ")").unwrap();
+                    }
+                }
+                write_join_on(s, DBImpl::MySQL, &d, lookup)?;
+            }
+            #[cfg(feature = "postgres")]
+            JoinTableImpl::Postgres(d) => {
+                write_join_type(s, &d);
+                match d.join_target {
+                    JoinTarget::Table(table_name) => {
+                        check_lateral(&d)?;
+                        write!(s, "{}", table_name.quoted(DBImpl::Postgres)).unwrap()
+                    }
+                    JoinTarget::Subquery(query) => {
+                        if d.lateral {
+                            write!(s, "LATERAL ").unwrap();
+                        }
+                        write!(s, "(").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ")").unwrap();
+                    }
+                }
+                write_join_on(s, DBImpl::Postgres, &d, lookup)?;
+            }
         }
+        Ok(())
+    }
+}
+
+/// Writes the join type, prefixed with NATURAL for natural joins
+fn write_join_type(s: &mut String, d: &JoinTableData) {
+    if matches!(d.join_on, JoinOn::Natural) {
+        write!(s, "NATURAL ").unwrap();
+    }
+    write!(s, "{} ", d.join_type).unwrap();
+}
+
+/// Writes the alias and the condition or columns the join is matched on
+fn write_join_on<'post_query>(
+    s: &mut String,
+    dialect: DBImpl,
+    d: &JoinTableData<'_, 'post_query>,
+    lookup: &mut Vec<Value<'post_query>>,
+) -> Result<(), Error> {
+    write!(s, " AS {}", d.join_alias).unwrap();
+    match d.join_on {
+        JoinOn::On(condition) => write!(s, " ON {}", condition.build(dialect, lookup)?).unwrap(),
+        JoinOn::Using(columns) => write!(
+            s,
+            " USING ({})",
+            columns
+                .iter()
+                .map(|column| dialect.quote_identifier(column))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .unwrap(),
+        JoinOn::Natural => {}
+    }
+    Ok(())
+}
+
+/// Returns an error, if a table is joined with LATERAL
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+fn check_lateral(d: &JoinTableData) -> Result<(), Error> {
+    if d.lateral {
+        return Err(Error::SQLBuildError(format!(
+            "Couldn't join {}: Only subqueries can be joined LATERAL",
+            d.join_alias
+        )));
     }
+    Ok(())
 }
@@ -15,16 +15,28 @@ pub mod conditional;
 pub mod create_column;
 /// Implementation of SQL CREATE INDEX
 pub mod create_index;
+/// Implementation of SQL CREATE SEQUENCE statements
+pub mod create_sequence;
 /// Implementation of SQL CREATE TABLE statements
 pub mod create_table;
 /// Implementation of SQL CREATE TRIGGER statements
 pub mod create_trigger;
+/// Implementation of SQL CREATE VIEW statements
+pub mod create_view;
 /// Implementation of SQL DELETE operation
 pub mod delete;
+/// Implementation of SQL DROP INDEX statements
+pub mod drop_index;
 /// Implementation of SQL DROP TABLE statements
 pub mod drop_table;
+/// Implementation of SQL DROP TRIGGER statements
+pub mod drop_trigger;
+/// Implementation of SQL DROP VIEW statements
+pub mod drop_view;
 /// Definition of error types that can occur.
 pub mod error;
+/// Implementation of GROUP BY groupings
+pub mod grouping;
 /// Implementation of SQL INSERT statements
 pub mod insert;
 /// Implementation of JOIN statements
@@ -35,16 +47,29 @@ pub mod limit_clause;
 pub mod on_conflict;
 /// Implementation of ORDER BY expressions
 pub mod ordering;
+/// Implementation of built statements and their values
+pub mod query;
+/// Implementation of row locking clauses
+pub mod row_lock;
 /// Implementation of SQL SELECT statements
 pub mod select;
 /// Implementation of identifiers in select queries
 pub mod select_column;
+/// Implementation of batches of multiple statements
+pub mod statement_batch;
+/// Implementation of schema qualified table references
+pub mod table_ref;
+/// Implementation of transaction control statements
+pub mod transaction;
+/// Implementation of SQL TRUNCATE TABLE statements
+pub mod truncate_table;
 /// Implementation of SQL UPDATE statements
 pub mod update;
 /// Implementation of supported datatypes
 pub mod value;
 
 mod db_specific;
+mod pretty;
 
 use rorm_declaration::imr::{Annotation, DbType};
 
@@ -59,18 +84,29 @@ use crate::create_column::CreateColumnPostgresData;
 use crate::create_column::CreateColumnSQLiteData;
 use crate::create_column::{CreateColumnImpl, SQLAnnotation};
 use crate::create_index::{CreateIndex, CreateIndexData, CreateIndexImpl};
+use crate::create_sequence::{CreateSequence, CreateSequenceData, CreateSequenceImpl};
 use crate::create_table::{CreateTable, CreateTableData, CreateTableImpl};
 use crate::create_trigger::{
     SQLCreateTrigger, SQLCreateTriggerOperation, SQLCreateTriggerPointInTime,
 };
+use crate::create_view::{CreateView, CreateViewData, CreateViewImpl};
 use crate::delete::{Delete, DeleteData, DeleteImpl};
+use crate::drop_index::{DropIndex, DropIndexData, DropIndexImpl};
 use crate::drop_table::{DropTable, DropTableData, DropTableImpl};
+use crate::drop_trigger::{DropTrigger, DropTriggerData, DropTriggerImpl};
+use crate::drop_view::{DropView, DropViewData, DropViewImpl};
+use crate::error::Error;
+use crate::grouping::Grouping;
 use crate::insert::{Insert, InsertData, InsertImpl};
-use crate::join_table::{JoinTableData, JoinTableImpl, JoinType};
+use crate::join_table::{JoinOn, JoinTableData, JoinTableImpl, JoinTarget, JoinType};
 use crate::on_conflict::OnConflict;
 use crate::ordering::OrderByEntry;
-use crate::select::{Select, SelectData, SelectImpl};
-use crate::select_column::{SelectColumnData, SelectColumnImpl};
+use crate::select::{FromClause, SelectData, SelectImpl};
+use crate::select_column::{SelectColumnData, SelectColumnImpl, COUNT_ALL, ONE};
+use crate::statement_batch::StatementBatch;
+use crate::table_ref::TableRef;
+use crate::transaction::{Transaction, TransactionData, TransactionImpl, TransactionStatement};
+use crate::truncate_table::{TruncateTable, TruncateTableData, TruncateTableImpl};
 use crate::update::{Update, UpdateData, UpdateImpl};
 use crate::value::Value;
 
@@ -99,15 +135,23 @@ impl DBImpl {
     */
     pub fn create_table<'until_build, 'post_build>(
         &self,
-        name: &'until_build str,
+        name: impl Into<TableRef<'until_build>>,
     ) -> impl CreateTable<'until_build, 'post_build>
     where
         'post_build: 'until_build,
     {
         let d = CreateTableData {
-            name,
+            name: name.into(),
             columns: vec![],
             if_not_exists: false,
+            temporary: false,
+            on_commit_drop: false,
+            without_rowid: false,
+            options: vec![],
+            primary_key: &[],
+            uniques: vec![],
+            checks: vec![],
+            comment: None,
             lookup: vec![],
             pre_statements: vec![],
             statements: vec![],
@@ -132,17 +176,18 @@ impl DBImpl {
     - `point_in_time`: [Option] of [SQLCreateTriggerPointInTime]: When to execute the trigger.
     - `operation`: [SQLCreateTriggerOperation]: The operation that invokes the trigger.
     */
-    pub fn create_trigger(
+    pub fn create_trigger<'until_build>(
         &self,
         name: &str,
-        table_name: &str,
+        table_name: impl Into<TableRef<'until_build>>,
         point_in_time: Option<SQLCreateTriggerPointInTime>,
         operation: SQLCreateTriggerOperation,
     ) -> SQLCreateTrigger {
         SQLCreateTrigger {
             name: name.to_string(),
-            table_name: table_name.to_string(),
+            table_name: table_name.into().to_string(),
             if_not_exists: false,
+            or_replace: false,
             point_in_time,
             operation,
             statements: vec![],
@@ -160,15 +205,17 @@ impl DBImpl {
     pub fn create_index<'until_build>(
         &self,
         name: &'until_build str,
-        table_name: &'until_build str,
+        table_name: impl Into<TableRef<'until_build>>,
     ) -> impl CreateIndex<'until_build> {
         let d = CreateIndexData {
             name,
-            table_name,
+            table_name: table_name.into(),
             unique: false,
+            fulltext: false,
             if_not_exists: false,
             columns: vec![],
             condition: None,
+            where_clause: None,
         };
 
         match self {
@@ -181,6 +228,69 @@ impl DBImpl {
         }
     }
 
+    /**
+    The entry point to create a sequence.
+
+    Only supported by Postgres, the other dialects return an error on build.
+
+    **Parameter**:
+    - `name`: Name of the sequence.
+    */
+    pub fn create_sequence<'until_build>(
+        &self,
+        name: &'until_build str,
+    ) -> impl CreateSequence<'until_build> {
+        let d = CreateSequenceData {
+            name,
+            start_with: None,
+            increment_by: None,
+            min_value: None,
+            max_value: None,
+            cycle: false,
+            if_not_exists: false,
+        };
+
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => CreateSequenceImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => CreateSequenceImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => CreateSequenceImpl::Postgres(d),
+        }
+    }
+
+    /**
+    The entry point to create a view.
+
+    **Parameter**:
+    - `name`: Name of the view.
+    */
+    pub fn create_view<'until_build, 'post_build>(
+        &self,
+        name: &'until_build str,
+    ) -> impl CreateView<'until_build, 'post_build>
+    where
+        'post_build: 'until_build,
+    {
+        let d = CreateViewData {
+            name,
+            columns: &[],
+            query: None,
+            or_replace: false,
+            if_not_exists: false,
+        };
+
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => CreateViewImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => CreateViewImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => CreateViewImpl::Postgres(d),
+        }
+    }
+
     /**
     The entry point to drop a table.
 
@@ -189,11 +299,12 @@ impl DBImpl {
     */
     pub fn drop_table<'until_build>(
         &self,
-        name: &'until_build str,
-    ) -> impl DropTable + 'until_build {
+        name: impl Into<TableRef<'until_build>>,
+    ) -> impl DropTable<'until_build> {
         let d = DropTableData {
-            name,
+            names: vec![name.into()],
             if_exists: false,
+            cascade: false,
         };
         match self {
             #[cfg(feature = "sqlite")]
@@ -205,6 +316,142 @@ impl DBImpl {
         }
     }
 
+    /**
+    The entry point to drop an index.
+
+    **Parameter**:
+    - `name`: Name of the index to drop.
+    - `table_name`: Table the index is defined on. Required by MySQL.
+    */
+    pub fn drop_index<'until_build>(
+        &self,
+        name: &'until_build str,
+        table_name: Option<&'until_build str>,
+    ) -> impl DropIndex + 'until_build {
+        let d = DropIndexData {
+            name,
+            table_name,
+            if_exists: false,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => DropIndexImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => DropIndexImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => DropIndexImpl::Postgres(d),
+        }
+    }
+
+    /**
+    The entry point to drop a trigger.
+
+    **Parameter**:
+    - `name`: Name of the trigger to drop.
+    - `table_name`: Table the trigger is defined on. Required by Postgres.
+    */
+    pub fn drop_trigger<'until_build>(
+        &self,
+        name: &'until_build str,
+        table_name: Option<&'until_build str>,
+    ) -> impl DropTrigger + 'until_build {
+        let d = DropTriggerData {
+            name,
+            table_name,
+            if_exists: false,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => DropTriggerImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => DropTriggerImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => DropTriggerImpl::Postgres(d),
+        }
+    }
+
+    /**
+    The entry point to drop a view.
+
+    **Parameter**:
+    - `name`: Name of the view to drop.
+    */
+    pub fn drop_view<'until_build>(&self, name: &'until_build str) -> impl DropView + 'until_build {
+        let d = DropViewData {
+            name,
+            if_exists: false,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => DropViewImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => DropViewImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => DropViewImpl::Postgres(d),
+        }
+    }
+
+    /**
+    The entry point to collect multiple statements into a single script, e.g. for migrations.
+    */
+    pub fn statement_batch<'post_build>(&self) -> StatementBatch<'post_build> {
+        StatementBatch {
+            dialect: *self,
+            statements: vec![],
+        }
+    }
+
+    /**
+    The entry point to control transactions and savepoints.
+
+    Savepoint names are quoted as identifiers.
+
+    **Parameter**:
+    - `statement`: The transaction control statement to build.
+    */
+    pub fn transaction<'until_build>(
+        &self,
+        statement: TransactionStatement<'until_build>,
+    ) -> impl Transaction + 'until_build {
+        let d = TransactionData {
+            statement,
+            isolation_level: None,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => TransactionImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => TransactionImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => TransactionImpl::Postgres(d),
+        }
+    }
+
+    /**
+    The entry point to truncate a table.
+
+    **Parameter**:
+    - `name`: Name of the table to truncate.
+    */
+    pub fn truncate_table<'until_build>(
+        &self,
+        name: impl Into<TableRef<'until_build>>,
+    ) -> impl TruncateTable<'until_build> {
+        let d = TruncateTableData {
+            name: name.into(),
+            restart_identity: false,
+            cascade: false,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => TruncateTableImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => TruncateTableImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => TruncateTableImpl::Postgres(d),
+        }
+    }
+
     /**
     The entry point to alter a table.
 
@@ -214,17 +461,15 @@ impl DBImpl {
     */
     pub fn alter_table<'until_build, 'post_build>(
         &self,
-        name: &'until_build str,
+        name: impl Into<TableRef<'until_build>>,
         operation: AlterTableOperation<'until_build, 'post_build>,
-    ) -> impl AlterTable<'post_build> + 'until_build
+    ) -> impl AlterTable<'until_build, 'post_build>
     where
         'post_build: 'until_build,
     {
         let d = AlterTableData {
-            name,
-            operation,
-            lookup: vec![],
-            statements: vec![],
+            name: name.into(),
+            operations: vec![operation],
         };
 
         match self {
@@ -248,11 +493,12 @@ impl DBImpl {
     */
     pub fn create_column<'until_build, 'post_build>(
         &self,
-        table_name: &'until_build str,
+        table_name: impl Into<TableRef<'until_build>>,
         name: &'until_build str,
         data_type: DbType,
         annotations: &'post_build [Annotation],
     ) -> CreateColumnImpl<'until_build, 'post_build> {
+        let table_name = table_name.into();
         #[cfg(not(any(feature = "postgres", feature = "sqlite")))]
         let _ = table_name;
 
@@ -278,6 +524,12 @@ impl DBImpl {
                 table_name,
                 data_type,
                 annotations: a,
+                collation: None,
+                generated: None,
+                check: None,
+                comment: None,
+                deferrable: None,
+                default: None,
                 statements: None,
                 lookup: None,
             }),
@@ -286,6 +538,12 @@ impl DBImpl {
                 name,
                 data_type,
                 annotations: a,
+                collation: None,
+                generated: None,
+                check: None,
+                comment: None,
+                deferrable: None,
+                default: None,
                 statements: None,
                 lookup: None,
             }),
@@ -295,6 +553,12 @@ impl DBImpl {
                 table_name,
                 data_type,
                 annotations: a,
+                collation: None,
+                generated: None,
+                check: None,
+                comment: None,
+                deferrable: None,
+                default: None,
                 pre_statements: None,
                 statements: None,
             }),
@@ -312,20 +576,80 @@ impl DBImpl {
     pub fn select<'until_build, 'post_build>(
         &self,
         columns: &'until_build [SelectColumnImpl],
-        from_clause: &'until_build str,
+        from_clause: impl Into<TableRef<'until_build>>,
         joins: &'until_build [JoinTableImpl<'until_build, 'post_build>],
         order_by_clause: &'until_build [OrderByEntry<'until_build>],
-    ) -> impl Select<'until_build, 'post_build> {
+    ) -> SelectImpl<'until_build, 'post_build> {
         let d = SelectData {
+            with: vec![],
+            recursive: false,
             join_tables: joins,
             resulting_columns: columns,
+            expressions: vec![],
             limit: None,
             offset: None,
-            from_clause,
-            where_clause: None,
+            bind_limit: false,
+            from_clause: FromClause::Table(from_clause.into()),
+            from_tables: vec![],
+            cross_joins: vec![],
+            where_groups: vec![],
+            group_by: Grouping::Columns(&[]),
+            having_clause: None,
             distinct: false,
-            lookup: vec![],
-            order_by_clause,
+            distinct_on: &[],
+            order_by_clause: order_by_clause.to_vec(),
+            compound: vec![],
+            lock: None,
+            tablesample: None,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => SelectImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => SelectImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => SelectImpl::Postgres(d),
+        }
+    }
+
+    /**
+    Build a select query selecting from the result of another query.
+
+    **Parameter**:
+    - `columns`: The columns to select.
+    - `subquery`: The query to select from.
+    - `alias`: Alias of the subquery, used to refer to its columns.
+    - `joins`: List of join tables.
+    */
+    pub fn select_from_subquery<'until_build, 'post_build>(
+        &self,
+        columns: &'until_build [SelectColumnImpl],
+        subquery: SelectImpl<'until_build, 'post_build>,
+        alias: &'until_build str,
+        joins: &'until_build [JoinTableImpl<'until_build, 'post_build>],
+        order_by_clause: &'until_build [OrderByEntry<'until_build>],
+    ) -> SelectImpl<'until_build, 'post_build> {
+        let d = SelectData {
+            with: vec![],
+            recursive: false,
+            join_tables: joins,
+            resulting_columns: columns,
+            expressions: vec![],
+            limit: None,
+            offset: None,
+            bind_limit: false,
+            from_clause: FromClause::Subquery(Box::new(subquery), alias),
+            from_tables: vec![],
+            cross_joins: vec![],
+            where_groups: vec![],
+            group_by: Grouping::Columns(&[]),
+            having_clause: None,
+            distinct: false,
+            distinct_on: &[],
+            order_by_clause: order_by_clause.to_vec(),
+            compound: vec![],
+            lock: None,
+            tablesample: None,
         };
         match self {
             #[cfg(feature = "sqlite")]
@@ -337,32 +661,153 @@ impl DBImpl {
         }
     }
 
+    /**
+    Build a select query counting the rows, e.g. `SELECT COUNT(*) FROM "user"`.
+
+    Add conditions to the query to count the matching rows only.
+
+    **Parameter**:
+    - `from_clause`: The table to count the rows of.
+    */
+    pub fn count<'until_build, 'post_build>(
+        &self,
+        from_clause: impl Into<TableRef<'until_build>>,
+    ) -> SelectImpl<'until_build, 'post_build> {
+        let columns: &'static [SelectColumnImpl<'static>] = match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => &[SelectColumnImpl::SQLite(COUNT_ALL)],
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => &[SelectColumnImpl::MySQL(COUNT_ALL)],
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => &[SelectColumnImpl::Postgres(COUNT_ALL)],
+        };
+        self.select(columns, from_clause, &[], &[])
+    }
+
+    /**
+    Build a query checking whether the subquery returns any row,
+    e.g. `SELECT EXISTS(SELECT 1 FROM "user" WHERE ...)`.
+
+    The columns and expressions selected by the subquery are replaced by `1`,
+    so it may be built without columns.
+    Returns an error, if the subquery was built for another dialect
+    or is combined with other queries, e.g. by [Select::union](crate::select::Select::union).
+
+    **Parameter**:
+    - `subquery`: The query to check, built for the same dialect.
+    */
+    pub fn exists<'until_build, 'post_build>(
+        &self,
+        mut subquery: SelectImpl<'until_build, 'post_build>,
+    ) -> Result<(String, Vec<Value<'post_build>>), Error> {
+        subquery.check_dialect(*self)?;
+        let compound = match &mut subquery {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(d) => {
+                d.resulting_columns = &[SelectColumnImpl::SQLite(ONE)];
+                d.expressions.clear();
+                !d.compound.is_empty()
+            }
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(d) => {
+                d.resulting_columns = &[SelectColumnImpl::MySQL(ONE)];
+                d.expressions.clear();
+                !d.compound.is_empty()
+            }
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(d) => {
+                d.resulting_columns = &[SelectColumnImpl::Postgres(ONE)];
+                d.expressions.clear();
+                !d.compound.is_empty()
+            }
+        };
+        // Replacing the columns would change the result of INTERSECT and EXCEPT
+        if compound {
+            return Err(Error::SQLBuildError(
+                "EXISTS doesn't support compound subqueries".to_string(),
+            ));
+        }
+
+        let mut s = String::from("SELECT EXISTS(");
+        let mut lookup = vec![];
+        subquery.build_to_writer(&mut s, &mut lookup)?;
+        s.push_str(");");
+        Ok((s, lookup))
+    }
+
     /**
     Build an INSERT query.
 
     **Parameter**:
     - `into_clause`: The table to insert into.
     - `insert_columns`: The column names to insert into.
+      If empty, a single row of default values is inserted.
     - `insert_values`: slice of slice of [Value]: The values to insert.
     - `returning_clause`: Optional slice of string to retrieve after the insert.
+      Not supported by MySQL.
     */
     pub fn insert<'until_build, 'post_build>(
         &self,
-        into_clause: &'until_build str,
+        into_clause: impl Into<TableRef<'until_build>>,
         insert_columns: &'until_build [&'until_build str],
         insert_values: &'until_build [&'until_build [Value<'post_build>]],
         returning_clause: Option<&'until_build [&'until_build str]>,
-    ) -> impl Insert<'post_build> + 'until_build
+    ) -> impl Insert<'until_build, 'post_build>
     where
         'post_build: 'until_build,
     {
         let d = InsertData {
-            into_clause,
+            into_clause: into_clause.into(),
             columns: insert_columns,
             row_values: insert_values,
             lookup: vec![],
             on_conflict: OnConflict::ABORT,
             returning_clause,
+            select: None,
+            upsert: None,
+            upsert_where: None,
+        };
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => InsertImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => InsertImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => InsertImpl::Postgres(d),
+        }
+    }
+
+    /**
+    Build an INSERT query, which inserts the result of a select query.
+
+    **Parameter**:
+    - `into_clause`: The table to insert into.
+    - `insert_columns`: The column names to insert into.
+      If empty, the columns of the table are used in their declared order.
+    - `select`: [SelectImpl]: The query providing the rows to insert.
+    - `returning_clause`: Optional slice of string to retrieve after the insert.
+      Not supported by MySQL.
+    */
+    pub fn insert_select<'until_build, 'post_build>(
+        &self,
+        into_clause: impl Into<TableRef<'until_build>>,
+        insert_columns: &'until_build [&'until_build str],
+        select: &'until_build SelectImpl<'until_build, 'post_build>,
+        returning_clause: Option<&'until_build [&'until_build str]>,
+    ) -> impl Insert<'until_build, 'post_build>
+    where
+        'post_build: 'until_build,
+    {
+        let d = InsertData {
+            into_clause: into_clause.into(),
+            columns: insert_columns,
+            row_values: &[],
+            lookup: vec![],
+            on_conflict: OnConflict::ABORT,
+            returning_clause,
+            select: Some(select),
+            upsert: None,
+            upsert_where: None,
         };
         match self {
             #[cfg(feature = "sqlite")]
@@ -382,12 +827,17 @@ impl DBImpl {
     */
     pub fn delete<'until_build, 'post_query>(
         &self,
-        table_name: &'until_build str,
-    ) -> impl Delete<'until_build, 'post_query> {
+        table_name: impl Into<TableRef<'until_build>>,
+    ) -> impl Delete<'until_build, 'post_query>
+    where
+        'post_query: 'until_build,
+    {
         let d = DeleteData {
-            model: table_name,
+            model: table_name.into(),
+            using: None,
+            limit: None,
             lookup: vec![],
-            where_clause: None,
+            where_groups: vec![],
         };
         match self {
             #[cfg(feature = "sqlite")]
@@ -407,13 +857,19 @@ impl DBImpl {
     */
     pub fn update<'until_build, 'post_query>(
         &self,
-        table_name: &'until_build str,
-    ) -> impl Update<'until_build, 'post_query> {
+        table_name: impl Into<TableRef<'until_build>>,
+    ) -> impl Update<'until_build, 'post_query>
+    where
+        'post_query: 'until_build,
+    {
         let d = UpdateData {
-            model: table_name,
+            model: table_name.into(),
             on_conflict: OnConflict::ABORT,
+            from: None,
+            limit: None,
             updates: vec![],
-            where_clause: None,
+            bulk: None,
+            where_groups: vec![],
             lookup: vec![],
         };
         match self {
@@ -438,15 +894,77 @@ impl DBImpl {
     pub fn join_table<'until_build, 'post_query>(
         &self,
         join_type: JoinType,
-        table_name: &'until_build str,
+        table_name: impl Into<TableRef<'until_build>>,
         join_alias: &'until_build str,
         join_condition: &'until_build Condition<'post_query>,
     ) -> JoinTableImpl<'until_build, 'post_query> {
-        let d = JoinTableData {
+        self.join_table_on(
             join_type,
             table_name,
             join_alias,
-            join_condition,
+            JoinOn::On(join_condition),
+        )
+    }
+
+    /**
+    The entry point for a JOIN expression builder matching the rows
+    by a condition, shared columns or all columns with the same name.
+
+    **Parameter**:
+    - `join_type`: [JoinType]: Type for a JOIN expression
+    - `table_name`: Table to perform the join on
+    - `join_alias`: Alias for the join table
+    - `join_on`: [JoinOn] how the rows are matched
+    */
+    pub fn join_table_on<'until_build, 'post_query>(
+        &self,
+        join_type: JoinType,
+        table_name: impl Into<TableRef<'until_build>>,
+        join_alias: &'until_build str,
+        join_on: JoinOn<'until_build, 'post_query>,
+    ) -> JoinTableImpl<'until_build, 'post_query> {
+        let d = JoinTableData {
+            join_type,
+            join_target: JoinTarget::Table(table_name.into()),
+            lateral: false,
+            join_alias,
+            join_on,
+        };
+
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => JoinTableImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => JoinTableImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => JoinTableImpl::Postgres(d),
+        }
+    }
+
+    /**
+    The entry point for a JOIN expression builder joining a subquery.
+
+    Use [crate::join_table::JoinTable::lateral] to allow the subquery to reference the preceding tables.
+
+    **Parameter**:
+    - `join_type`: [JoinType]: Type for a JOIN expression
+    - `subquery`: Subquery to perform the join on
+    - `join_alias`: Alias for the subquery
+    - `join_condition`: [Condition] to apply to the join
+    */
+    pub fn join_subquery<'until_build, 'post_query>(
+        &self,
+        join_type: JoinType,
+        subquery: &'until_build SelectImpl<'until_build, 'post_query>,
+        join_alias: &'until_build str,
+        join_condition: &'until_build Condition<'post_query>,
+    ) -> JoinTableImpl<'until_build, 'post_query> {
+        let d = JoinTableData {
+            join_type,
+            join_target: JoinTarget::Subquery(subquery),
+            lateral: false,
+            join_alias,
+            join_on: JoinOn::On(join_condition),
         };
 
         match self {
@@ -464,7 +982,7 @@ impl DBImpl {
 
     **Parameter**:
     - `table_name`: Optional table name
-    - `column_name`: Name of the column
+    - `column_name`: Name of the column, use `*` to select all columns
     - `select_alias`: Alias for the selector
     - `aggregation`: Optional aggregation function
      */
@@ -480,6 +998,9 @@ impl DBImpl {
             column_name,
             select_alias,
             aggregation,
+            distinct: false,
+            cast: None,
+            raw: false,
         };
 
         match self {
@@ -491,4 +1012,368 @@ impl DBImpl {
             DBImpl::Postgres => SelectColumnImpl::Postgres(d),
         }
     }
+
+    /**
+    The entry point for a column selector builder selecting a raw SQL expression,
+    e.g. `NULL` to pad the columns of a UNION.
+
+    The expression is written as is, so do not pass unchecked data to it.
+    Use [Select::add_expression](crate::select::Select::add_expression) to select bound values.
+
+    **Parameter**:
+    - `expression`: The SQL expression to select
+    - `select_alias`: Alias for the selector
+     */
+    pub fn select_raw_column<'until_build>(
+        &self,
+        expression: &'until_build str,
+        select_alias: Option<&'until_build str>,
+    ) -> SelectColumnImpl<'until_build> {
+        let d = SelectColumnData {
+            table_name: None,
+            column_name: expression,
+            select_alias,
+            aggregation: None,
+            distinct: false,
+            cast: None,
+            raw: true,
+        };
+
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => SelectColumnImpl::SQLite(d),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => SelectColumnImpl::MySQL(d),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => SelectColumnImpl::Postgres(d),
+        }
+    }
+
+    /**
+    Quote an identifier according to the rules of the dialect.
+
+    SQLite and Postgres use double quotes, MySQL uses backticks.
+    Quote characters inside the identifier are escaped by doubling them.
+    The identifier is always treated as a single name, so dots are not interpreted as separators.
+
+    **Parameter**:
+    - `ident`: The identifier to quote.
+    */
+    pub fn quote_identifier(&self, ident: &str) -> String {
+        let quote = match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => '"',
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => '`',
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => '"',
+        };
+
+        let mut s = String::with_capacity(ident.len() + 2);
+        s.push(quote);
+        for c in ident.chars() {
+            if c == quote {
+                s.push(quote);
+            }
+            s.push(c);
+        }
+        s.push(quote);
+        s
+    }
+
+    /**
+    The maximum number of parameters a single statement can bind.
+
+    SQLite versions before 3.32 allow 999 parameters, MySQL and Postgres 65535.
+    Can be passed to [Insert::build_chunked].
+    */
+    pub fn max_params(&self) -> usize {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => 999,
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => 65535,
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => 65535,
+        }
+    }
+
+    /**
+    Wrap a built statement in EXPLAIN to inspect its query plan.
+
+    SQLite uses `EXPLAIN QUERY PLAN`, as its plain `EXPLAIN` lists bytecode instead.
+    Note that `EXPLAIN ANALYZE` executes the statement.
+
+    **Parameter**:
+    - `query`: The result of building a statement, the bound values are passed through unchanged.
+    - `analyze`: Use `EXPLAIN ANALYZE` to include run time statistics, not supported by SQLite.
+    */
+    pub fn explain<'post_build>(
+        &self,
+        query: (String, Vec<Value<'post_build>>),
+        analyze: bool,
+    ) -> Result<(String, Vec<Value<'post_build>>), Error> {
+        let (sql, lookup) = query;
+        let prefix = match self {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => {
+                if analyze {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support EXPLAIN ANALYZE".to_string(),
+                    ));
+                }
+                "EXPLAIN QUERY PLAN"
+            }
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => {
+                if analyze {
+                    "EXPLAIN ANALYZE"
+                } else {
+                    "EXPLAIN"
+                }
+            }
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => {
+                if analyze {
+                    "EXPLAIN ANALYZE"
+                } else {
+                    "EXPLAIN"
+                }
+            }
+        };
+        Ok((format!("{prefix} {sql}"), lookup))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conditional::{BinaryCondition, Condition};
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use crate::error::Error;
+    use crate::select::Select;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn explain_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let query = DBImpl::SQLite
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            DBImpl::SQLite.explain(query.clone(), false).unwrap(),
+            (
+                "EXPLAIN QUERY PLAN SELECT name FROM user WHERE (id = ?);".to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+        assert!(matches!(
+            DBImpl::SQLite.explain(query, true),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn explain_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let query = DBImpl::MySQL
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            DBImpl::MySQL.explain(query.clone(), false).unwrap(),
+            (
+                "EXPLAIN SELECT `name` FROM user WHERE (id = ?);".to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+        assert_eq!(
+            DBImpl::MySQL.explain(query, true).unwrap(),
+            (
+                "EXPLAIN ANALYZE SELECT `name` FROM user WHERE (id = ?);".to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn explain_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let query = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            DBImpl::Postgres.explain(query, true).unwrap(),
+            (
+                "EXPLAIN ANALYZE SELECT \"name\" FROM \"user\" WHERE (id = $1);".to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn quote_identifier_sqlite() {
+        assert_eq!(DBImpl::SQLite.quote_identifier("select"), "\"select\"");
+        assert_eq!(
+            DBImpl::SQLite.quote_identifier("user.name"),
+            "\"user.name\""
+        );
+        assert_eq!(DBImpl::SQLite.quote_identifier("a\"b"), "\"a\"\"b\"");
+        assert_eq!(DBImpl::SQLite.quote_identifier("a`b"), "\"a`b\"");
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn quote_identifier_mysql() {
+        assert_eq!(DBImpl::MySQL.quote_identifier("select"), "`select`");
+        assert_eq!(DBImpl::MySQL.quote_identifier("user.name"), "`user.name`");
+        assert_eq!(DBImpl::MySQL.quote_identifier("a`b"), "`a``b`");
+        assert_eq!(DBImpl::MySQL.quote_identifier("a\"b"), "`a\"b`");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn quote_identifier_postgres() {
+        assert_eq!(DBImpl::Postgres.quote_identifier("select"), "\"select\"");
+        assert_eq!(
+            DBImpl::Postgres.quote_identifier("user.name"),
+            "\"user.name\""
+        );
+        assert_eq!(DBImpl::Postgres.quote_identifier("a\"b"), "\"a\"\"b\"");
+        assert_eq!(DBImpl::Postgres.quote_identifier("a`b"), "\"a`b\"");
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn count_and_exists_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .count("user")
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT COUNT(*) FROM user WHERE (age > ?);".to_string(),
+                vec![Value::I64(18)]
+            )
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .exists(
+                    DBImpl::SQLite
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .unwrap(),
+            (
+                "SELECT EXISTS(SELECT 1 FROM user WHERE (age > ?));".to_string(),
+                vec![Value::I64(18)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn count_and_exists_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL.count("user").build().unwrap(),
+            ("SELECT COUNT(*) FROM user;".to_string(), vec![])
+        );
+        assert_eq!(
+            DBImpl::MySQL
+                .exists(DBImpl::MySQL.select(&columns, "user", &[], &[]))
+                .unwrap(),
+            ("SELECT EXISTS(SELECT 1 FROM user);".to_string(), vec![])
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn count_and_exists_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .count("user")
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT COUNT(*) FROM \"user\" WHERE (age > $1);".to_string(),
+                vec![Value::I64(18)]
+            )
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .exists(
+                    DBImpl::Postgres
+                        .select(&columns, "user", &[], &[])
+                        .where_clause(&condition)
+                )
+                .unwrap(),
+            (
+                "SELECT EXISTS(SELECT 1 FROM \"user\" WHERE (age > $1));".to_string(),
+                vec![Value::I64(18)]
+            )
+        );
+    }
+
+    #[cfg(all(feature = "sqlite", feature = "postgres"))]
+    #[test]
+    fn exists_other_dialect() {
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres.exists(DBImpl::SQLite.select(&columns, "user", &[], &[])),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn exists_compound_postgres() {
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        let subquery = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .union(DBImpl::Postgres.select(&columns, "member", &[], &[]));
+        assert!(matches!(
+            DBImpl::Postgres.exists(subquery),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
 }
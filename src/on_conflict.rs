@@ -1,3 +1,5 @@
+use crate::value::Value;
+
 /**
 Representation of an ON CONFLICT case in SQL.
 
@@ -14,3 +16,32 @@ pub enum OnConflict {
     /// If there's no transaction, the behaviour is equivalent with [OnConflict::ABORT]
     ROLLBACK,
 }
+
+/**
+Representation of the value a column is set to by an ON CONFLICT DO UPDATE clause.
+ */
+#[derive(Debug, Copy, Clone)]
+pub enum UpsertValue<'until_build, 'post_build> {
+    /// The value to set the column to
+    Value(Value<'post_build>),
+    /// The value of the given column in the row that was proposed for insertion.
+    ///
+    /// Rendered as `excluded.column` for SQLite and Postgres and `VALUES(column)` for MySQL.
+    Excluded(&'until_build str),
+}
+
+/**
+Representation of the conflict target of an ON CONFLICT DO UPDATE clause.
+
+MySQL has no conflict targets, `ON DUPLICATE KEY UPDATE` checks every unique index,
+so the target is ignored.
+ */
+#[derive(Debug, Copy, Clone)]
+pub enum ConflictTarget<'until_build> {
+    /// The columns of the unique constraint to check for conflicts
+    Columns(&'until_build [&'until_build str]),
+    /// The name of the unique constraint to check for conflicts.
+    ///
+    /// Rendered as `ON CONFLICT ON CONSTRAINT "name"`, only supported by Postgres.
+    Constraint(&'until_build str),
+}
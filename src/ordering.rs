@@ -1,7 +1,7 @@
 /**
 All supported orderings
  */
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Ordering {
     /// Ascending ordering
     Asc,
@@ -9,10 +9,21 @@ pub enum Ordering {
     Desc,
 }
 
+/**
+Position of NULL values in an ordering
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NullsOrder {
+    /// NULL values are sorted before all other values
+    First,
+    /// NULL values are sorted after all other values
+    Last,
+}
+
 /**
 Representation of an entry in a ORDER BY expression
 */
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct OrderByEntry<'until_build> {
     /// Ordering to apply
     pub ordering: Ordering,
@@ -20,4 +31,13 @@ pub struct OrderByEntry<'until_build> {
     pub table_name: Option<&'until_build str>,
     /// Column to apply the ordering to
     pub column_name: &'until_build str,
+    /// Optional collation to compare the values with, e.g. `NOCASE` for SQLite
+    ///
+    /// The collation is passed through as is, postgres quotes it as identifier.
+    pub collation: Option<&'until_build str>,
+    /// Optional position of NULL values, the database's default is used if not set
+    ///
+    /// MySQL lacks NULLS FIRST and NULLS LAST, so it is emulated by ordering by
+    /// `CASE WHEN column IS NULL THEN .. END` first.
+    pub nulls: Option<NullsOrder>,
 }
@@ -0,0 +1,240 @@
+/**
+Keywords that start a new clause.
+
+Keywords that are a prefix of another one have to be listed after it.
+*/
+const CLAUSES: &[&str] = &[
+    "ON DUPLICATE KEY UPDATE",
+    "ON CONFLICT",
+    "UNION ALL",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "SELECT",
+    "FROM",
+    "CROSS JOIN",
+    "LEFT JOIN",
+    "RIGHT JOIN",
+    "FULL JOIN",
+    "JOIN",
+    "USING",
+    "WHERE",
+    "GROUP BY",
+    "HAVING",
+    "ORDER BY",
+    "LIMIT",
+    "VALUES",
+    "SET",
+    "RETURNING",
+    "FOR UPDATE",
+    "FOR SHARE",
+];
+
+/**
+Format a compact SQL statement into a multi-line one.
+
+Every clause starts on a new line, clauses of subqueries are indented by their depth.
+Only whitespace outside of quotes is changed, so placeholders stay in the same order.
+*/
+pub(crate) fn format(sql: &str) -> String {
+    let mut s = String::with_capacity(sql.len());
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut prev = None;
+    let mut chars = sql.char_indices();
+
+    while let Some((idx, c)) = chars.next() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            s.push(c);
+            prev = Some(c);
+            continue;
+        }
+
+        if prev == Some(' ') && !s.trim_end().is_empty() {
+            if let Some(clause) = CLAUSES
+                .iter()
+                .find(|clause| starts_clause(&sql[idx..], clause))
+            {
+                s.truncate(s.trim_end().len());
+                s.push('\n');
+                s.push_str(&"  ".repeat(depth));
+                s.push_str(clause);
+                // Clauses are ASCII only, so their length matches the number of chars
+                chars.nth(clause.len() - 2);
+                prev = clause.chars().last();
+                continue;
+            }
+        }
+
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ';' => s.truncate(s.trim_end().len()),
+            _ => {}
+        }
+        s.push(c);
+        prev = Some(c);
+    }
+
+    s
+}
+
+/**
+Checks whether `rest` starts with the keyword `clause` as a whole word.
+*/
+fn starts_clause(rest: &str, clause: &str) -> bool {
+    rest.starts_with(clause)
+        && matches!(
+            rest[clause.len()..].chars().next(),
+            None | Some(' ' | '(' | ';')
+        )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conditional::{BinaryCondition, Condition};
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use crate::join_table::JoinType;
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    use crate::limit_clause::LimitClause;
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use crate::ordering::{OrderByEntry, Ordering};
+    use crate::pretty::format;
+    use crate::select::Select;
+    #[cfg(feature = "postgres")]
+    use crate::update::Update;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    fn equals<'a>(lhs: Value<'a>, rhs: Value<'a>) -> Condition<'a> {
+        Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(lhs),
+            Condition::Value(rhs),
+        ])))
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn select_sqlite() {
+        let join_condition = equals(Value::Ident("user.id"), Value::Ident("p.user_id"));
+        let where_clause = Condition::Conjunction(vec![
+            equals(Value::Ident("p.published"), Value::Bool(true)),
+            equals(Value::Ident("name"), Value::String("bob")),
+        ]);
+        let joins = [DBImpl::SQLite.join_table(JoinType::LeftJoin, "post", "p", &join_condition)];
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Desc,
+            table_name: None,
+            column_name: "name",
+            collation: None,
+            nulls: None,
+        }];
+        let select = || {
+            DBImpl::SQLite
+                .select(&columns, "user", &joins, &order_by)
+                .where_clause(&where_clause)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: Some(5),
+                })
+        };
+
+        assert_eq!(
+            select().build().unwrap(),
+            (
+                "SELECT name FROM user LEFT JOIN post AS p ON (user.id = p.user_id) WHERE ((p.published = ?) AND (name = ?)) ORDER BY name DESC LIMIT 10 OFFSET 5;".to_string(),
+                vec![Value::Bool(true), Value::String("bob")]
+            )
+        );
+        assert_eq!(
+            select().build_pretty().unwrap(),
+            (
+                "SELECT name\nFROM user\nLEFT JOIN post AS p ON (user.id = p.user_id)\nWHERE ((p.published = ?) AND (name = ?))\nORDER BY name DESC\nLIMIT 10 OFFSET 5;".to_string(),
+                vec![Value::Bool(true), Value::String("bob")]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn select_mysql() {
+        let where_clause = equals(Value::Ident("name"), Value::String("bob"));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_clause(&where_clause)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: None,
+                })
+                .build_pretty()
+                .unwrap(),
+            (
+                "SELECT `name`\nFROM user\nWHERE (name = ?)\nLIMIT 10;".to_string(),
+                vec![Value::String("bob")]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn select_postgres() {
+        let join_condition = equals(Value::Ident("user.id"), Value::Ident("p.user_id"));
+        let joins = [DBImpl::Postgres.join_table(JoinType::LeftJoin, "post", "p", &join_condition)];
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Desc,
+            table_name: None,
+            column_name: "name",
+            collation: None,
+            nulls: None,
+        }];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &joins, &order_by)
+                .build_pretty()
+                .unwrap(),
+            (
+                "SELECT \"name\"\nFROM \"user\"\nLEFT JOIN \"post\" AS p ON (user.id = p.user_id)\nORDER BY \"name\" DESC;".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn update_postgres() {
+        let condition = equals(Value::Ident("id"), Value::I64(1));
+        let query = || {
+            DBImpl::Postgres
+                .update("user")
+                .add_update("name", Value::String("bob"))
+                .where_clause(&condition)
+        };
+
+        let (compact, compact_lookup) = query().build().unwrap();
+        let (pretty, pretty_lookup) = query().build_pretty().unwrap();
+        assert_eq!(compact_lookup, pretty_lookup);
+        assert!(!compact.contains('\n'));
+        assert_eq!(
+            pretty,
+            "UPDATE \"user\"\nSET \"name\" = $1\nWHERE (id = $2);"
+        );
+    }
+
+    #[test]
+    fn subqueries_and_quotes() {
+        assert_eq!(
+            format("SELECT \"FROM\" FROM t WHERE (id IN (SELECT id FROM b WHERE (x = 'a WHERE b'))) ;"),
+            "SELECT \"FROM\"\nFROM t\nWHERE (id IN (SELECT id\n    FROM b\n    WHERE (x = 'a WHERE b')));"
+        );
+    }
+}
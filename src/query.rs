@@ -0,0 +1,490 @@
+use std::fmt::{Display, Formatter, Write};
+
+use crate::error::Error;
+use crate::value::Value;
+use crate::DBImpl;
+
+/**
+A built SQL statement together with the values to bind to it.
+
+Returned by the `build_query` methods of the statement builders.
+It converts from and into the `(String, Vec<Value>)` tuples returned by their `build` methods,
+statements without values, e.g. from [crate::drop_table::DropTable::build], convert via [Query::from].
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query<'post_build> {
+    sql: String,
+    values: Vec<Value<'post_build>>,
+}
+
+impl<'post_build> Query<'post_build> {
+    /**
+    Create a new query.
+
+    **Parameter**:
+    - `sql`: The SQL statement.
+    - `values`: The values to bind to the statement's placeholders, in order.
+     */
+    pub fn new(sql: String, values: Vec<Value<'post_build>>) -> Self {
+        Self { sql, values }
+    }
+
+    /**
+    The SQL statement.
+     */
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /**
+    The values to bind to the statement's placeholders, in order.
+     */
+    pub fn values(&self) -> &[Value<'post_build>] {
+        &self.values
+    }
+
+    /**
+    The number of values to bind to the statement.
+     */
+    pub fn value_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /**
+    Format the statement over multiple lines with every clause on its own line.
+
+    Meant for logging, the values stay the same.
+     */
+    pub fn pretty(self) -> Self {
+        Self {
+            sql: crate::pretty::format(&self.sql),
+            values: self.values,
+        }
+    }
+
+    /**
+    Bind identical values only once, their placeholders refer to the same value instead.
+
+    Only Postgres `$n` placeholders can be reused, statements with `?` placeholders
+    are returned unchanged.
+    Placeholders are renumbered in the order of their first occurrence.
+     */
+    pub fn deduplicate_values(self) -> Self {
+        let mut values: Vec<Value<'post_build>> = vec![];
+        let sql = replace_placeholders(&self.sql, Syntax::NUMBERED, |index, s| {
+            let value = index
+                .and_then(|index| self.values.get(index.checked_sub(1)?))
+                .ok_or_else(|| Error::SQLBuildError("Placeholder without a value".to_string()))?;
+            let position = match values.iter().position(|v| v == value) {
+                Some(position) => position,
+                None => {
+                    values.push(*value);
+                    values.len() - 1
+                }
+            };
+            write!(s, "${}", position + 1)?;
+            Ok(())
+        });
+
+        match sql {
+            // Not a placeholder of this query
+            Err(_) => self,
+            Ok(_) if values.is_empty() => self,
+            Ok(sql) => Self { sql, values },
+        }
+    }
+
+    /**
+    Returns the SQL statement with the values written as literals in place of their placeholders.
+
+    Meant for logging and debugging only, execute the statement with bound values instead.
+    Returns an error, if a value has no literal representation, see [Value::to_sql_literal].
+
+    **Parameter**:
+    - `dialect`: The dialect the query was built for.
+     */
+    pub fn to_inlined_sql(&self, dialect: DBImpl) -> Result<String, Error> {
+        inline_values(&self.sql, &self.values, dialect)
+    }
+
+    /**
+    Split the query into the SQL statement and its values.
+     */
+    pub fn into_parts(self) -> (String, Vec<Value<'post_build>>) {
+        (self.sql, self.values)
+    }
+}
+
+impl<'post_build> From<(String, Vec<Value<'post_build>>)> for Query<'post_build> {
+    fn from((sql, values): (String, Vec<Value<'post_build>>)) -> Self {
+        Self::new(sql, values)
+    }
+}
+
+impl From<String> for Query<'_> {
+    fn from(sql: String) -> Self {
+        Self::new(sql, vec![])
+    }
+}
+
+impl<'post_build> From<Query<'post_build>> for (String, Vec<Value<'post_build>>) {
+    fn from(query: Query<'post_build>) -> Self {
+        query.into_parts()
+    }
+}
+
+/**
+Writes the SQL statement, the values are omitted.
+*/
+impl Display for Query<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.sql)
+    }
+}
+
+/**
+How placeholders and string literals are written in a dialect.
+*/
+#[derive(Copy, Clone)]
+struct Syntax {
+    /// Placeholders are numbered `$n` instead of `?`
+    numbered: bool,
+    /// Backslashes escape the next character in string literals
+    backslash_escapes: bool,
+}
+
+impl Syntax {
+    /// Postgres placeholders, backslashes are only escapes in `E'...'` strings
+    const NUMBERED: Self = Self {
+        numbered: true,
+        backslash_escapes: false,
+    };
+
+    fn of(dialect: DBImpl) -> Self {
+        match dialect {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => Self {
+                numbered: false,
+                backslash_escapes: false,
+            },
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => Self {
+                numbered: false,
+                backslash_escapes: true,
+            },
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => Self::NUMBERED,
+        }
+    }
+}
+
+/**
+Copy the SQL statement, `replace` writes the placeholders.
+
+Quoted strings, quoted identifiers and Postgres dollar quoted strings are copied unchanged,
+as are `$n` sequences which are part of an unquoted identifier.
+`replace` gets the index of a `$n` placeholder, `None` for a `?` placeholder.
+*/
+fn replace_placeholders(
+    sql: &str,
+    syntax: Syntax,
+    mut replace: impl FnMut(Option<usize>, &mut String) -> Result<(), Error>,
+) -> Result<String, Error> {
+    let bytes = sql.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || !b.is_ascii();
+    let in_word = |i: usize| i > 0 && is_word(bytes[i - 1]);
+
+    let mut s = String::with_capacity(sql.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                let escape_string = syntax.numbered
+                    && quote == b'\''
+                    && i > 0
+                    && bytes[i - 1].eq_ignore_ascii_case(&b'e')
+                    && !in_word(i - 1);
+                let escapes = escape_string || (syntax.backslash_escapes && quote != b'`');
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if escapes && bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                // A doubled quote is skipped as two adjacent literals
+                i += 1;
+            }
+            b'?' if !syntax.numbered => {
+                s.push_str(&sql[copied..i]);
+                replace(None, &mut s)?;
+                i += 1;
+                copied = i;
+            }
+            b'$' if syntax.numbered && !in_word(i) => {
+                let start = i + 1;
+                let digits = bytes[start..].iter().take_while(|b| b.is_ascii_digit());
+                let end = start + digits.count();
+                if end > start {
+                    s.push_str(&sql[copied..i]);
+                    replace(Some(sql[start..end].parse().unwrap_or(usize::MAX)), &mut s)?;
+                    i = end;
+                    copied = i;
+                    continue;
+                }
+
+                // Dollar quoted string, e.g. $$...$$ or $body$...$body$
+                let tag = bytes[start..]
+                    .iter()
+                    .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_');
+                let tag_end = start + tag.count();
+                if bytes.get(tag_end) == Some(&b'$') {
+                    let tag = &sql[i..=tag_end];
+                    i = sql[tag_end + 1..]
+                        .find(tag)
+                        .map_or(bytes.len(), |end| tag_end + 1 + end + tag.len());
+                } else {
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    s.push_str(&sql[copied..]);
+
+    Ok(s)
+}
+
+/**
+Write the values as literals in place of their placeholders.
+
+Postgres placeholders refer to the value at their index,
+`?` placeholders take the values in order.
+Returns an error, if the placeholders don't match the values
+or a value has no literal representation.
+*/
+pub(crate) fn inline_values(sql: &str, values: &[Value], dialect: DBImpl) -> Result<String, Error> {
+    let mut remaining = values.iter();
+    replace_placeholders(sql, Syntax::of(dialect), |index, s| {
+        let value = match index {
+            Some(index) => index.checked_sub(1).and_then(|index| values.get(index)),
+            None => remaining.next(),
+        };
+        let value = value
+            .ok_or_else(|| Error::SQLBuildError("Missing value for placeholder".to_string()))?;
+        s.push_str(&value.to_sql_literal(dialect)?);
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conditional::{BinaryCondition, Condition};
+    #[cfg(feature = "postgres")]
+    use crate::drop_table::DropTable;
+    use crate::query::Query;
+    use crate::select::Select;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn round_trip_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("foo")),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+        let select = || {
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+        };
+
+        let query = select().build_query().unwrap();
+        assert_eq!(query.sql(), "SELECT id FROM user WHERE (name = ?);");
+        assert_eq!(query.values(), [Value::String("foo")]);
+        assert_eq!(query.value_count(), 1);
+        assert_eq!(query.to_string(), "SELECT id FROM user WHERE (name = ?);");
+        assert_eq!(
+            query.clone().pretty(),
+            Query::new(
+                "SELECT id\nFROM user\nWHERE (name = ?);".to_string(),
+                vec![Value::String("foo")]
+            )
+        );
+        assert_eq!(query, Query::from(select().build().unwrap()));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn round_trip_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("foo")),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+        let query = DBImpl::MySQL
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build_query()
+            .unwrap();
+
+        assert_eq!(
+            query.into_parts(),
+            (
+                "SELECT `id` FROM user WHERE (name = ?);".to_string(),
+                vec![Value::String("foo")]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn round_trip_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("foo")),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        let query = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build_query()
+            .unwrap();
+
+        assert_eq!(
+            query.into_parts(),
+            (
+                "SELECT \"id\" FROM \"user\" WHERE (name = $1);".to_string(),
+                vec![Value::String("foo")]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn without_values_postgres() {
+        let sql = DBImpl::Postgres.drop_table("user").build().unwrap();
+        let query = Query::from(sql.clone());
+        assert_eq!(query.sql(), sql);
+        assert!(query.values().is_empty());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn deduplicate_values_postgres() {
+        let condition = Condition::Disjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("name")),
+                Condition::Value(Value::String("foo")),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("alias")),
+                Condition::Value(Value::String("it's $2")),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("nickname")),
+                Condition::Value(Value::String("foo")),
+            ]))),
+        ]);
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        let query = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build_query()
+            .unwrap();
+        assert_eq!(query.value_count(), 3);
+
+        let query = query.deduplicate_values();
+        assert_eq!(
+            query.sql(),
+            "SELECT \"id\" FROM \"user\" WHERE ((name = $1) OR (alias = $2) OR (nickname = $1));"
+        );
+        assert_eq!(
+            query.values(),
+            [Value::String("foo"), Value::String("it's $2")]
+        );
+
+        let literal = Query::new("SELECT '$1', $1;".to_string(), vec![Value::I64(1)]);
+        assert_eq!(literal.clone().deduplicate_values(), literal);
+
+        let quoted = Query::new(
+            "SELECT 'a\\', price$1, $$ $2 $$, E'\\'$2' FROM t WHERE x = $1 OR y = $2;".to_string(),
+            vec![Value::I64(1), Value::I64(1)],
+        );
+        assert_eq!(
+            quoted.deduplicate_values(),
+            Query::new(
+                "SELECT 'a\\', price$1, $$ $2 $$, E'\\'$2' FROM t WHERE x = $1 OR y = $1;"
+                    .to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn deduplicate_values_sqlite() {
+        let query = Query::new(
+            "SELECT id FROM user WHERE (name = ?) OR (nickname = ?);".to_string(),
+            vec![Value::String("foo"), Value::String("foo")],
+        );
+        assert_eq!(query.clone().deduplicate_values(), query);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn to_inlined_sql_postgres() {
+        let query = Query::new(
+            "SELECT '$1?', $2 FROM \"user\" WHERE name = $1 OR alias = $1;".to_string(),
+            vec![Value::String("it's"), Value::I64(3)],
+        );
+        assert_eq!(
+            query.to_inlined_sql(DBImpl::Postgres).unwrap(),
+            "SELECT '$1?', 3 FROM \"user\" WHERE name = 'it''s' OR alias = 'it''s';"
+        );
+
+        let missing = Query::new("SELECT $2;".to_string(), vec![Value::I64(1)]);
+        assert!(missing.to_inlined_sql(DBImpl::Postgres).is_err());
+
+        let quoted = Query::new(
+            "SELECT 'a\\', price$1, $1 FROM t;".to_string(),
+            vec![Value::I64(7)],
+        );
+        assert_eq!(
+            quoted.to_inlined_sql(DBImpl::Postgres).unwrap(),
+            "SELECT 'a\\', price$1, 7 FROM t;"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn to_inlined_sql_sqlite() {
+        let query = Query::new(
+            "SELECT '?' FROM user WHERE name = ? AND active = ?;".to_string(),
+            vec![Value::String("foo"), Value::Bool(true)],
+        );
+        assert_eq!(
+            query.to_inlined_sql(DBImpl::SQLite).unwrap(),
+            "SELECT '?' FROM user WHERE name = 'foo' AND active = 1;"
+        );
+
+        let missing = Query::new("SELECT ?, ?;".to_string(), vec![Value::I64(1)]);
+        assert!(missing.to_inlined_sql(DBImpl::SQLite).is_err());
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn to_inlined_sql_mysql() {
+        let query = Query::new(
+            "SELECT 'a\\'?' FROM user WHERE name = ?;".to_string(),
+            vec![Value::String("back\\slash")],
+        );
+        assert_eq!(
+            query.to_inlined_sql(DBImpl::MySQL).unwrap(),
+            "SELECT 'a\\'?' FROM user WHERE name = 'back\\\\slash';"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+/**
+The kinds of row locks that can be acquired by a SELECT query.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LockMode {
+    /// Representation of FOR UPDATE in SQL
+    Update,
+    /// Representation of FOR SHARE in SQL
+    Share,
+}
+
+/**
+The behaviour if a row to lock is already locked by another transaction.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LockWait {
+    /// Wait until the lock is released
+    Wait,
+    /// Representation of NOWAIT in SQL, an error is raised instead of waiting
+    NoWait,
+    /// Representation of SKIP LOCKED in SQL, locked rows are left out of the result
+    SkipLocked,
+}
+
+/**
+Representation of a row locking clause in SQL.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RowLock {
+    /// Kind of lock to acquire
+    pub mode: LockMode,
+    /// Behaviour if rows are already locked
+    pub wait: LockWait,
+}
+
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+impl RowLock {
+    pub(crate) fn build(&self, s: &mut String) {
+        s.push_str(match self.mode {
+            LockMode::Update => " FOR UPDATE",
+            LockMode::Share => " FOR SHARE",
+        });
+        s.push_str(match self.wait {
+            LockWait::Wait => "",
+            LockWait::NoWait => " NOWAIT",
+            LockWait::SkipLocked => " SKIP LOCKED",
+        });
+    }
+}
@@ -1,10 +1,15 @@
 use std::fmt::Write;
 
-use crate::conditional::{BuildCondition, Condition};
+use crate::conditional::{build_where, write_placeholder, BuildCondition, Condition, WhereGroup};
+use crate::error::Error;
+use crate::grouping::Grouping;
 use crate::join_table::{JoinTable, JoinTableImpl};
 use crate::limit_clause::LimitClause;
-use crate::ordering::{OrderByEntry, Ordering};
+use crate::ordering::{NullsOrder, OrderByEntry, Ordering};
+use crate::query::Query;
+use crate::row_lock::RowLock;
 use crate::select_column::{SelectColumn, SelectColumnImpl};
+use crate::table_ref::TableRef;
 use crate::{DBImpl, Value};
 
 /**
@@ -16,36 +21,385 @@ pub trait Select<'until_build, 'post_query> {
      */
     fn limit_clause(self, limit: LimitClause) -> Self;
 
+    /**
+    Skip the given number of rows without limiting the resulting rows.
+
+    Postgres is the only database allowing an OFFSET without a LIMIT,
+    so the LIMIT is set to its maximum on SQLite and MySQL.
+
+    **Parameter**:
+    - `offset`: Number of rows to skip.
+     */
+    fn offset(self, offset: u64) -> Self;
+
+    /**
+    Bind the limit and offset as parameters instead of writing them into the query.
+
+    The query then stays the same for different limits and offsets,
+    so drivers can reuse their prepared statements.
+    The parameters are placed after the ones of the rest of the query.
+     */
+    fn bind_limit(self) -> Self;
+
     /**
     Only retrieve distinct rows.
      */
     fn distinct(self) -> Self;
 
     /**
-    Set a where clause to the query.
+    Only retrieve the first row of each set of rows with equal values in the given columns.
+
+    Only supported by Postgres and mutually exclusive with [Select::distinct].
+    An empty list of columns doesn't emit a DISTINCT ON clause.
+
+    **Parameter**:
+    - `columns`: Names of the columns to compare.
+     */
+    fn distinct_on(self, columns: &'until_build [&'until_build str]) -> Self;
+
+    /**
+    Add an expression to the selected columns, e.g. [Condition::Coalesce], [Condition::Case]
+    or a bound literal like `Condition::Value(Value::I64(1))`.
+
+    Expressions are emitted after the columns passed to [DBImpl::select],
+    in the order they were added.
+
+    **Parameter**:
+    - `expression`: The expression to select.
+    - `alias`: Optional alias of the expression.
+     */
+    fn add_expression(
+        self,
+        expression: &'until_build Condition<'post_query>,
+        alias: Option<&'until_build str>,
+    ) -> Self;
+
+    /**
+    Add another table to the FROM clause, separated by a comma.
+
+    The result is the cartesian product of the tables.
+    Joins bind tighter than the comma, so join conditions can't reference
+    the tables added with this method. Use [Select::cross_join] in that case.
+
+    **Parameter**:
+    - `table`: Name of the table.
+     */
+    fn add_from_table(self, table: &'until_build str) -> Self;
+
+    /**
+    Add a CROSS JOIN with the given table, resulting in the cartesian product of the tables.
+
+    Cross joins are emitted in the order they were added,
+    before the join tables of the query.
+
+    **Parameter**:
+    - `table`: Name of the table.
+     */
+    fn cross_join(self, table: &'until_build str) -> Self;
+
+    /**
+    Add a [Condition] to the where clause of the query.
+
+    If the query already has a where clause, the condition is combined with it using AND.
+
+    **Parameter**:
+    - `where_clause`: Condition to add.
      */
     fn where_clause(self, where_clause: &'until_build Condition<'post_query>) -> Self;
 
+    /**
+    Add a [Condition] to the where clause of the query, combined with
+    the existing where clause using OR.
+
+    Everything added before is wrapped in parentheses, so
+    `a AND b OR c` is built as `((a AND b) OR c)`.
+
+    **Parameter**:
+    - `where_clause`: Condition to add.
+     */
+    fn or_where(self, where_clause: &'until_build Condition<'post_query>) -> Self;
+
+    /**
+    Replace the where clause of the query, discarding all conditions added before.
+
+    **Parameter**:
+    - `where_clause`: The new condition.
+     */
+    fn replace_where(self, where_clause: &'until_build Condition<'post_query>) -> Self;
+
+    /**
+    Add conditions to the where clause, which all have to be met.
+
+    The conditions are combined with the existing where clause using AND.
+    An empty slice doesn't change the query.
+
+    **Parameter**:
+    - `conditions`: The conditions to add.
+     */
+    fn where_all(self, conditions: &'until_build [Condition<'post_query>]) -> Self;
+
+    /**
+    Add conditions to the where clause, of which any has to be met.
+
+    See [Select::where_all] for further details.
+
+    **Parameter**:
+    - `conditions`: The conditions to add.
+     */
+    fn where_any(self, conditions: &'until_build [Condition<'post_query>]) -> Self;
+
+    /**
+    Group the resulting rows by the given columns.
+
+    An empty list of columns doesn't emit a GROUP BY clause.
+
+    **Parameter**:
+    - `columns`: Names of the columns to group by.
+     */
+    fn group_by(self, columns: &'until_build [&'until_build str]) -> Self;
+
+    /**
+    Group the resulting rows by the given [Grouping], e.g. a ROLLUP.
+
+    Replaces the columns set by [Select::group_by].
+    SQLite doesn't support groupings other than plain columns,
+    MySQL only supports ROLLUP. Both return [Error::UnsupportedFeature] on build.
+
+    **Parameter**:
+    - `grouping`: The grouping to apply.
+     */
+    fn grouping(self, grouping: Grouping<'until_build>) -> Self;
+
+    /**
+    Set a having clause to the query.
+
+    The clause is applied to the groups created by [Select::group_by].
+    Without groups, the whole result set is treated as one group.
+     */
+    fn having_clause(self, having_clause: &'until_build Condition<'post_query>) -> Self;
+
+    /**
+    Add multiple entries to the ORDER BY clause.
+
+    The entries are appended to the ones passed to [DBImpl::select].
+
+    **Parameter**:
+    - `entries`: The entries to order by, in descending priority.
+     */
+    fn order_by_many(self, entries: &[OrderByEntry<'until_build>]) -> Self;
+
+    /**
+    Continue a keyset pagination after the row with the given key values.
+
+    Adds a condition only matching rows after the given values to the where clause
+    and appends the keys to the ORDER BY clause.
+    If all keys share the same ordering, the condition is a row value comparison
+    like `((a, b) > (?, ?))`, otherwise it is expanded to the equivalent
+    `((a > ?) OR ((a = ?) AND (b < ?)))`.
+    An empty slice doesn't change the query.
+
+    **Parameter**:
+    - `keys`: Column, value of the last row and ordering of each key, in descending priority.
+     */
+    fn paginate_after(
+        self,
+        keys: &'until_build [(&'until_build str, Value<'post_query>, Ordering)],
+    ) -> Self;
+
+    /**
+    Add a common table expression to the query.
+
+    Multiple common table expressions are emitted in the order they were added.
+    Their bound parameters are placed before the ones of the main query.
+
+    **Parameter**:
+    - `name`: Name of the common table expression.
+    - `query`: Query defining the common table expression.
+     */
+    fn with(self, name: &'until_build str, query: SelectImpl<'until_build, 'post_query>) -> Self;
+
+    /**
+    Add a common table expression to the query and mark the WITH clause as recursive.
+
+    See [Select::with] for further details.
+     */
+    fn with_recursive(
+        self,
+        name: &'until_build str,
+        query: SelectImpl<'until_build, 'post_query>,
+    ) -> Self;
+
+    /**
+    Lock the selected rows.
+
+    The clause is emitted at the very end of the query.
+    SQLite doesn't support row locking, so the lock is omitted for SQLite.
+     */
+    fn lock(self, lock: RowLock) -> Self;
+
+    /**
+    Only select a random sample of the rows of the table, e.g. `TABLESAMPLE SYSTEM (10)`.
+
+    Only supported by Postgres when selecting from a table,
+    SQLite and MySQL return [Error::UnsupportedFeature] on build.
+
+    **Parameter**:
+    - `method`: The method to sample the rows with.
+    - `percent`: The percentage of rows to sample, between 0 and 100.
+    - `seed`: Optional seed to get the same sample on every execution, rendered as `REPEATABLE`.
+     */
+    fn tablesample(self, method: SampleMethod, percent: f64, seed: Option<i64>) -> Self;
+
+    /**
+    Combine the query with another query using UNION.
+
+    ORDER BY and LIMIT clauses of this query are applied to the combined result.
+    As SQLite doesn't allow parenthesized queries, `other` must not have
+    ORDER BY or LIMIT clauses or be combined itself when building for SQLite.
+    `other` must be built for the same dialect, otherwise building fails.
+     */
+    fn union(self, other: SelectImpl<'until_build, 'post_query>) -> Self;
+
+    /**
+    Combine the query with another query using UNION ALL.
+
+    See [Select::union] for further details.
+     */
+    fn union_all(self, other: SelectImpl<'until_build, 'post_query>) -> Self;
+
+    /**
+    Combine the query with another query using INTERSECT.
+
+    Not supported by MySQL.
+    See [Select::union] for further details.
+     */
+    fn intersect(self, other: SelectImpl<'until_build, 'post_query>) -> Self;
+
+    /**
+    Combine the query with another query using EXCEPT.
+
+    Not supported by MySQL.
+    See [Select::union] for further details.
+     */
+    fn except(self, other: SelectImpl<'until_build, 'post_query>) -> Self;
+
+    /**
+    Returns the number of values [Select::build] binds to the query.
+
+    Can be used to allocate the parameters before building the query.
+    If the query can't be built, 0 is returned.
+     */
+    fn value_count(&self) -> usize;
+
     /**
     Build the select query
      */
-    fn build(self) -> (String, Vec<Value<'post_query>>);
+    fn build(self) -> Result<(String, Vec<Value<'post_query>>), Error>;
+
+    /**
+    Same as [Select::build], but the query is formatted over multiple lines
+    with every clause on its own line.
+
+    Meant for logging, the bound values are the same as the ones of [Select::build].
+     */
+    fn build_pretty(self) -> Result<(String, Vec<Value<'post_query>>), Error>
+    where
+        Self: Sized,
+    {
+        self.build()
+            .map(|(query, lookup)| (crate::pretty::format(&query), lookup))
+    }
+
+    /**
+    Same as [Select::build], but the statement and its values are returned as [Query].
+     */
+    fn build_query(self) -> Result<Query<'post_query>, Error>
+    where
+        Self: Sized,
+    {
+        self.build().map(Query::from)
+    }
+}
+
+/**
+The set operations that can be used to combine select queries.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SetOperator {
+    /// Representation of UNION in SQL
+    Union,
+    /// Representation of UNION ALL in SQL
+    UnionAll,
+    /// Representation of INTERSECT in SQL
+    Intersect,
+    /// Representation of EXCEPT in SQL
+    Except,
+}
+
+/**
+The methods to sample the rows of a table with.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SampleMethod {
+    /// Representation of SYSTEM in SQL, samples whole pages of the table
+    System,
+    /// Representation of BERNOULLI in SQL, samples each row individually
+    Bernoulli,
+}
+
+impl SetOperator {
+    fn keyword(self) -> &'static str {
+        match self {
+            SetOperator::Union => "UNION",
+            SetOperator::UnionAll => "UNION ALL",
+            SetOperator::Intersect => "INTERSECT",
+            SetOperator::Except => "EXCEPT",
+        }
+    }
 }
 
 /**
 Representation of the data of a SELECT operation in SQL.
  */
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SelectData<'until_build, 'post_query> {
+    pub(crate) with: Vec<(&'until_build str, SelectImpl<'until_build, 'post_query>)>,
+    pub(crate) recursive: bool,
     pub(crate) resulting_columns: &'until_build [SelectColumnImpl<'until_build>],
+    pub(crate) expressions: Vec<(
+        &'until_build Condition<'post_query>,
+        Option<&'until_build str>,
+    )>,
     pub(crate) limit: Option<u64>,
     pub(crate) offset: Option<u64>,
-    pub(crate) from_clause: &'until_build str,
-    pub(crate) where_clause: Option<&'until_build Condition<'post_query>>,
+    pub(crate) bind_limit: bool,
+    pub(crate) from_clause: FromClause<'until_build, 'post_query>,
+    pub(crate) from_tables: Vec<&'until_build str>,
+    pub(crate) cross_joins: Vec<&'until_build str>,
+    pub(crate) where_groups: Vec<WhereGroup<'until_build, 'post_query>>,
+    pub(crate) group_by: Grouping<'until_build>,
+    pub(crate) having_clause: Option<&'until_build Condition<'post_query>>,
     pub(crate) distinct: bool,
-    pub(crate) lookup: Vec<Value<'post_query>>,
+    pub(crate) distinct_on: &'until_build [&'until_build str],
     pub(crate) join_tables: &'until_build [JoinTableImpl<'until_build, 'post_query>],
-    pub(crate) order_by_clause: &'until_build [OrderByEntry<'until_build>],
+    pub(crate) order_by_clause: Vec<OrderByEntry<'until_build>>,
+    pub(crate) compound: Vec<(SetOperator, SelectImpl<'until_build, 'post_query>)>,
+    pub(crate) lock: Option<RowLock>,
+    pub(crate) tablesample: Option<(SampleMethod, f64, Option<i64>)>,
+}
+
+/**
+Representation of the source of a SELECT operation.
+ */
+#[derive(Debug, PartialEq)]
+pub(crate) enum FromClause<'until_build, 'post_query> {
+    /// A table
+    Table(TableRef<'until_build>),
+    /// A subquery with its alias
+    Subquery(
+        Box<SelectImpl<'until_build, 'post_query>>,
+        &'until_build str,
+    ),
 }
 
 /**
@@ -53,7 +407,7 @@ Implementation of the [Select] trait for the different implementations.
 
 Should only be constructed via [DBImpl::select]
  */
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum SelectImpl<'until_build, 'post_query> {
     /**
     SQLite representation of the SELECT operation.
@@ -96,6 +450,30 @@ impl<'until_build, 'post_build> Select<'until_build, 'post_build>
         self
     }
 
+    fn offset(mut self, offset: u64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.offset = Some(offset),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.offset = Some(offset),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.offset = Some(offset),
+        };
+        self
+    }
+
+    fn bind_limit(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.bind_limit = true,
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.bind_limit = true,
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.bind_limit = true,
+        };
+        self
+    }
+
     fn distinct(mut self) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
@@ -108,104 +486,459 @@ impl<'until_build, 'post_build> Select<'until_build, 'post_build>
         self
     }
 
-    fn where_clause(mut self, where_clause: &'until_build Condition<'post_build>) -> Self {
+    fn distinct_on(mut self, columns: &'until_build [&'until_build str]) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
-            SelectImpl::SQLite(ref mut d) => d.where_clause = Some(where_clause),
+            SelectImpl::SQLite(ref mut d) => d.distinct_on = columns,
             #[cfg(feature = "mysql")]
-            SelectImpl::MySQL(ref mut d) => d.where_clause = Some(where_clause),
+            SelectImpl::MySQL(ref mut d) => d.distinct_on = columns,
             #[cfg(feature = "postgres")]
-            SelectImpl::Postgres(ref mut d) => d.where_clause = Some(where_clause),
+            SelectImpl::Postgres(ref mut d) => d.distinct_on = columns,
         };
         self
     }
 
-    fn build(self) -> (String, Vec<Value<'post_build>>) {
+    fn add_expression(
+        mut self,
+        expression: &'until_build Condition<'post_build>,
+        alias: Option<&'until_build str>,
+    ) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
-            SelectImpl::SQLite(mut d) => {
-                let mut s = format!("SELECT{} ", if d.distinct { " DISTINCT" } else { "" });
-
-                let column_len = d.resulting_columns.len();
-                for (idx, column) in d.resulting_columns.iter().enumerate() {
-                    column.build(&mut s);
-
-                    if idx != column_len - 1 {
-                        write!(s, ", ").unwrap();
-                    }
-                }
-
-                write!(s, " FROM {}", d.from_clause).unwrap();
-
-                for x in d.join_tables {
-                    write!(s, " ").unwrap();
-                    x.build(&mut s, &mut d.lookup);
-                }
-
-                if let Some(c) = d.where_clause {
-                    write!(s, " WHERE {}", c.build(DBImpl::SQLite, &mut d.lookup)).unwrap()
-                };
-
-                if !d.order_by_clause.is_empty() {
-                    write!(s, " ORDER BY ").unwrap();
+            SelectImpl::SQLite(ref mut d) => d.expressions.push((expression, alias)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.expressions.push((expression, alias)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.expressions.push((expression, alias)),
+        };
+        self
+    }
 
-                    let order_by_len = d.order_by_clause.len();
-                    for (idx, entry) in d.order_by_clause.iter().enumerate() {
-                        if let Some(table_name) = entry.table_name {
-                            write!(s, "{table_name}.").unwrap();
-                        };
-                        write!(
-                            s,
-                            "{}{}",
-                            entry.column_name,
-                            match entry.ordering {
-                                Ordering::Asc => "",
-                                Ordering::Desc => " DESC",
-                            }
-                        )
-                        .unwrap();
+    fn add_from_table(mut self, table: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.from_tables.push(table),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.from_tables.push(table),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.from_tables.push(table),
+        };
+        self
+    }
 
-                        if idx != order_by_len - 1 {
-                            write!(s, ", ").unwrap();
-                        }
-                    }
-                };
+    fn cross_join(mut self, table: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.cross_joins.push(table),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.cross_joins.push(table),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.cross_joins.push(table),
+        };
+        self
+    }
 
-                if let Some(limit) = d.limit {
-                    write!(s, " LIMIT {limit}").unwrap();
-                    if let Some(offset) = d.offset {
-                        write!(s, " OFFSET {offset}").unwrap();
-                    }
-                };
+    fn where_clause(mut self, where_clause: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::And(where_clause)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::And(where_clause)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::And(where_clause)),
+        };
+        self
+    }
 
-                write!(s, ";").unwrap();
+    fn or_where(mut self, where_clause: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::Or(where_clause)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::Or(where_clause)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::Or(where_clause)),
+        };
+        self
+    }
 
-                (s, d.lookup)
-            }
+    fn replace_where(mut self, where_clause: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.where_groups = vec![WhereGroup::And(where_clause)],
             #[cfg(feature = "mysql")]
-            SelectImpl::MySQL(mut d) => {
-                let mut s = format!("SELECT{} ", if d.distinct { " DISTINCT" } else { "" });
+            SelectImpl::MySQL(ref mut d) => d.where_groups = vec![WhereGroup::And(where_clause)],
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.where_groups = vec![WhereGroup::And(where_clause)],
+        };
+        self
+    }
 
-                let column_len = d.resulting_columns.len();
-                for (idx, column) in d.resulting_columns.iter().enumerate() {
-                    column.build(&mut s);
+    fn where_all(mut self, conditions: &'until_build [Condition<'post_build>]) -> Self {
+        if conditions.is_empty() {
+            return self;
+        }
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::All(conditions)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::All(conditions)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::All(conditions)),
+        };
+        self
+    }
 
-                    if idx != column_len - 1 {
-                        write!(s, ", ").unwrap();
-                    }
-                }
+    fn where_any(mut self, conditions: &'until_build [Condition<'post_build>]) -> Self {
+        if conditions.is_empty() {
+            return self;
+        }
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::Any(conditions)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::Any(conditions)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::Any(conditions)),
+        };
+        self
+    }
 
-                write!(s, " FROM {}", d.from_clause).unwrap();
+    fn group_by(mut self, columns: &'until_build [&'until_build str]) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.group_by = Grouping::Columns(columns),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.group_by = Grouping::Columns(columns),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.group_by = Grouping::Columns(columns),
+        };
+        self
+    }
+
+    fn grouping(mut self, grouping: Grouping<'until_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.group_by = grouping,
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.group_by = grouping,
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.group_by = grouping,
+        };
+        self
+    }
+
+    fn having_clause(mut self, having_clause: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.having_clause = Some(having_clause),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.having_clause = Some(having_clause),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.having_clause = Some(having_clause),
+        };
+        self
+    }
+
+    fn order_by_many(mut self, entries: &[OrderByEntry<'until_build>]) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.order_by_clause.extend_from_slice(entries),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.order_by_clause.extend_from_slice(entries),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.order_by_clause.extend_from_slice(entries),
+        };
+        self
+    }
+
+    fn paginate_after(
+        self,
+        keys: &'until_build [(&'until_build str, Value<'post_build>, Ordering)],
+    ) -> Self {
+        if keys.is_empty() {
+            return self;
+        }
+
+        let order_by: Vec<_> = keys
+            .iter()
+            .map(|(column_name, _, ordering)| OrderByEntry {
+                ordering: *ordering,
+                table_name: None,
+                column_name,
+                collation: None,
+                nulls: None,
+            })
+            .collect();
+        let mut query = self.order_by_many(&order_by);
+        match query {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::Keyset(keys)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::Keyset(keys)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::Keyset(keys)),
+        };
+        query
+    }
+
+    fn with(
+        mut self,
+        name: &'until_build str,
+        query: SelectImpl<'until_build, 'post_build>,
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.with.push((name, query)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.with.push((name, query)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.with.push((name, query)),
+        };
+        self
+    }
+
+    fn with_recursive(
+        mut self,
+        name: &'until_build str,
+        query: SelectImpl<'until_build, 'post_build>,
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.recursive = true,
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.recursive = true,
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.recursive = true,
+        };
+        self.with(name, query)
+    }
+
+    fn lock(mut self, lock: RowLock) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.lock = Some(lock),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.lock = Some(lock),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.lock = Some(lock),
+        };
+        self
+    }
+
+    fn tablesample(mut self, method: SampleMethod, percent: f64, seed: Option<i64>) -> Self {
+        let sample = Some((method, percent, seed));
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.tablesample = sample,
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.tablesample = sample,
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.tablesample = sample,
+        };
+        self
+    }
+
+    fn union(self, other: SelectImpl<'until_build, 'post_build>) -> Self {
+        self.combine(SetOperator::Union, other)
+    }
+
+    fn union_all(self, other: SelectImpl<'until_build, 'post_build>) -> Self {
+        self.combine(SetOperator::UnionAll, other)
+    }
+
+    fn intersect(self, other: SelectImpl<'until_build, 'post_build>) -> Self {
+        self.combine(SetOperator::Intersect, other)
+    }
+
+    fn except(self, other: SelectImpl<'until_build, 'post_build>) -> Self {
+        self.combine(SetOperator::Except, other)
+    }
+
+    fn value_count(&self) -> usize {
+        let mut lookup = vec![];
+        match self.build_to_writer(&mut String::new(), &mut lookup) {
+            Ok(()) => lookup.len(),
+            Err(_) => 0,
+        }
+    }
+
+    fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error> {
+        let mut s = String::new();
+        let mut lookup = vec![];
+
+        self.build_to_writer(&mut s, &mut lookup)?;
+        write!(s, ";").unwrap();
+
+        Ok((s, lookup))
+    }
+}
+
+impl<'until_build, 'post_build> SelectImpl<'until_build, 'post_build> {
+    fn combine(
+        mut self,
+        operator: SetOperator,
+        other: SelectImpl<'until_build, 'post_build>,
+    ) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(ref mut d) => d.compound.push((operator, other)),
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(ref mut d) => d.compound.push((operator, other)),
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(ref mut d) => d.compound.push((operator, other)),
+        };
+        self
+    }
+
+    /**
+    Checks that the query was built for the given dialect.
+
+    Queries embedded into other statements must use the placeholders of the statement.
+     */
+    pub(crate) fn check_dialect(&self, dialect: DBImpl) -> Result<(), Error> {
+        match (self, dialect) {
+            #[cfg(feature = "sqlite")]
+            (SelectImpl::SQLite(_), DBImpl::SQLite) => Ok(()),
+            #[cfg(feature = "mysql")]
+            (SelectImpl::MySQL(_), DBImpl::MySQL) => Ok(()),
+            #[cfg(feature = "postgres")]
+            (SelectImpl::Postgres(_), DBImpl::Postgres) => Ok(()),
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::SQLBuildError(
+                "The subquery was built for another dialect".to_string(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    fn data(&self) -> &SelectData<'until_build, 'post_build> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(d) => d,
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(d) => d,
+            #[cfg(feature = "postgres")]
+            SelectImpl::Postgres(d) => d,
+        }
+    }
+
+    /**
+    Checks whether the built SQLite query ends with its FROM clause or joins.
+
+    SQLite would parse an ON CONFLICT following such a query as the constraint of a join.
+     */
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn ends_with_sqlite_from_clause(&self) -> bool {
+        match self {
+            SelectImpl::SQLite(d) => {
+                if !d.order_by_clause.is_empty() || d.limit.is_some() || d.offset.is_some() {
+                    return false;
+                }
+                if let Some((_, last)) = d.compound.last() {
+                    return last.ends_with_sqlite_from_clause();
+                }
+                d.where_groups.is_empty()
+                    && matches!(d.group_by, Grouping::Columns(columns) if columns.is_empty())
+                    && d.having_clause.is_none()
+            }
+            #[cfg(any(feature = "mysql", feature = "postgres"))]
+            _ => false,
+        }
+    }
+
+    /**
+    Build the select query into the provided String without terminating it.
+
+    The bound parameters are appended to `lookup`, so the query can be embedded
+    into other statements while keeping their placeholders in order.
+     */
+    pub(crate) fn build_to_writer(
+        &self,
+        s: &mut String,
+        lookup: &mut Vec<Value<'post_build>>,
+    ) -> Result<(), Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectImpl::SQLite(d) => {
+                if !d.with.is_empty() {
+                    write!(s, "WITH{} ", if d.recursive { " RECURSIVE" } else { "" }).unwrap();
+                    for (idx, (name, query)) in d.with.iter().enumerate() {
+                        if idx > 0 {
+                            write!(s, ", ").unwrap();
+                        }
+                        write!(s, "{name} AS (").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ")").unwrap();
+                    }
+                    write!(s, " ").unwrap();
+                }
+
+                if !d.distinct_on.is_empty() {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support DISTINCT ON".to_string(),
+                    ));
+                }
+                if d.tablesample.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support TABLESAMPLE".to_string(),
+                    ));
+                }
+
+                write!(s, "SELECT{} ", if d.distinct { " DISTINCT" } else { "" }).unwrap();
+
+                write_columns(s, lookup, DBImpl::SQLite, d)?;
+
+                match &d.from_clause {
+                    FromClause::Table(table) => write!(s, " FROM {table}").unwrap(),
+                    FromClause::Subquery(query, alias) => {
+                        write!(s, " FROM (").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ") AS \"{alias}\"").unwrap();
+                    }
+                }
+                for table in &d.from_tables {
+                    write!(s, ", {table}").unwrap();
+                }
+                for table in &d.cross_joins {
+                    write!(s, " CROSS JOIN {table}").unwrap();
+                }
 
                 for x in d.join_tables {
                     write!(s, " ").unwrap();
-                    x.build(&mut s, &mut d.lookup);
+                    x.build(s, lookup)?;
                 }
 
-                if let Some(c) = d.where_clause {
-                    write!(s, " WHERE {}", c.build(DBImpl::MySQL, &mut d.lookup)).unwrap()
+                if let Some(condition) = build_where(&d.where_groups, DBImpl::SQLite, lookup)? {
+                    write!(s, " WHERE {condition}").unwrap()
                 };
 
+                d.group_by.build(s, DBImpl::SQLite)?;
+
+                if let Some(c) = d.having_clause {
+                    c.check_sqlite()?;
+                    write!(s, " HAVING {}", c.build(DBImpl::SQLite, lookup)?).unwrap()
+                };
+
+                for (operator, query) in &d.compound {
+                    query.check_dialect(DBImpl::SQLite)?;
+                    // SQLite doesn't allow parenthesized queries, so these clauses would apply to
+                    // the whole compound
+                    let other = query.data();
+                    if !other.order_by_clause.is_empty()
+                        || other.limit.is_some()
+                        || other.offset.is_some()
+                        || !other.compound.is_empty()
+                    {
+                        return Err(Error::SQLBuildError(
+                            "SQLite doesn't support ORDER BY, LIMIT or compound parts in the combined query"
+                                .to_string(),
+                        ));
+                    }
+                    write!(s, " {} ", operator.keyword()).unwrap();
+                    query.build_to_writer(s, lookup)?;
+                }
+
                 if !d.order_by_clause.is_empty() {
                     write!(s, " ORDER BY ").unwrap();
 
@@ -216,12 +949,17 @@ impl<'until_build, 'post_build> Select<'until_build, 'post_build>
                         };
                         write!(
                             s,
-                            "{}{}",
+                            "{}{}{}{}",
                             entry.column_name,
+                            entry
+                                .collation
+                                .map(|collation| format!(" COLLATE {collation}"))
+                                .unwrap_or_default(),
                             match entry.ordering {
                                 Ordering::Asc => "",
                                 Ordering::Desc => " DESC",
-                            }
+                            },
+                            nulls_order(entry.nulls)
                         )
                         .unwrap();
 
@@ -231,41 +969,236 @@ impl<'until_build, 'post_build> Select<'until_build, 'post_build>
                     }
                 };
 
-                if let Some(limit) = d.limit {
-                    write!(s, " LIMIT {limit}").unwrap();
-                    if let Some(offset) = d.offset {
-                        write!(s, " OFFSET {offset}").unwrap();
+                write_limit(s, lookup, DBImpl::SQLite, d)?;
+
+                Ok(())
+            }
+            #[cfg(feature = "mysql")]
+            SelectImpl::MySQL(d) => {
+                if !d.with.is_empty() {
+                    write!(s, "WITH{} ", if d.recursive { " RECURSIVE" } else { "" }).unwrap();
+                    for (idx, (name, query)) in d.with.iter().enumerate() {
+                        if idx > 0 {
+                            write!(s, ", ").unwrap();
+                        }
+                        write!(s, "{name} AS (").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ")").unwrap();
+                    }
+                    write!(s, " ").unwrap();
+                }
+
+                if !d.compound.is_empty() {
+                    write!(s, "(").unwrap();
+                }
+
+                if !d.distinct_on.is_empty() {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support DISTINCT ON".to_string(),
+                    ));
+                }
+                if d.tablesample.is_some() {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support TABLESAMPLE".to_string(),
+                    ));
+                }
+
+                write!(s, "SELECT{} ", if d.distinct { " DISTINCT" } else { "" }).unwrap();
+
+                write_columns(s, lookup, DBImpl::MySQL, d)?;
+
+                match &d.from_clause {
+                    FromClause::Table(table) => write!(s, " FROM {table}").unwrap(),
+                    FromClause::Subquery(query, alias) => {
+                        write!(s, " FROM (").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ") AS `{alias}`").unwrap();
+                    }
+                }
+                for table in &d.from_tables {
+                    write!(s, ", {table}").unwrap();
+                }
+                for table in &d.cross_joins {
+                    write!(s, " CROSS JOIN {table}").unwrap();
+                }
+
+                for x in d.join_tables {
+                    write!(s, " ").unwrap();
+                    x.build(s, lookup)?;
+                }
+
+                if let Some(condition) = build_where(&d.where_groups, DBImpl::MySQL, lookup)? {
+                    write!(s, " WHERE {condition}").unwrap()
+                };
+
+                d.group_by.build(s, DBImpl::MySQL)?;
+
+                if let Some(c) = d.having_clause {
+                    write!(s, " HAVING {}", c.build(DBImpl::MySQL, lookup)?).unwrap()
+                };
+
+                if !d.compound.is_empty() {
+                    write!(s, ")").unwrap();
+                }
+                for (operator, query) in &d.compound {
+                    if let SetOperator::Intersect | SetOperator::Except = operator {
+                        return Err(Error::UnsupportedFeature(format!(
+                            "MySQL doesn't support {}",
+                            operator.keyword()
+                        )));
+                    }
+                    query.check_dialect(DBImpl::MySQL)?;
+                    write!(s, " {} (", operator.keyword()).unwrap();
+                    query.build_to_writer(s, lookup)?;
+                    write!(s, ")").unwrap();
+                }
+
+                if !d.order_by_clause.is_empty() {
+                    write!(s, " ORDER BY ").unwrap();
+
+                    let order_by_len = d.order_by_clause.len();
+                    for (idx, entry) in d.order_by_clause.iter().enumerate() {
+                        if let Some(nulls) = entry.nulls {
+                            write!(s, "CASE WHEN ").unwrap();
+                            if let Some(table_name) = entry.table_name {
+                                write!(s, "{table_name}.").unwrap();
+                            };
+                            write!(
+                                s,
+                                "{} IS NULL THEN {} END, ",
+                                entry.column_name,
+                                match nulls {
+                                    NullsOrder::First => "0 ELSE 1",
+                                    NullsOrder::Last => "1 ELSE 0",
+                                }
+                            )
+                            .unwrap();
+                        }
+                        if let Some(table_name) = entry.table_name {
+                            write!(s, "{table_name}.").unwrap();
+                        };
+                        write!(
+                            s,
+                            "{}{}{}",
+                            entry.column_name,
+                            entry
+                                .collation
+                                .map(|collation| format!(" COLLATE {collation}"))
+                                .unwrap_or_default(),
+                            match entry.ordering {
+                                Ordering::Asc => "",
+                                Ordering::Desc => " DESC",
+                            }
+                        )
+                        .unwrap();
+
+                        if idx != order_by_len - 1 {
+                            write!(s, ", ").unwrap();
+                        }
                     }
                 };
 
-                write!(s, ";").unwrap();
+                write_limit(s, lookup, DBImpl::MySQL, d)?;
+
+                if let Some(lock) = d.lock {
+                    lock.build(s);
+                }
 
-                (s, d.lookup)
+                Ok(())
             }
             #[cfg(feature = "postgres")]
-            SelectImpl::Postgres(mut d) => {
-                let mut s = format!("SELECT{} ", if d.distinct { " DISTINCT" } else { "" });
+            SelectImpl::Postgres(d) => {
+                if !d.with.is_empty() {
+                    write!(s, "WITH{} ", if d.recursive { " RECURSIVE" } else { "" }).unwrap();
+                    for (idx, (name, query)) in d.with.iter().enumerate() {
+                        if idx > 0 {
+                            write!(s, ", ").unwrap();
+                        }
+                        write!(s, "\"{name}\" AS (").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ")").unwrap();
+                    }
+                    write!(s, " ").unwrap();
+                }
+
+                if !d.compound.is_empty() {
+                    write!(s, "(").unwrap();
+                }
 
-                let column_len = d.resulting_columns.len();
-                for (idx, column) in d.resulting_columns.iter().enumerate() {
-                    column.build(&mut s);
+                if d.distinct && !d.distinct_on.is_empty() {
+                    return Err(Error::SQLBuildError(
+                        "DISTINCT and DISTINCT ON can't be used together".to_string(),
+                    ));
+                }
 
-                    if idx != column_len - 1 {
-                        write!(s, ", ").unwrap();
+                write!(s, "SELECT").unwrap();
+                if d.distinct {
+                    write!(s, " DISTINCT").unwrap();
+                }
+                if !d.distinct_on.is_empty() {
+                    write!(s, " DISTINCT ON (").unwrap();
+                    for (idx, column) in d.distinct_on.iter().enumerate() {
+                        if idx > 0 {
+                            write!(s, ", ").unwrap();
+                        }
+                        write!(s, "\"{column}\"").unwrap();
                     }
+                    write!(s, ")").unwrap();
                 }
+                write!(s, " ").unwrap();
 
-                write!(s, " FROM \"{}\"", d.from_clause).unwrap();
+                write_columns(s, lookup, DBImpl::Postgres, d)?;
+
+                match &d.from_clause {
+                    FromClause::Table(table) => {
+                        write!(s, " FROM {}", table.quoted(DBImpl::Postgres)).unwrap();
+                        if let Some((method, percent, seed)) = d.tablesample {
+                            write_tablesample(s, method, percent, seed)?;
+                        }
+                    }
+                    FromClause::Subquery(_, _) if d.tablesample.is_some() => {
+                        return Err(Error::SQLBuildError(
+                            "TABLESAMPLE can only be applied to tables".to_string(),
+                        ));
+                    }
+                    FromClause::Subquery(query, alias) => {
+                        write!(s, " FROM (").unwrap();
+                        query.build_to_writer(s, lookup)?;
+                        write!(s, ") AS \"{alias}\"").unwrap();
+                    }
+                }
+                for table in &d.from_tables {
+                    write!(s, ", \"{table}\"").unwrap();
+                }
+                for table in &d.cross_joins {
+                    write!(s, " CROSS JOIN \"{table}\"").unwrap();
+                }
 
                 for x in d.join_tables {
                     write!(s, " ").unwrap();
-                    x.build(&mut s, &mut d.lookup);
+                    x.build(s, lookup)?;
                 }
 
-                if let Some(c) = d.where_clause {
-                    write!(s, " WHERE {}", c.build(DBImpl::Postgres, &mut d.lookup)).unwrap()
+                if let Some(condition) = build_where(&d.where_groups, DBImpl::Postgres, lookup)? {
+                    write!(s, " WHERE {condition}").unwrap()
                 };
 
+                d.group_by.build(s, DBImpl::Postgres)?;
+
+                if let Some(c) = d.having_clause {
+                    write!(s, " HAVING {}", c.build(DBImpl::Postgres, lookup)?).unwrap()
+                };
+
+                if !d.compound.is_empty() {
+                    write!(s, ")").unwrap();
+                }
+                for (operator, query) in &d.compound {
+                    query.check_dialect(DBImpl::Postgres)?;
+                    write!(s, " {} (", operator.keyword()).unwrap();
+                    query.build_to_writer(s, lookup)?;
+                    write!(s, ")").unwrap();
+                }
+
                 if !d.order_by_clause.is_empty() {
                     write!(s, " ORDER BY ").unwrap();
 
@@ -276,12 +1209,20 @@ impl<'until_build, 'post_build> Select<'until_build, 'post_build>
                         };
                         write!(
                             s,
-                            "\"{}\"{}",
+                            "\"{}\"{}{}{}",
                             entry.column_name,
+                            entry
+                                .collation
+                                .map(|collation| format!(
+                                    " COLLATE {}",
+                                    DBImpl::Postgres.quote_identifier(collation)
+                                ))
+                                .unwrap_or_default(),
                             match entry.ordering {
                                 Ordering::Asc => "",
                                 Ordering::Desc => " DESC",
-                            }
+                            },
+                            nulls_order(entry.nulls)
                         )
                         .unwrap();
 
@@ -291,17 +1232,3335 @@ impl<'until_build, 'post_build> Select<'until_build, 'post_build>
                     }
                 };
 
-                if let Some(limit) = d.limit {
-                    write!(s, " LIMIT {limit}").unwrap();
-                    if let Some(offset) = d.offset {
-                        write!(s, " OFFSET {offset}").unwrap();
-                    }
-                };
+                write_limit(s, lookup, DBImpl::Postgres, d)?;
 
-                write!(s, ";").unwrap();
+                if let Some(lock) = d.lock {
+                    lock.build(s);
+                }
 
-                (s, d.lookup)
+                Ok(())
             }
         }
     }
 }
+
+/// Returns the NULLS FIRST or NULLS LAST suffix of an ORDER BY entry
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn nulls_order(nulls: Option<NullsOrder>) -> &'static str {
+    match nulls {
+        None => "",
+        Some(NullsOrder::First) => " NULLS FIRST",
+        Some(NullsOrder::Last) => " NULLS LAST",
+    }
+}
+
+/**
+Write the selected columns and expressions of a query.
+
+Returns an error, if neither columns nor expressions are selected.
+ */
+fn write_columns<'post_build>(
+    s: &mut String,
+    lookup: &mut Vec<Value<'post_build>>,
+    dialect: DBImpl,
+    d: &SelectData<'_, 'post_build>,
+) -> Result<(), Error> {
+    if d.resulting_columns.is_empty() && d.expressions.is_empty() {
+        return Err(Error::EmptyColumnList {
+            statement: "SELECT",
+        });
+    }
+
+    for (idx, column) in d.resulting_columns.iter().enumerate() {
+        if idx > 0 {
+            write!(s, ", ").unwrap();
+        }
+        column.build(s)?;
+    }
+
+    for (idx, (expression, alias)) in d.expressions.iter().enumerate() {
+        if idx > 0 || !d.resulting_columns.is_empty() {
+            write!(s, ", ").unwrap();
+        }
+        #[cfg(feature = "sqlite")]
+        if matches!(dialect, DBImpl::SQLite) {
+            expression.check_sqlite()?;
+        }
+        write!(s, "{}", expression.build(dialect, lookup)?).unwrap();
+        if let Some(alias) = alias {
+            write!(s, " AS {alias}").unwrap();
+        }
+    }
+    Ok(())
+}
+
+/**
+Write the TABLESAMPLE clause of a query.
+
+Returns an error, if the percentage isn't between 0 and 100.
+ */
+#[cfg(feature = "postgres")]
+fn write_tablesample(
+    s: &mut String,
+    method: SampleMethod,
+    percent: f64,
+    seed: Option<i64>,
+) -> Result<(), Error> {
+    if !(0.0..=100.0).contains(&percent) {
+        return Err(Error::SQLBuildError(format!(
+            "TABLESAMPLE requires a percentage between 0 and 100, got {percent}"
+        )));
+    }
+    let method = match method {
+        SampleMethod::System => "SYSTEM",
+        SampleMethod::Bernoulli => "BERNOULLI",
+    };
+    write!(s, " TABLESAMPLE {method} ({percent})").unwrap();
+    if let Some(seed) = seed {
+        write!(s, " REPEATABLE ({seed})").unwrap();
+    }
+    Ok(())
+}
+
+/**
+Write the limit clause of a query, either inline or as bound parameters.
+ */
+fn write_limit<'post_build>(
+    s: &mut String,
+    lookup: &mut Vec<Value<'post_build>>,
+    dialect: DBImpl,
+    d: &SelectData<'_, 'post_build>,
+) -> Result<(), Error> {
+    if d.limit.is_none() && d.offset.is_some() {
+        match dialect {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => write!(s, " LIMIT -1").unwrap(),
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => write!(s, " LIMIT 18446744073709551615").unwrap(),
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => {}
+        }
+    }
+    for (keyword, value) in [("LIMIT", d.limit), ("OFFSET", d.offset)] {
+        if let Some(value) = value {
+            if d.bind_limit {
+                let value = i64::try_from(value).map_err(|_| {
+                    Error::SQLBuildError(format!("{keyword} {value} is too large to be bound"))
+                })?;
+                lookup.push(Value::I64(value));
+                write!(s, " {keyword} ").unwrap();
+                write_placeholder(s, dialect, lookup.len()).unwrap();
+            } else {
+                write!(s, " {keyword} {value}").unwrap();
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use rorm_declaration::imr::DbType;
+
+    use crate::aggregation::SelectAggregator;
+    use crate::conditional::{
+        AggregateExpr, BinaryCondition, CaseExpr, Condition, MathFunction, Quantifier,
+        StringFunction, UnaryCondition,
+    };
+    use crate::error::Error;
+    use crate::grouping::Grouping;
+    use crate::join_table::{JoinOn, JoinTable, JoinType};
+    use crate::limit_clause::LimitClause;
+    use crate::ordering::{NullsOrder, OrderByEntry, Ordering};
+    use crate::row_lock::{LockMode, LockWait, RowLock};
+    use crate::select::{SampleMethod, Select};
+    use crate::select_column::SelectColumn;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    fn column_equals<'a>(lhs: (&'a str, &'a str), rhs: (&'a str, &'a str)) -> Condition<'a> {
+        Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some(lhs.0),
+                column_name: lhs.1,
+            }),
+            Condition::Value(Value::Column {
+                table_name: Some(rhs.0),
+                column_name: rhs.1,
+            }),
+        ])))
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn join_sqlite() {
+        let post_condition = column_equals(("user", "id"), ("p", "user_id"));
+        let comment_condition = column_equals(("p", "id"), ("c", "post_id"));
+        let joins = [
+            DBImpl::SQLite.join_table(JoinType::Join, "post", "p", &post_condition),
+            DBImpl::SQLite.join_table(JoinType::LeftJoin, "comment", "c", &comment_condition),
+        ];
+        let columns = [DBImpl::SQLite.select_column(Some("user"), "name", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT user.name FROM user JOIN post AS p ON (user.id = p.user_id) LEFT JOIN comment AS c ON (p.id = c.post_id);"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn join_mysql() {
+        let post_condition = column_equals(("user", "id"), ("p", "user_id"));
+        let comment_condition = column_equals(("p", "id"), ("c", "post_id"));
+        let joins = [
+            DBImpl::MySQL.join_table(JoinType::Join, "post", "p", &post_condition),
+            DBImpl::MySQL.join_table(JoinType::LeftJoin, "comment", "c", &comment_condition),
+        ];
+        let columns = [DBImpl::MySQL.select_column(Some("user"), "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `user`.`name` FROM user JOIN post AS p ON (user.id = p.user_id) LEFT JOIN comment AS c ON (p.id = c.post_id);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn join_postgres() {
+        let post_condition = column_equals(("user", "id"), ("p", "user_id"));
+        let comment_condition = column_equals(("p", "id"), ("c", "post_id"));
+        let joins = [
+            DBImpl::Postgres.join_table(JoinType::Join, "post", "p", &post_condition),
+            DBImpl::Postgres.join_table(JoinType::LeftJoin, "comment", "c", &comment_condition),
+        ];
+        let columns = [DBImpl::Postgres.select_column(Some("user"), "name", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"user\".\"name\" FROM \"user\" JOIN \"post\" AS p ON (\"user\".id = \"p\".user_id) LEFT JOIN \"comment\" AS c ON (\"p\".id = \"c\".post_id);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn join_bound_values_postgres() {
+        let condition = Condition::Conjunction(vec![
+            column_equals(("user", "id"), ("p", "user_id")),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("p"),
+                    column_name: "title",
+                }),
+                Condition::Value(Value::String("foo")),
+            ]))),
+        ]);
+        let where_clause = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("user"),
+                column_name: "id",
+            }),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let joins = [DBImpl::Postgres.join_table(JoinType::RightJoin, "post", "p", &condition)];
+        let columns = [DBImpl::Postgres.select_column(Some("p"), "title", None, None)];
+
+        let (sql, values) = DBImpl::Postgres
+            .select(&columns, "user", &joins, &[])
+            .where_clause(&where_clause)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT \"p\".\"title\" FROM \"user\" RIGHT JOIN \"post\" AS p ON ((\"user\".id = \"p\".user_id) AND (\"p\".title = $1)) WHERE (\"user\".id = $2);"
+        );
+        assert_eq!(values, vec![Value::String("foo"), Value::I64(1)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn exists_sqlite() {
+        let correlation = Condition::Conjunction(vec![
+            column_equals(("post", "user_id"), ("user", "id")),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("post"),
+                    column_name: "published",
+                }),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+        ]);
+        let subquery_columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+        let subquery = DBImpl::SQLite
+            .select(&subquery_columns, "post", &[], &[])
+            .where_clause(&correlation);
+        let where_clause = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("active")),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+            Condition::UnaryCondition(UnaryCondition::Exists(Box::new(Condition::Subquery(
+                Box::new(subquery),
+            )))),
+        ]);
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        let (sql, values) = DBImpl::SQLite
+            .select(&columns, "user", &[], &[])
+            .where_clause(&where_clause)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT name FROM user WHERE ((active = ?) AND (EXISTS (SELECT id FROM post WHERE ((post.user_id = user.id) AND (post.published = ?)))));"
+        );
+        assert_eq!(values, vec![Value::Bool(true), Value::Bool(true)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn not_exists_mysql() {
+        let correlation = column_equals(("post", "user_id"), ("user", "id"));
+        let subquery_columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+        let subquery = DBImpl::MySQL
+            .select(&subquery_columns, "post", &[], &[])
+            .where_clause(&correlation);
+        let where_clause = Condition::UnaryCondition(UnaryCondition::NotExists(Box::new(
+            Condition::Subquery(Box::new(subquery)),
+        )));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_clause(&where_clause)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `name` FROM user WHERE (NOT EXISTS (SELECT `id` FROM post WHERE (post.user_id = user.id)));"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn exists_postgres() {
+        let correlation = Condition::Conjunction(vec![
+            column_equals(("post", "user_id"), ("user", "id")),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("post"),
+                    column_name: "published",
+                }),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+        ]);
+        let subquery_columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        let subquery = DBImpl::Postgres
+            .select(&subquery_columns, "post", &[], &[])
+            .where_clause(&correlation);
+        let where_clause = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("active")),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+            Condition::UnaryCondition(UnaryCondition::Exists(Box::new(Condition::Subquery(
+                Box::new(subquery),
+            )))),
+        ]);
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        let (sql, values) = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .where_clause(&where_clause)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT \"name\" FROM \"user\" WHERE ((active = $1) AND (EXISTS (SELECT \"id\" FROM \"post\" WHERE ((\"post\".user_id = \"user\".id) AND (\"post\".published = $2)))));"
+        );
+        assert_eq!(values, vec![Value::Bool(true), Value::Bool(true)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn group_by_having_sqlite() {
+        let where_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let having_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("COUNT(id)")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "city", None, None)];
+
+        let (sql, values) = DBImpl::SQLite
+            .select(&columns, "user", &[], &[])
+            .where_clause(&where_clause)
+            .group_by(&["city", "country"])
+            .having_clause(&having_clause)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "SELECT city FROM user WHERE (age > ?) GROUP BY city, country HAVING (COUNT(id) > ?);"
+        );
+        assert_eq!(values, vec![Value::I64(18), Value::I64(5)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn group_by_having_mysql() {
+        let having_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("COUNT(id)")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "city", None, None)];
+
+        let (sql, values) = DBImpl::MySQL
+            .select(&columns, "user", &[], &[])
+            .group_by(&["city"])
+            .having_clause(&having_clause)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "SELECT `city` FROM user GROUP BY city HAVING (COUNT(id) > ?);"
+        );
+        assert_eq!(values, vec![Value::I64(5)]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn group_by_having_postgres() {
+        let where_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let having_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("COUNT(id)")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "city", None, None)];
+
+        let (sql, values) = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .where_clause(&where_clause)
+            .group_by(&["city", "country"])
+            .having_clause(&having_clause)
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "SELECT \"city\" FROM \"user\" WHERE (age > $1) GROUP BY \"city\", \"country\" HAVING (COUNT(id) > $2);"
+        );
+        assert_eq!(values, vec![Value::I64(18), Value::I64(5)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn grouping_sqlite() {
+        let columns = [
+            DBImpl::SQLite.select_column(None, "city", None, None),
+            DBImpl::SQLite.select_column(None, "*", Some("cnt"), Some(SelectAggregator::Count)),
+        ];
+        let select = || DBImpl::SQLite.select(&columns, "user", &[], &[]);
+
+        assert_eq!(
+            select()
+                .grouping(Grouping::Columns(&["city"]))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT city, COUNT(*) AS cnt FROM user GROUP BY city;"
+        );
+        assert!(matches!(
+            select().grouping(Grouping::Rollup(&["city"])).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn grouping_mysql() {
+        let columns = [
+            DBImpl::MySQL.select_column(None, "city", None, None),
+            DBImpl::MySQL.select_column(None, "country", None, None),
+            DBImpl::MySQL.select_column(None, "*", Some("cnt"), Some(SelectAggregator::Count)),
+        ];
+        let select = || DBImpl::MySQL.select(&columns, "user", &[], &[]);
+
+        assert_eq!(
+            select()
+                .grouping(Grouping::Rollup(&["city", "country"]))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `city`, `country`, COUNT(*) AS cnt FROM user GROUP BY city, country WITH ROLLUP;"
+        );
+        assert!(matches!(
+            select().grouping(Grouping::Cube(&["city"])).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn grouping_postgres() {
+        let columns = [
+            DBImpl::Postgres.select_column(None, "city", None, None),
+            DBImpl::Postgres.select_column(None, "country", None, None),
+            DBImpl::Postgres.select_column(None, "*", Some("cnt"), Some(SelectAggregator::Count)),
+        ];
+        let select = || DBImpl::Postgres.select(&columns, "user", &[], &[]);
+
+        assert_eq!(
+            select()
+                .grouping(Grouping::Rollup(&["city", "country"]))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"city\", \"country\", COUNT(*) AS cnt FROM \"user\" GROUP BY ROLLUP (\"city\", \"country\");"
+        );
+        assert_eq!(
+            select()
+                .grouping(Grouping::Cube(&["city", "country"]))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"city\", \"country\", COUNT(*) AS cnt FROM \"user\" GROUP BY CUBE (\"city\", \"country\");"
+        );
+        assert_eq!(
+            select()
+                .grouping(Grouping::GroupingSets(&[&["city"], &["country"], &[]]))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"city\", \"country\", COUNT(*) AS cnt FROM \"user\" GROUP BY GROUPING SETS ((\"city\"), (\"country\"), ());"
+        );
+        assert!(matches!(
+            select().grouping(Grouping::Rollup(&[])).build(),
+            Err(Error::EmptyColumnList { .. })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn having_without_group_by_postgres() {
+        let having_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("COUNT(id)")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "city", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .group_by(&[])
+                .having_clause(&having_clause)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"city\" FROM \"user\" HAVING (COUNT(id) > $1);"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn aggregation_sqlite() {
+        let columns = [
+            DBImpl::SQLite.select_column(None, "city", None, None),
+            DBImpl::SQLite.select_column(None, "*", Some("cnt"), Some(SelectAggregator::Count)),
+            DBImpl::SQLite.select_column(
+                Some("user"),
+                "age",
+                Some("avg_age"),
+                Some(SelectAggregator::Avg),
+            ),
+            DBImpl::SQLite.select_column(None, "age", None, Some(SelectAggregator::Max)),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .group_by(&["city"])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT city, COUNT(*) AS cnt, AVG(user.age) AS avg_age, MAX(age) FROM user GROUP BY city;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn aggregation_mysql() {
+        let columns = [
+            DBImpl::MySQL.select_column(None, "city", None, None),
+            DBImpl::MySQL.select_column(None, "*", Some("cnt"), Some(SelectAggregator::Count)),
+            DBImpl::MySQL.select_column(
+                Some("user"),
+                "age",
+                Some("avg_age"),
+                Some(SelectAggregator::Avg),
+            ),
+            DBImpl::MySQL.select_column(None, "age", None, Some(SelectAggregator::Max)),
+        ];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .group_by(&["city"])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `city`, COUNT(*) AS cnt, AVG(`user`.`age`) AS avg_age, MAX(`age`) FROM user GROUP BY city;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn aggregation_postgres() {
+        let columns = [
+            DBImpl::Postgres.select_column(None, "city", None, None),
+            DBImpl::Postgres.select_column(None, "*", Some("cnt"), Some(SelectAggregator::Count)),
+            DBImpl::Postgres.select_column(
+                Some("user"),
+                "age",
+                Some("avg_age"),
+                Some(SelectAggregator::Avg),
+            ),
+            DBImpl::Postgres.select_column(None, "age", None, Some(SelectAggregator::Max)),
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .group_by(&["city"])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"city\", COUNT(*) AS cnt, AVG(\"user\".\"age\") AS avg_age, MAX(\"age\") FROM \"user\" GROUP BY \"city\";"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn with_sqlite() {
+        let adult = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let where_clause = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("admin")),
+        ])));
+        let adult_columns = [
+            DBImpl::SQLite.select_column(None, "id", None, None),
+            DBImpl::SQLite.select_column(None, "name", None, None),
+        ];
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let adults = || {
+            DBImpl::SQLite
+                .select(&adult_columns, "user", &[], &[])
+                .where_clause(&adult)
+        };
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "adults", &[], &[])
+                .with("adults", adults())
+                .where_clause(&where_clause)
+                .build()
+                .unwrap(),
+            (
+                "WITH adults AS (SELECT id, name FROM user WHERE (age >= ?)) SELECT name FROM adults WHERE (name <> ?);".to_string(),
+                vec![Value::I64(18), Value::String("admin")]
+            )
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "adults", &[], &[])
+                .with_recursive("adults", adults())
+                .build()
+                .unwrap()
+                .0,
+            "WITH RECURSIVE adults AS (SELECT id, name FROM user WHERE (age >= ?)) SELECT name FROM adults;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn with_mysql() {
+        let adult = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let published = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("published")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let adult_columns = [
+            DBImpl::MySQL.select_column(None, "id", None, None),
+            DBImpl::MySQL.select_column(None, "name", None, None),
+        ];
+        let author_columns = [DBImpl::MySQL.select_column(None, "user_id", None, None)];
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let adults = || {
+            DBImpl::MySQL
+                .select(&adult_columns, "user", &[], &[])
+                .where_clause(&adult)
+        };
+        let authors = DBImpl::MySQL
+            .select(&author_columns, "post", &[], &[])
+            .where_clause(&published);
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "adults", &[], &[])
+                .with("adults", adults())
+                .with("authors", authors)
+                .build()
+                .unwrap(),
+            (
+                "WITH adults AS (SELECT `id`, `name` FROM user WHERE (age >= ?)), authors AS (SELECT `user_id` FROM post WHERE (published = ?)) SELECT `name` FROM adults;".to_string(),
+                vec![Value::I64(18), Value::Bool(true)]
+            )
+        );
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "adults", &[], &[])
+                .with_recursive("adults", adults())
+                .build()
+                .unwrap()
+                .0,
+            "WITH RECURSIVE adults AS (SELECT `id`, `name` FROM user WHERE (age >= ?)) SELECT `name` FROM adults;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn with_postgres() {
+        let adult = Condition::BinaryCondition(BinaryCondition::GreaterOrEquals(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let published = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("published")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let where_clause = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("admin")),
+        ])));
+        let adult_columns = [
+            DBImpl::Postgres.select_column(None, "id", None, None),
+            DBImpl::Postgres.select_column(None, "name", None, None),
+        ];
+        let author_columns = [DBImpl::Postgres.select_column(None, "user_id", None, None)];
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let adults = || {
+            DBImpl::Postgres
+                .select(&adult_columns, "user", &[], &[])
+                .where_clause(&adult)
+        };
+        let authors = DBImpl::Postgres
+            .select(&author_columns, "post", &[], &[])
+            .where_clause(&published);
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "adults", &[], &[])
+                .with("adults", adults())
+                .with("authors", authors)
+                .where_clause(&where_clause)
+                .build()
+                .unwrap(),
+            (
+                "WITH \"adults\" AS (SELECT \"id\", \"name\" FROM \"user\" WHERE (age >= $1)), \"authors\" AS (SELECT \"user_id\" FROM \"post\" WHERE (published = $2)) SELECT \"name\" FROM \"adults\" WHERE (name <> $3);".to_string(),
+                vec![Value::I64(18), Value::Bool(true), Value::String("admin")]
+            )
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "adults", &[], &[])
+                .with_recursive("adults", adults())
+                .build()
+                .unwrap()
+                .0,
+            "WITH RECURSIVE \"adults\" AS (SELECT \"id\", \"name\" FROM \"user\" WHERE (age >= $1)) SELECT \"name\" FROM \"adults\";"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn compound_sqlite() {
+        let adult = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let active = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Asc,
+            table_name: None,
+            column_name: "name",
+            collation: None,
+            nulls: None,
+        }];
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let select = || {
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &order_by)
+                .where_clause(&adult)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: None,
+                })
+        };
+        let other = |table| {
+            DBImpl::SQLite
+                .select(&columns, table, &[], &[])
+                .where_clause(&active)
+        };
+
+        assert_eq!(
+            select().union(other("moderator")).build().unwrap(),
+            (
+                "SELECT name FROM user WHERE (age > ?) UNION SELECT name FROM moderator WHERE (active = ?) ORDER BY name LIMIT 10;".to_string(),
+                vec![Value::I64(18), Value::Bool(true)]
+            )
+        );
+        assert_eq!(
+            select().union_all(other("moderator")).build().unwrap().0,
+            "SELECT name FROM user WHERE (age > ?) UNION ALL SELECT name FROM moderator WHERE (active = ?) ORDER BY name LIMIT 10;"
+        );
+        assert_eq!(
+            select().intersect(other("moderator")).build().unwrap().0,
+            "SELECT name FROM user WHERE (age > ?) INTERSECT SELECT name FROM moderator WHERE (active = ?) ORDER BY name LIMIT 10;"
+        );
+        assert_eq!(
+            select()
+                .except(other("moderator"))
+                .union(other("admin"))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT name FROM user WHERE (age > ?) EXCEPT SELECT name FROM moderator WHERE (active = ?) UNION SELECT name FROM admin WHERE (active = ?) ORDER BY name LIMIT 10;"
+        );
+        for other in [select(), other("moderator").union(other("admin"))] {
+            assert!(matches!(
+                select().union(other).build(),
+                Err(Error::SQLBuildError(_))
+            ));
+        }
+    }
+
+    #[cfg(all(feature = "sqlite", feature = "postgres"))]
+    #[test]
+    fn compound_other_dialect() {
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let other_columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        assert!(matches!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .union(DBImpl::Postgres.select(&other_columns, "moderator", &[], &[]))
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn compound_mysql() {
+        let adult = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let active = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Asc,
+            table_name: None,
+            column_name: "name",
+            collation: None,
+            nulls: None,
+        }];
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let select = || {
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &order_by)
+                .where_clause(&adult)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: None,
+                })
+        };
+        let other = |table| {
+            DBImpl::MySQL
+                .select(&columns, table, &[], &[])
+                .where_clause(&active)
+        };
+
+        assert_eq!(
+            select()
+                .union(other("moderator"))
+                .union_all(other("admin"))
+                .build()
+                .unwrap(),
+            (
+                "(SELECT `name` FROM user WHERE (age > ?)) UNION (SELECT `name` FROM moderator WHERE (active = ?)) UNION ALL (SELECT `name` FROM admin WHERE (active = ?)) ORDER BY name LIMIT 10;".to_string(),
+                vec![Value::I64(18), Value::Bool(true), Value::Bool(true)]
+            )
+        );
+        assert!(matches!(
+            select().intersect(other("moderator")).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+        assert!(matches!(
+            select().except(other("moderator")).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn compound_postgres() {
+        let adult = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let active = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Asc,
+            table_name: None,
+            column_name: "name",
+            collation: None,
+            nulls: None,
+        }];
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let select = || {
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &order_by)
+                .where_clause(&adult)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: None,
+                })
+        };
+        let other = |table| {
+            DBImpl::Postgres
+                .select(&columns, table, &[], &[])
+                .where_clause(&active)
+        };
+
+        assert_eq!(
+            select()
+                .union(other("moderator"))
+                .union(other("admin"))
+                .build()
+                .unwrap(),
+            (
+                "(SELECT \"name\" FROM \"user\" WHERE (age > $1)) UNION (SELECT \"name\" FROM \"moderator\" WHERE (active = $2)) UNION (SELECT \"name\" FROM \"admin\" WHERE (active = $3)) ORDER BY \"name\" LIMIT 10;".to_string(),
+                vec![Value::I64(18), Value::Bool(true), Value::Bool(true)]
+            )
+        );
+        assert_eq!(
+            select().union_all(other("moderator")).build().unwrap().0,
+            "(SELECT \"name\" FROM \"user\" WHERE (age > $1)) UNION ALL (SELECT \"name\" FROM \"moderator\" WHERE (active = $2)) ORDER BY \"name\" LIMIT 10;"
+        );
+        assert_eq!(
+            select().intersect(other("moderator")).build().unwrap().0,
+            "(SELECT \"name\" FROM \"user\" WHERE (age > $1)) INTERSECT (SELECT \"name\" FROM \"moderator\" WHERE (active = $2)) ORDER BY \"name\" LIMIT 10;"
+        );
+        assert_eq!(
+            select().except(other("moderator")).build().unwrap().0,
+            "(SELECT \"name\" FROM \"user\" WHERE (age > $1)) EXCEPT (SELECT \"name\" FROM \"moderator\" WHERE (active = $2)) ORDER BY \"name\" LIMIT 10;"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn from_subquery_sqlite() {
+        let inner_condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("amount")),
+            Condition::Value(Value::I64(100)),
+        ])));
+        let outer_condition = Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("t"),
+                column_name: "user_id",
+            }),
+            Condition::Value(Value::I64(50)),
+        ])));
+        let inner_columns = [
+            DBImpl::SQLite.select_column(None, "user_id", None, None),
+            DBImpl::SQLite.select_column(None, "amount", None, None),
+        ];
+        let outer_columns = [DBImpl::SQLite.select_column(Some("t"), "user_id", None, None)];
+        let inner = DBImpl::SQLite
+            .select(&inner_columns, "order", &[], &[])
+            .where_clause(&inner_condition);
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select_from_subquery(&outer_columns, inner, "t", &[], &[])
+                .where_clause(&outer_condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT t.user_id FROM (SELECT user_id, amount FROM order WHERE (amount > ?)) AS \"t\" WHERE (t.user_id < ?);".to_string(),
+                vec![Value::I64(100), Value::I64(50)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn from_subquery_mysql() {
+        let inner_condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("amount")),
+            Condition::Value(Value::I64(100)),
+        ])));
+        let outer_condition = Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("t"),
+                column_name: "user_id",
+            }),
+            Condition::Value(Value::I64(50)),
+        ])));
+        let inner_columns = [
+            DBImpl::MySQL.select_column(None, "user_id", None, None),
+            DBImpl::MySQL.select_column(None, "amount", None, None),
+        ];
+        let outer_columns = [DBImpl::MySQL.select_column(Some("t"), "user_id", None, None)];
+        let inner = DBImpl::MySQL
+            .select(&inner_columns, "order", &[], &[])
+            .where_clause(&inner_condition);
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select_from_subquery(&outer_columns, inner, "t", &[], &[])
+                .where_clause(&outer_condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT `t`.`user_id` FROM (SELECT `user_id`, `amount` FROM order WHERE (amount > ?)) AS `t` WHERE (t.user_id < ?);".to_string(),
+                vec![Value::I64(100), Value::I64(50)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn from_subquery_postgres() {
+        let inner_condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("amount")),
+            Condition::Value(Value::I64(100)),
+        ])));
+        let middle_condition = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Value(Value::Ident("user_id")),
+            Condition::Value(Value::I64(0)),
+        ])));
+        let outer_condition = Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("t"),
+                column_name: "user_id",
+            }),
+            Condition::Value(Value::I64(50)),
+        ])));
+        let inner_columns = [
+            DBImpl::Postgres.select_column(None, "user_id", None, None),
+            DBImpl::Postgres.select_column(None, "amount", None, None),
+        ];
+        let middle_columns = [DBImpl::Postgres.select_column(None, "user_id", None, None)];
+        let outer_columns = [DBImpl::Postgres.select_column(Some("t"), "user_id", None, None)];
+        let inner = DBImpl::Postgres
+            .select(&inner_columns, "order", &[], &[])
+            .where_clause(&inner_condition);
+        let middle = DBImpl::Postgres
+            .select_from_subquery(&middle_columns, inner, "big_orders", &[], &[])
+            .where_clause(&middle_condition);
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select_from_subquery(&outer_columns, middle, "t", &[], &[])
+                .where_clause(&outer_condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"t\".\"user_id\" FROM (SELECT \"user_id\" FROM (SELECT \"user_id\", \"amount\" FROM \"order\" WHERE (amount > $1)) AS \"big_orders\" WHERE (user_id <> $2)) AS \"t\" WHERE (\"t\".user_id < $3);".to_string(),
+                vec![Value::I64(100), Value::I64(0), Value::I64(50)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn lock_sqlite() {
+        let columns = [DBImpl::SQLite.select_column(None, "balance", None, None)];
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "account", &[], &[])
+                .lock(RowLock {
+                    mode: LockMode::Update,
+                    wait: LockWait::SkipLocked,
+                })
+                .build()
+                .unwrap()
+                .0,
+            "SELECT balance FROM account;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn lock_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "balance", None, None)];
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "account", &[], &[])
+                .limit_clause(LimitClause {
+                    limit: 1,
+                    offset: None,
+                })
+                .lock(RowLock {
+                    mode: LockMode::Share,
+                    wait: LockWait::NoWait,
+                })
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `balance` FROM account LIMIT 1 FOR SHARE NOWAIT;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn lock_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "balance", None, None)];
+        let select = || {
+            DBImpl::Postgres
+                .select(&columns, "account", &[], &[])
+                .where_clause(&condition)
+                .limit_clause(LimitClause {
+                    limit: 1,
+                    offset: None,
+                })
+        };
+
+        let lock = RowLock {
+            mode: LockMode::Update,
+            wait: LockWait::SkipLocked,
+        };
+        assert_eq!(
+            select().lock(lock).build().unwrap().0,
+            "SELECT \"balance\" FROM \"account\" WHERE (id = $1) LIMIT 1 FOR UPDATE SKIP LOCKED;"
+        );
+        let lock = RowLock {
+            mode: LockMode::Share,
+            wait: LockWait::Wait,
+        };
+        assert_eq!(
+            select().lock(lock).build().unwrap().0,
+            "SELECT \"balance\" FROM \"account\" WHERE (id = $1) LIMIT 1 FOR SHARE;"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn distinct_on_sqlite() {
+        let columns = [DBImpl::SQLite.select_column(None, "title", None, None)];
+        assert!(matches!(
+            DBImpl::SQLite
+                .select(&columns, "post", &[], &[])
+                .distinct_on(&["user_id"])
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn distinct_on_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "title", None, None)];
+        assert!(matches!(
+            DBImpl::MySQL
+                .select(&columns, "post", &[], &[])
+                .distinct_on(&["user_id"])
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn distinct_on_postgres() {
+        let order_by = [
+            OrderByEntry {
+                ordering: Ordering::Asc,
+                table_name: None,
+                column_name: "user_id",
+                collation: None,
+                nulls: None,
+            },
+            OrderByEntry {
+                ordering: Ordering::Desc,
+                table_name: None,
+                column_name: "created_at",
+                collation: None,
+                nulls: None,
+            },
+        ];
+        let columns = [
+            DBImpl::Postgres.select_column(None, "user_id", None, None),
+            DBImpl::Postgres.select_column(None, "title", None, None),
+        ];
+        let select = || {
+            DBImpl::Postgres
+                .select(&columns, "post", &[], &order_by)
+                .distinct_on(&["user_id"])
+        };
+
+        assert_eq!(
+            select().build().unwrap().0,
+            "SELECT DISTINCT ON (\"user_id\") \"user_id\", \"title\" FROM \"post\" ORDER BY \"user_id\", \"created_at\" DESC;"
+        );
+        assert!(matches!(
+            select().distinct().build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn tablesample_sqlite() {
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::SQLite
+                .select(&columns, "event", &[], &[])
+                .tablesample(SampleMethod::System, 10.0, None)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn tablesample_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::MySQL
+                .select(&columns, "event", &[], &[])
+                .tablesample(SampleMethod::Bernoulli, 10.0, None)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn tablesample_postgres() {
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        let select = || DBImpl::Postgres.select(&columns, "event", &[], &[]);
+
+        assert_eq!(
+            select()
+                .tablesample(SampleMethod::System, 10.0, None)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"event\" TABLESAMPLE SYSTEM (10);"
+        );
+        assert_eq!(
+            select()
+                .tablesample(SampleMethod::Bernoulli, 10.0, Some(42))
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"event\" TABLESAMPLE BERNOULLI (10) REPEATABLE (42);"
+        );
+        assert_eq!(
+            select()
+                .tablesample(SampleMethod::Bernoulli, 0.5, None)
+                .limit_clause(LimitClause {
+                    limit: 5,
+                    offset: None,
+                })
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"event\" TABLESAMPLE BERNOULLI (0.5) LIMIT 5;"
+        );
+        assert!(matches!(
+            select()
+                .tablesample(SampleMethod::System, 150.0, None)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn placeholders_sqlite() {
+        let where_clause = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                Condition::Value(Value::Ident("age")),
+                Condition::Value(Value::I64(18)),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+                Condition::Value(Value::Ident("name")),
+                Condition::Value(Value::String("admin")),
+            ]))),
+        ]);
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let query = DBImpl::SQLite
+            .select(&columns, "user", &[], &[])
+            .where_clause(&where_clause);
+        assert_eq!(query.value_count(), 2);
+
+        assert_eq!(
+            query.build().unwrap(),
+            (
+                "SELECT name FROM user WHERE ((age > ?) AND (name <> ?));".to_string(),
+                vec![Value::I64(18), Value::String("admin")]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn placeholders_mysql() {
+        let join_condition = Condition::Conjunction(vec![
+            column_equals(("user", "id"), ("p", "user_id")),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("p"),
+                    column_name: "published",
+                }),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+        ]);
+        let where_clause = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Value(Value::I64(18)),
+        ])));
+        let joins = [DBImpl::MySQL.join_table(JoinType::Join, "post", "p", &join_condition)];
+        let columns = [DBImpl::MySQL.select_column(Some("user"), "name", None, None)];
+        let query = DBImpl::MySQL
+            .select(&columns, "user", &joins, &[])
+            .where_clause(&where_clause);
+        assert_eq!(query.value_count(), 2);
+
+        assert_eq!(
+            query.build().unwrap(),
+            (
+                "SELECT `user`.`name` FROM user JOIN post AS p ON ((user.id = p.user_id) AND (p.published = ?)) WHERE (age > ?);".to_string(),
+                vec![Value::Bool(true), Value::I64(18)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn placeholders_postgres() {
+        let join_condition = Condition::Conjunction(vec![
+            column_equals(("user", "id"), ("p", "user_id")),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("p"),
+                    column_name: "published",
+                }),
+                Condition::Value(Value::Bool(true)),
+            ]))),
+        ]);
+        let where_clause = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                Condition::Value(Value::Ident("age")),
+                Condition::Value(Value::I64(18)),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+                Condition::Value(Value::Ident("name")),
+                Condition::Value(Value::String("admin")),
+            ]))),
+        ]);
+        let joins = [DBImpl::Postgres.join_table(JoinType::Join, "post", "p", &join_condition)];
+        let columns = [DBImpl::Postgres.select_column(Some("user"), "name", None, None)];
+        let query = DBImpl::Postgres
+            .select(&columns, "user", &joins, &[])
+            .where_clause(&where_clause);
+        assert_eq!(query.value_count(), 3);
+
+        assert_eq!(
+            query.build().unwrap(),
+            (
+                "SELECT \"user\".\"name\" FROM \"user\" JOIN \"post\" AS p ON ((\"user\".id = \"p\".user_id) AND (\"p\".published = $1)) WHERE ((age > $2) AND (name <> $3));".to_string(),
+                vec![Value::Bool(true), Value::I64(18), Value::String("admin")]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn collate_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Collate(Box::new(Condition::Value(Value::String("bob"))), "NOCASE"),
+        ])));
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Desc,
+            table_name: None,
+            column_name: "name",
+            collation: Some("NOCASE"),
+            nulls: None,
+        }];
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &order_by)
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT id FROM user WHERE (name = ? COLLATE NOCASE) ORDER BY name COLLATE NOCASE DESC;".to_string(),
+                vec![Value::String("bob")]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn collate_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Collate(
+                Box::new(Condition::Value(Value::String("bob"))),
+                "utf8mb4_general_ci",
+            ),
+        ])));
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Desc,
+            table_name: None,
+            column_name: "name",
+            collation: Some("utf8mb4_general_ci"),
+            nulls: None,
+        }];
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &order_by)
+                .where_clause(&condition)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `id` FROM user WHERE (name = ? COLLATE utf8mb4_general_ci) ORDER BY name COLLATE utf8mb4_general_ci DESC;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn collate_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Collate(
+                Box::new(Condition::Value(Value::String("bob"))),
+                "und-x-icu",
+            ),
+        ])));
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Desc,
+            table_name: None,
+            column_name: "name",
+            collation: Some("und-x-icu"),
+            nulls: None,
+        }];
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &order_by)
+                .where_clause(&condition)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"user\" WHERE (name = $1 COLLATE \"und-x-icu\") ORDER BY \"name\" COLLATE \"und-x-icu\" DESC;"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn quantified_sqlite() {
+        let subquery_columns = [DBImpl::SQLite.select_column(None, "age", None, None)];
+        let subquery = DBImpl::SQLite.select(&subquery_columns, "child", &[], &[]);
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Quantified(Quantifier::All, Box::new(subquery)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert!(matches!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn quantified_mysql() {
+        let active = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(true)),
+        ])));
+        let subquery_columns = [DBImpl::MySQL.select_column(None, "age", None, None)];
+        let subquery = DBImpl::MySQL
+            .select(&subquery_columns, "child", &[], &[])
+            .where_clause(&active);
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Quantified(Quantifier::All, Box::new(subquery)),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT `name` FROM user WHERE (age > ALL (SELECT `age` FROM child WHERE (active = ?)));".to_string(),
+                vec![Value::Bool(true)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn quantified_postgres() {
+        let subquery_columns = [DBImpl::Postgres.select_column(None, "age", None, None)];
+        let subquery = DBImpl::Postgres.select(&subquery_columns, "child", &[], &[]);
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Quantified(Quantifier::Any, Box::new(subquery)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"name\" FROM \"user\" WHERE (age > ANY (SELECT \"age\" FROM \"child\"));"
+        );
+    }
+
+    #[cfg(all(feature = "mysql", feature = "postgres"))]
+    #[test]
+    fn quantified_other_dialect() {
+        let subquery_columns = [DBImpl::MySQL.select_column(None, "age", None, None)];
+        let subquery = DBImpl::MySQL.select(&subquery_columns, "child", &[], &[]);
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("age")),
+            Condition::Quantified(Quantifier::Any, Box::new(subquery)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn bind_limit_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: Some(20),
+                })
+                .bind_limit()
+                .build()
+                .unwrap(),
+            (
+                "SELECT name FROM user WHERE (id > ?) LIMIT ? OFFSET ?;".to_string(),
+                vec![Value::I64(5), Value::I64(10), Value::I64(20)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn bind_limit_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: Some(20),
+                })
+                .bind_limit()
+                .build()
+                .unwrap(),
+            (
+                "SELECT `name` FROM user LIMIT ? OFFSET ?;".to_string(),
+                vec![Value::I64(10), Value::I64(20)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn bind_limit_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: Some(20),
+                })
+                .bind_limit()
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"name\" FROM \"user\" WHERE (id > $1) LIMIT $2 OFFSET $3;".to_string(),
+                vec![Value::I64(5), Value::I64(10), Value::I64(20)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn offset_only_sqlite() {
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let select = || DBImpl::SQLite.select(&columns, "user", &[], &[]).offset(20);
+
+        assert_eq!(
+            select().build().unwrap(),
+            (
+                "SELECT name FROM user LIMIT -1 OFFSET 20;".to_string(),
+                vec![]
+            )
+        );
+        assert_eq!(
+            select().bind_limit().build().unwrap(),
+            (
+                "SELECT name FROM user LIMIT -1 OFFSET ?;".to_string(),
+                vec![Value::I64(20)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn offset_only_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let select = || DBImpl::MySQL.select(&columns, "user", &[], &[]).offset(20);
+
+        assert_eq!(
+            select().build().unwrap().0,
+            "SELECT `name` FROM user LIMIT 18446744073709551615 OFFSET 20;"
+        );
+        assert_eq!(
+            select().bind_limit().build().unwrap(),
+            (
+                "SELECT `name` FROM user LIMIT 18446744073709551615 OFFSET ?;".to_string(),
+                vec![Value::I64(20)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn offset_only_postgres() {
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let select = || {
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .offset(20)
+        };
+
+        assert_eq!(
+            select().build().unwrap().0,
+            "SELECT \"name\" FROM \"user\" OFFSET 20;"
+        );
+        assert_eq!(
+            select().bind_limit().build().unwrap(),
+            (
+                "SELECT \"name\" FROM \"user\" OFFSET $1;".to_string(),
+                vec![Value::I64(20)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn cartesian_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("role.id")),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let select = || {
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+        };
+
+        assert_eq!(
+            select()
+                .add_from_table("role")
+                .add_from_table("team")
+                .build()
+                .unwrap(),
+            (
+                "SELECT name FROM user, role, team WHERE (role.id = ?);".to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+        assert_eq!(
+            select()
+                .cross_join("role")
+                .cross_join("team")
+                .build()
+                .unwrap()
+                .0,
+            "SELECT name FROM user CROSS JOIN role CROSS JOIN team WHERE (role.id = ?);"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn cartesian_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let select = || DBImpl::MySQL.select(&columns, "user", &[], &[]);
+
+        assert_eq!(
+            select().add_from_table("role").build().unwrap().0,
+            "SELECT `name` FROM user, role;"
+        );
+        assert_eq!(
+            select().cross_join("role").build().unwrap().0,
+            "SELECT `name` FROM user CROSS JOIN role;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn cartesian_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("role.id")),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let select = || {
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+        };
+
+        assert_eq!(
+            select()
+                .add_from_table("role")
+                .add_from_table("team")
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"name\" FROM \"user\", \"role\", \"team\" WHERE (role.id = $1);"
+                    .to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+        assert_eq!(
+            select()
+                .cross_join("role")
+                .cross_join("team")
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"name\" FROM \"user\" CROSS JOIN \"role\" CROSS JOIN \"team\" WHERE (role.id = $1);"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn lateral_sqlite() {
+        let post_columns = [DBImpl::SQLite.select_column(None, "title", None, None)];
+        let posts = DBImpl::SQLite.select(&post_columns, "post", &[], &[]);
+        let join_condition = Condition::Value(Value::Ident("TRUE"));
+        let joins = [DBImpl::SQLite
+            .join_subquery(JoinType::LeftJoin, &posts, "p", &join_condition)
+            .lateral()];
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert!(matches!(
+            DBImpl::SQLite.select(&columns, "user", &joins, &[]).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn lateral_mysql() {
+        let post_condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("post.user_id")),
+            Condition::Value(Value::Ident("user.id")),
+        ])));
+        let post_columns = [DBImpl::MySQL.select_column(None, "title", None, None)];
+        let posts = DBImpl::MySQL
+            .select(&post_columns, "post", &[], &[])
+            .where_clause(&post_condition)
+            .limit_clause(LimitClause {
+                limit: 1,
+                offset: None,
+            });
+        let join_condition = Condition::Value(Value::Ident("TRUE"));
+        let joins = [DBImpl::MySQL
+            .join_subquery(JoinType::LeftJoin, &posts, "p", &join_condition)
+            .lateral()];
+        let columns = [
+            DBImpl::MySQL.select_column(None, "name", None, None),
+            DBImpl::MySQL.select_column(Some("p"), "title", None, None),
+        ];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `name`, `p`.`title` FROM user LEFT JOIN LATERAL (SELECT `title` FROM post WHERE (post.user_id = user.id) LIMIT 1) AS p ON TRUE;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn lateral_postgres() {
+        let post_condition = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Ident("post.user_id")),
+                Condition::Value(Value::Ident("user.id")),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                Condition::Value(Value::Ident("post.likes")),
+                Condition::Value(Value::I64(10)),
+            ]))),
+        ]);
+        let post_columns = [DBImpl::Postgres.select_column(None, "title", None, None)];
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Desc,
+            table_name: None,
+            column_name: "created",
+            collation: None,
+            nulls: None,
+        }];
+        let posts = DBImpl::Postgres
+            .select(&post_columns, "post", &[], &order_by)
+            .where_clause(&post_condition)
+            .limit_clause(LimitClause {
+                limit: 1,
+                offset: None,
+            });
+        let join_condition = Condition::Value(Value::Ident("TRUE"));
+        let joins = [DBImpl::Postgres
+            .join_subquery(JoinType::LeftJoin, &posts, "p", &join_condition)
+            .lateral()];
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("foo")),
+        ])));
+        let columns = [
+            DBImpl::Postgres.select_column(None, "name", None, None),
+            DBImpl::Postgres.select_column(Some("p"), "title", None, None),
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &joins, &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"name\", \"p\".\"title\" FROM \"user\" LEFT JOIN LATERAL (SELECT \"title\" FROM \"post\" WHERE ((post.user_id = user.id) AND (post.likes > $1)) ORDER BY \"created\" DESC LIMIT 1) AS p ON TRUE WHERE (name = $2);".to_string(),
+                vec![Value::I64(10), Value::String("foo")]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn nulls_order_sqlite() {
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let order_by = [
+            OrderByEntry {
+                ordering: Ordering::Asc,
+                table_name: None,
+                column_name: "nickname",
+                collation: None,
+                nulls: Some(NullsOrder::Last),
+            },
+            OrderByEntry {
+                ordering: Ordering::Desc,
+                table_name: Some("user"),
+                column_name: "last_login",
+                collation: None,
+                nulls: Some(NullsOrder::First),
+            },
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &order_by)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT name FROM user ORDER BY nickname NULLS LAST, user.last_login DESC NULLS FIRST;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn nulls_order_mysql() {
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Asc,
+            table_name: None,
+            column_name: "id",
+            collation: None,
+            nulls: None,
+        }];
+
+        // MySQL sorts NULL as the smallest value, so the CASE key has to put
+        // NULL last for ascending and first for descending orderings explicitly
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &order_by)
+                .order_by_many(&[
+                    OrderByEntry {
+                        ordering: Ordering::Asc,
+                        table_name: None,
+                        column_name: "nickname",
+                        collation: None,
+                        nulls: Some(NullsOrder::Last),
+                    },
+                    OrderByEntry {
+                        ordering: Ordering::Desc,
+                        table_name: Some("user"),
+                        column_name: "last_login",
+                        collation: None,
+                        nulls: Some(NullsOrder::First),
+                    },
+                ])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `name` FROM user ORDER BY id, CASE WHEN nickname IS NULL THEN 1 ELSE 0 END, nickname, CASE WHEN user.last_login IS NULL THEN 0 ELSE 1 END, user.last_login DESC;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn nulls_order_postgres() {
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let order_by = [OrderByEntry {
+            ordering: Ordering::Asc,
+            table_name: None,
+            column_name: "id",
+            collation: None,
+            nulls: None,
+        }];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &order_by)
+                .order_by_many(&[
+                    OrderByEntry {
+                        ordering: Ordering::Asc,
+                        table_name: None,
+                        column_name: "nickname",
+                        collation: None,
+                        nulls: Some(NullsOrder::Last),
+                    },
+                    OrderByEntry {
+                        ordering: Ordering::Desc,
+                        table_name: Some("user"),
+                        column_name: "last_login",
+                        collation: None,
+                        nulls: Some(NullsOrder::First),
+                    },
+                ])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"name\" FROM \"user\" ORDER BY \"id\", \"nickname\" NULLS LAST, \"user\".\"last_login\" DESC NULLS FIRST;"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn cast_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Cast(
+                Box::new(Condition::Value(Value::Ident("zip"))),
+                DbType::Int32,
+            ),
+            Condition::Value(Value::I64(5000)),
+        ])));
+        let columns = [
+            DBImpl::SQLite.select_column(None, "name", None, None),
+            DBImpl::SQLite
+                .select_column(None, "zip", Some("zip_number"), None)
+                .cast(DbType::Int32),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "address", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT name, CAST(zip AS INTEGER) AS zip_number FROM address WHERE (CAST(zip AS INTEGER) > ?);".to_string(),
+                vec![Value::I64(5000)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn cast_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Cast(
+                Box::new(Condition::Value(Value::Ident("zip"))),
+                DbType::Int32,
+            ),
+            Condition::Value(Value::I64(5000)),
+        ])));
+        let columns = [
+            DBImpl::MySQL.select_column(None, "name", None, None),
+            DBImpl::MySQL
+                .select_column(None, "zip", Some("zip_number"), None)
+                .cast(DbType::Int32),
+        ];
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "address", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT `name`, CAST(`zip` AS SIGNED) AS zip_number FROM address WHERE (CAST(zip AS SIGNED) > ?);".to_string(),
+                vec![Value::I64(5000)]
+            )
+        );
+
+        let columns = [DBImpl::MySQL
+            .select_column(None, "mac", None, None)
+            .cast(DbType::MacAddress)];
+        assert!(matches!(
+            DBImpl::MySQL.select(&columns, "address", &[], &[]).build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Cast(
+                Box::new(Condition::Value(Value::Ident("flags"))),
+                DbType::BitVec,
+            ),
+            Condition::Value(Value::I64(0)),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        assert!(matches!(
+            DBImpl::MySQL
+                .select(&columns, "address", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn cast_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Cast(
+                Box::new(Condition::Value(Value::Ident("zip"))),
+                DbType::Int32,
+            ),
+            Condition::Value(Value::I64(5000)),
+        ])));
+        let columns = [
+            DBImpl::Postgres.select_column(None, "name", None, None),
+            DBImpl::Postgres
+                .select_column(None, "zip", Some("zip_number"), None)
+                .cast(DbType::Int32),
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "address", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"name\", CAST(\"zip\" AS integer) AS zip_number FROM \"address\" WHERE (CAST(zip AS integer) > $1);".to_string(),
+                vec![Value::I64(5000)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn distinct_aggregates_sqlite() {
+        let columns = [
+            DBImpl::SQLite
+                .select_column(
+                    None,
+                    "author",
+                    Some("authors"),
+                    Some(SelectAggregator::Count),
+                )
+                .distinct(),
+            DBImpl::SQLite
+                .select_column(Some("post"), "likes", None, Some(SelectAggregator::Avg))
+                .distinct(),
+            DBImpl::SQLite.select_column(None, "likes", Some("total"), Some(SelectAggregator::Sum)),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "post", &[], &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT COUNT(DISTINCT author) AS authors, AVG(DISTINCT post.likes), SUM(likes) AS total FROM post;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn distinct_aggregates_mysql() {
+        let columns = [
+            DBImpl::MySQL
+                .select_column(
+                    None,
+                    "author",
+                    Some("authors"),
+                    Some(SelectAggregator::Count),
+                )
+                .distinct(),
+            DBImpl::MySQL.select_column(None, "likes", Some("total"), Some(SelectAggregator::Sum)),
+        ];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "post", &[], &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT COUNT(DISTINCT `author`) AS authors, SUM(`likes`) AS total FROM post;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn distinct_aggregates_postgres() {
+        let columns = [
+            DBImpl::Postgres
+                .select_column(
+                    None,
+                    "author",
+                    Some("authors"),
+                    Some(SelectAggregator::Count),
+                )
+                .distinct(),
+            DBImpl::Postgres
+                .select_column(Some("post"), "likes", None, Some(SelectAggregator::Avg))
+                .distinct(),
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "post", &[], &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT COUNT(DISTINCT \"author\") AS authors, AVG(DISTINCT \"post\".\"likes\") FROM \"post\";"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn null_handling_sqlite() {
+        let display_name = Condition::Coalesce(vec![
+            Condition::Value(Value::Ident("nickname")),
+            Condition::Value(Value::Ident("name")),
+            Condition::Value(Value::String("anonymous")),
+        ]);
+        let score = Condition::NullIf(Box::new([
+            Condition::Value(Value::Ident("score")),
+            Condition::Value(Value::I64(0)),
+        ]));
+        let email = Condition::IfNull(Box::new([
+            Condition::Value(Value::Ident("email")),
+            Condition::Value(Value::String("")),
+        ]));
+        let condition = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(5)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .add_expression(&display_name, Some("display_name"))
+                .add_expression(&score, None)
+                .add_expression(&email, Some("email"))
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT id, COALESCE(nickname, name, ?) AS display_name, NULLIF(score, ?), IFNULL(email, ?) AS email FROM user WHERE (id > ?);".to_string(),
+                vec![
+                    Value::String("anonymous"),
+                    Value::I64(0),
+                    Value::String(""),
+                    Value::I64(5)
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn null_handling_mysql() {
+        let email = Condition::IfNull(Box::new([
+            Condition::Value(Value::Ident("email")),
+            Condition::Value(Value::String("")),
+        ]));
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .add_expression(&email, Some("email"))
+                .build()
+                .unwrap(),
+            (
+                "SELECT `id`, IFNULL(email, ?) AS email FROM user;".to_string(),
+                vec![Value::String("")]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn null_handling_postgres() {
+        let score = Condition::NullIf(Box::new([
+            Condition::Value(Value::Ident("score")),
+            Condition::Value(Value::I64(0)),
+        ]));
+        let email = Condition::IfNull(Box::new([
+            Condition::Value(Value::Ident("email")),
+            Condition::Value(Value::String("")),
+        ]));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+
+        // Postgres has no IFNULL, COALESCE with two arguments is equivalent
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .add_expression(&score, None)
+                .add_expression(&email, Some("email"))
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"id\", NULLIF(score, $1), COALESCE(email, $2) AS email FROM \"user\";"
+                    .to_string(),
+                vec![Value::I64(0), Value::String("")]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn empty_coalesce_mysql() {
+        let condition = Condition::UnaryCondition(UnaryCondition::IsNull(Box::new(
+            Condition::Coalesce(vec![]),
+        )));
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+
+        let joins = [DBImpl::MySQL.join_table(JoinType::Join, "post", "p", &condition)];
+        assert!(matches!(
+            DBImpl::MySQL.select(&columns, "user", &joins, &[]).build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn case_expression_sqlite() {
+        let sign = Condition::Case(Box::new(CaseExpr {
+            branches: vec![
+                (
+                    Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                        Condition::Value(Value::Ident("balance")),
+                        Condition::Value(Value::I64(0)),
+                    ]))),
+                    Value::String("pos"),
+                ),
+                (
+                    Condition::BinaryCondition(BinaryCondition::Less(Box::new([
+                        Condition::Value(Value::Ident("balance")),
+                        Condition::Value(Value::I64(0)),
+                    ]))),
+                    Value::String("neg"),
+                ),
+            ],
+            else_value: Some(Value::String("zero")),
+        }));
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "account", &[], &[])
+                .add_expression(&sign, Some("sign"))
+                .build()
+                .unwrap(),
+            (
+                "SELECT id, CASE WHEN (balance > ?) THEN ? WHEN (balance < ?) THEN ? ELSE ? END AS sign FROM account;".to_string(),
+                vec![
+                    Value::I64(0),
+                    Value::String("pos"),
+                    Value::I64(0),
+                    Value::String("neg"),
+                    Value::String("zero")
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn case_expression_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Case(Box::new(CaseExpr {
+                branches: vec![(
+                    Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                        Condition::Value(Value::Ident("balance")),
+                        Condition::Value(Value::I64(100)),
+                    ]))),
+                    Value::I64(1),
+                )],
+                else_value: None,
+            })),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "account", &[], &[])
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT `id` FROM account WHERE (CASE WHEN (balance > ?) THEN ? END <> ?);"
+                    .to_string(),
+                vec![Value::I64(100), Value::I64(1), Value::I64(1)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn case_expression_postgres() {
+        let sign = Condition::Case(Box::new(CaseExpr {
+            branches: vec![(
+                Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                    Condition::Value(Value::Ident("balance")),
+                    Condition::Value(Value::I64(0)),
+                ]))),
+                Value::String("pos"),
+            )],
+            else_value: Some(Value::String("neg")),
+        }));
+        let condition = Condition::BinaryCondition(BinaryCondition::NotEquals(Box::new([
+            Condition::Case(Box::new(CaseExpr {
+                branches: vec![(
+                    Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                        Condition::Value(Value::Ident("balance")),
+                        Condition::Value(Value::I64(100)),
+                    ]))),
+                    Value::I64(1),
+                )],
+                else_value: None,
+            })),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "account", &[], &[])
+                .add_expression(&sign, Some("sign"))
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"id\", CASE WHEN (balance > $1) THEN $2 ELSE $3 END AS sign FROM \"account\" WHERE (CASE WHEN (balance > $4) THEN $5 END <> $6);".to_string(),
+                vec![
+                    Value::I64(0),
+                    Value::String("pos"),
+                    Value::String("neg"),
+                    Value::I64(100),
+                    Value::I64(1),
+                    Value::I64(1)
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn case_without_branches_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Case(Box::new(CaseExpr {
+                branches: vec![],
+                else_value: Some(Value::I64(1)),
+            })),
+            Condition::Value(Value::I64(1)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "account", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn string_functions_sqlite() {
+        let full_name = Condition::StringFunction(
+            StringFunction::Concat,
+            vec![
+                Condition::Value(Value::Ident("first_name")),
+                Condition::Value(Value::String(" ")),
+                Condition::Value(Value::Ident("last_name")),
+            ],
+        );
+        let initial = Condition::StringFunction(
+            StringFunction::Substr,
+            vec![
+                Condition::Value(Value::Ident("first_name")),
+                Condition::Value(Value::I64(1)),
+                Condition::Value(Value::I64(1)),
+            ],
+        );
+        let length = Condition::StringFunction(
+            StringFunction::Length,
+            vec![Condition::Value(Value::Ident("last_name"))],
+        );
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&[], "user", &[], &[])
+                .add_expression(&full_name, Some("full_name"))
+                .add_expression(&initial, None)
+                .add_expression(&length, None)
+                .build()
+                .unwrap(),
+            (
+                "SELECT (first_name || ? || last_name) AS full_name, SUBSTR(first_name, ?, ?), LENGTH(last_name) FROM user;".to_string(),
+                vec![Value::String(" "), Value::I64(1), Value::I64(1)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn string_functions_mysql() {
+        let full_name = Condition::StringFunction(
+            StringFunction::Concat,
+            vec![
+                Condition::Value(Value::Ident("first_name")),
+                Condition::Value(Value::String(" ")),
+                Condition::Value(Value::Ident("last_name")),
+            ],
+        );
+        let length = Condition::StringFunction(
+            StringFunction::Length,
+            vec![Condition::Value(Value::Ident("last_name"))],
+        );
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&[], "user", &[], &[])
+                .add_expression(&full_name, Some("full_name"))
+                .add_expression(&length, None)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT CONCAT(first_name, ?, last_name) AS full_name, CHAR_LENGTH(last_name) FROM user;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn string_functions_postgres() {
+        let full_name = Condition::StringFunction(
+            StringFunction::Concat,
+            vec![
+                Condition::Value(Value::Ident("first_name")),
+                Condition::Value(Value::String(" ")),
+                Condition::Value(Value::Ident("last_name")),
+            ],
+        );
+        let email = Condition::StringFunction(
+            StringFunction::Lower,
+            vec![Condition::Value(Value::Ident("email"))],
+        );
+        let length = Condition::StringFunction(
+            StringFunction::Length,
+            vec![Condition::Value(Value::Ident("last_name"))],
+        );
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&[], "user", &[], &[])
+                .add_expression(&full_name, Some("full_name"))
+                .add_expression(&email, None)
+                .add_expression(&length, None)
+                .build()
+                .unwrap(),
+            (
+                "SELECT CONCAT(first_name, $1, last_name) AS full_name, LOWER(email), LENGTH(last_name) FROM \"user\";".to_string(),
+                vec![Value::String(" ")]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn string_function_arity_sqlite() {
+        let trimmed = Condition::StringFunction(StringFunction::Trim, vec![]);
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .add_expression(&trimmed, None)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn math_functions_sqlite() {
+        let price = Condition::MathFunction(
+            MathFunction::Round,
+            vec![
+                Condition::Value(Value::Ident("price")),
+                Condition::Value(Value::I64(2)),
+            ],
+        );
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::MathFunction(
+                MathFunction::Mod,
+                vec![
+                    Condition::Value(Value::Ident("id")),
+                    Condition::Value(Value::I64(2)),
+                ],
+            ),
+            Condition::Value(Value::I64(0)),
+        ])));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&[], "stock", &[], &[])
+                .add_expression(&price, Some("price"))
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT ROUND(price, ?) AS price FROM stock WHERE ((id % ?) = ?);".to_string(),
+                vec![Value::I64(2), Value::I64(2), Value::I64(0)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn math_functions_mysql() {
+        let change = Condition::MathFunction(
+            MathFunction::Abs,
+            vec![Condition::Value(Value::Ident("change"))],
+        );
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::MathFunction(
+                MathFunction::Mod,
+                vec![
+                    Condition::Value(Value::Ident("id")),
+                    Condition::Value(Value::I64(2)),
+                ],
+            ),
+            Condition::Value(Value::I64(0)),
+        ])));
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&[], "stock", &[], &[])
+                .add_expression(&change, None)
+                .where_clause(&condition)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT ABS(change) FROM stock WHERE (MOD(id, ?) = ?);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn math_functions_postgres() {
+        let price = Condition::MathFunction(
+            MathFunction::Round,
+            vec![
+                Condition::Value(Value::Ident("price")),
+                Condition::Value(Value::I64(2)),
+            ],
+        );
+        let change = Condition::MathFunction(
+            MathFunction::Abs,
+            vec![Condition::Value(Value::Ident("change"))],
+        );
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::MathFunction(
+                MathFunction::Mod,
+                vec![
+                    Condition::Value(Value::Ident("id")),
+                    Condition::Value(Value::I64(2)),
+                ],
+            ),
+            Condition::Value(Value::I64(0)),
+        ])));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&[], "stock", &[], &[])
+                .add_expression(&price, Some("price"))
+                .add_expression(&change, None)
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "SELECT ROUND(price, $1) AS price, ABS(change) FROM \"stock\" WHERE (MOD(id, $2) = $3);".to_string(),
+                vec![Value::I64(2), Value::I64(2), Value::I64(0)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn math_function_arity_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::MathFunction(
+                MathFunction::Mod,
+                vec![Condition::Value(Value::Ident("id"))],
+            ),
+            Condition::Value(Value::I64(0)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "stock", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn without_columns_sqlite() {
+        assert!(matches!(
+            DBImpl::SQLite.select(&[], "user", &[], &[]).build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn without_columns_mysql() {
+        assert!(matches!(
+            DBImpl::MySQL.select(&[], "user", &[], &[]).build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn without_columns_postgres() {
+        assert!(matches!(
+            DBImpl::Postgres.select(&[], "user", &[], &[]).build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn literals_sqlite() {
+        let flag = Condition::Value(Value::Bool(true));
+        let columns = [
+            DBImpl::SQLite.select_raw_column("1", Some("one")),
+            DBImpl::SQLite.select_column(None, "name", None, None),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .add_expression(&flag, Some("flag"))
+                .build()
+                .unwrap(),
+            (
+                "SELECT 1 AS one, name, ? AS flag FROM user;".to_string(),
+                vec![Value::Bool(true)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn literals_mysql() {
+        let columns = [
+            DBImpl::MySQL.select_column(None, "name", None, None),
+            DBImpl::MySQL.select_raw_column("NULL", Some("email")),
+        ];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `name`, NULL AS email FROM user;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn literals_postgres() {
+        let flag = Condition::Value(Value::Bool(true));
+        let columns = [
+            DBImpl::Postgres.select_raw_column("1", Some("one")),
+            DBImpl::Postgres.select_column(None, "name", None, None),
+            DBImpl::Postgres.select_raw_column("NULL", Some("email")),
+        ];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .add_expression(&flag, Some("flag"))
+                .build()
+                .unwrap(),
+            (
+                "SELECT 1 AS one, \"name\", NULL AS email, $1 AS flag FROM \"user\";".to_string(),
+                vec![Value::Bool(true)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn where_groups_sqlite() {
+        let active = Condition::Value(Value::Ident("active"));
+        let all = [
+            Condition::Value(Value::Ident("verified")),
+            Condition::UnaryCondition(UnaryCondition::IsNotNull(Box::new(Condition::Value(
+                Value::Ident("email"),
+            )))),
+        ];
+        let any = [
+            Condition::Value(Value::Ident("admin")),
+            Condition::Value(Value::Ident("owner")),
+        ];
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .where_clause(&active)
+                .where_all(&all)
+                .where_any(&any)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT id FROM user WHERE (active AND (verified AND (email IS NOT NULL)) AND (admin OR owner));"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn where_groups_mysql() {
+        let any = [
+            Condition::Value(Value::Ident("admin")),
+            Condition::Value(Value::Ident("owner")),
+        ];
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_any(&any)
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `id` FROM user WHERE (admin OR owner);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn where_groups_postgres() {
+        let active = Condition::Value(Value::Ident("active"));
+        let all = [
+            Condition::Value(Value::Ident("verified")),
+            Condition::UnaryCondition(UnaryCondition::IsNotNull(Box::new(Condition::Value(
+                Value::Ident("email"),
+            )))),
+        ];
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&active)
+                .where_all(&all)
+                .where_any(&[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"user\" WHERE (active AND (verified AND (email IS NOT NULL)));"
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_all(&[])
+                .where_any(&[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"user\";"
+        );
+    }
+
+    fn id_equals(id: i64) -> Condition<'static> {
+        Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::I64(id)),
+        ])))
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn accumulated_where_sqlite() {
+        let (first, second, third) = (id_equals(1), id_equals(2), id_equals(3));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &[], &[])
+                .where_clause(&first)
+                .where_clause(&second)
+                .or_where(&third)
+                .build()
+                .unwrap(),
+            (
+                "SELECT name FROM user WHERE (((id = ?) AND (id = ?)) OR (id = ?));".to_string(),
+                vec![Value::I64(1), Value::I64(2), Value::I64(3)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn accumulated_where_mysql() {
+        let (first, second, third) = (id_equals(1), id_equals(2), id_equals(3));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .or_where(&first)
+                .or_where(&second)
+                .where_clause(&third)
+                .build()
+                .unwrap(),
+            (
+                "SELECT `name` FROM user WHERE (((id = ?) OR (id = ?)) AND (id = ?));".to_string(),
+                vec![Value::I64(1), Value::I64(2), Value::I64(3)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn accumulated_where_postgres() {
+        let (first, second, third) = (id_equals(1), id_equals(2), id_equals(3));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&first)
+                .where_clause(&second)
+                .or_where(&third)
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"name\" FROM \"user\" WHERE (((id = $1) AND (id = $2)) OR (id = $3));"
+                    .to_string(),
+                vec![Value::I64(1), Value::I64(2), Value::I64(3)]
+            )
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&first)
+                .or_where(&second)
+                .replace_where(&third)
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"name\" FROM \"user\" WHERE (id = $1);".to_string(),
+                vec![Value::I64(3)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn keyset_sqlite() {
+        let keys = [
+            ("created", Value::I64(100), Ordering::Desc),
+            ("id", Value::I64(7), Ordering::Asc),
+        ];
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "post", &[], &[])
+                .paginate_after(&keys)
+                .build()
+                .unwrap(),
+            (
+                "SELECT name FROM post WHERE ((created < ?) OR ((created = ?) AND (id > ?))) ORDER BY created DESC, id;".to_string(),
+                vec![Value::I64(100), Value::I64(100), Value::I64(7)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn keyset_mysql() {
+        let keys = [
+            ("created", Value::I64(100), Ordering::Desc),
+            ("id", Value::I64(7), Ordering::Desc),
+        ];
+        let condition = Condition::Value(Value::Ident("active"));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "post", &[], &[])
+                .where_clause(&condition)
+                .paginate_after(&keys)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: None,
+                })
+                .build()
+                .unwrap(),
+            (
+                "SELECT `name` FROM post WHERE (active AND ((created, id) < (?, ?))) ORDER BY created DESC, id DESC LIMIT 10;".to_string(),
+                vec![Value::I64(100), Value::I64(7)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn keyset_postgres() {
+        let condition = Condition::Value(Value::Ident("active"));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let page = |keys| {
+            DBImpl::Postgres
+                .select(&columns, "post", &[], &[])
+                .where_clause(&condition)
+                .paginate_after(keys)
+                .limit_clause(LimitClause {
+                    limit: 10,
+                    offset: None,
+                })
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(
+            page(&[
+                ("created", Value::I64(100), Ordering::Desc),
+                ("id", Value::I64(7), Ordering::Desc),
+            ]),
+            (
+                "SELECT \"name\" FROM \"post\" WHERE (active AND ((\"created\", \"id\") < ($1, $2))) ORDER BY \"created\" DESC, \"id\" DESC LIMIT 10;".to_string(),
+                vec![Value::I64(100), Value::I64(7)]
+            )
+        );
+        assert_eq!(
+            page(&[
+                ("created", Value::I64(100), Ordering::Desc),
+                ("id", Value::I64(7), Ordering::Asc),
+            ]),
+            (
+                "SELECT \"name\" FROM \"post\" WHERE (active AND ((\"created\" < $1) OR ((\"created\" = $2) AND (\"id\" > $3)))) ORDER BY \"created\" DESC, \"id\" LIMIT 10;".to_string(),
+                vec![Value::I64(100), Value::I64(100), Value::I64(7)]
+            )
+        );
+        assert_eq!(
+            page(&[("createdAt", Value::I64(100), Ordering::Desc)]),
+            (
+                "SELECT \"name\" FROM \"post\" WHERE (active AND (\"createdAt\" < $1)) ORDER BY \"createdAt\" DESC LIMIT 10;".to_string(),
+                vec![Value::I64(100)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn shared_columns_sqlite() {
+        let joins = [
+            DBImpl::SQLite.join_table_on(
+                JoinType::Join,
+                "membership",
+                "m",
+                JoinOn::Using(&["user_id", "org_id"]),
+            ),
+            DBImpl::SQLite.join_table_on(JoinType::LeftJoin, "profile", "p", JoinOn::Natural),
+        ];
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT name FROM user JOIN membership AS m USING (\"user_id\", \"org_id\") NATURAL LEFT JOIN profile AS p;"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn shared_columns_mysql() {
+        let joins = [DBImpl::MySQL.join_table_on(
+            JoinType::Join,
+            "membership",
+            "m",
+            JoinOn::Using(&["user_id", "org_id"]),
+        )];
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `name` FROM user JOIN membership AS m USING (`user_id`, `org_id`);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn shared_columns_postgres() {
+        let joins = [
+            DBImpl::Postgres.join_table_on(
+                JoinType::Join,
+                "membership",
+                "m",
+                JoinOn::Using(&["user_id"]),
+            ),
+            DBImpl::Postgres.join_table_on(JoinType::LeftJoin, "profile", "p", JoinOn::Natural),
+        ];
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "user", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"name\" FROM \"user\" JOIN \"membership\" AS m USING (\"user_id\") NATURAL LEFT JOIN \"profile\" AS p;"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn filtered_aggregates_sqlite() {
+        let positive = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("amount")),
+            Condition::Value(Value::I64(0)),
+        ])));
+        let deposited = Condition::Aggregate(Box::new(AggregateExpr {
+            function: SelectAggregator::Sum,
+            argument: Some(Condition::Value(Value::Ident("amount"))),
+            filter: Some(positive),
+        }));
+        let total = Condition::Aggregate(Box::new(AggregateExpr {
+            function: SelectAggregator::Count,
+            argument: None,
+            filter: None,
+        }));
+        let columns = [DBImpl::SQLite.select_column(None, "account", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, "transfer", &[], &[])
+                .add_expression(&deposited, Some("deposited"))
+                .add_expression(&total, Some("total"))
+                .group_by(&["account"])
+                .build()
+                .unwrap(),
+            (
+                "SELECT account, SUM(amount) FILTER (WHERE (amount > ?)) AS deposited, COUNT(*) AS total FROM transfer GROUP BY account;".to_string(),
+                vec![Value::I64(0)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn filtered_aggregates_mysql() {
+        let positive = || {
+            Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+                Condition::Value(Value::Ident("amount")),
+                Condition::Value(Value::I64(0)),
+            ])))
+        };
+        let deposits = Condition::Aggregate(Box::new(AggregateExpr {
+            function: SelectAggregator::Count,
+            argument: None,
+            filter: Some(positive()),
+        }));
+        let deposited = Condition::Aggregate(Box::new(AggregateExpr {
+            function: SelectAggregator::Sum,
+            argument: Some(Condition::Value(Value::Ident("amount"))),
+            filter: Some(positive()),
+        }));
+        let columns = [DBImpl::MySQL.select_column(None, "account", None, None)];
+
+        // MySQL has no FILTER clause, the filter moves into a CASE expression
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "transfer", &[], &[])
+                .add_expression(&deposits, Some("deposits"))
+                .add_expression(&deposited, Some("deposited"))
+                .group_by(&["account"])
+                .build()
+                .unwrap(),
+            (
+                "SELECT `account`, COUNT(CASE WHEN (amount > ?) THEN 1 END) AS deposits, SUM(CASE WHEN (amount > ?) THEN amount END) AS deposited FROM transfer GROUP BY account;".to_string(),
+                vec![Value::I64(0), Value::I64(0)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn filtered_aggregates_postgres() {
+        let positive = Condition::BinaryCondition(BinaryCondition::Greater(Box::new([
+            Condition::Value(Value::Ident("amount")),
+            Condition::Value(Value::I64(0)),
+        ])));
+        let deposits = Condition::Aggregate(Box::new(AggregateExpr {
+            function: SelectAggregator::Count,
+            argument: None,
+            filter: Some(positive),
+        }));
+        let columns = [DBImpl::Postgres.select_column(None, "account", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "transfer", &[], &[])
+                .add_expression(&deposits, Some("deposits"))
+                .group_by(&["account"])
+                .build()
+                .unwrap(),
+            (
+                "SELECT \"account\", COUNT(*) FILTER (WHERE (amount > $1)) AS deposits FROM \"transfer\" GROUP BY \"account\";".to_string(),
+                vec![Value::I64(0)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn invalid_subquery_in_where_postgres() {
+        let subquery = DBImpl::Postgres.select(&[], "post", &[], &[]);
+        let condition = Condition::UnaryCondition(UnaryCondition::Exists(Box::new(
+            Condition::Subquery(Box::new(subquery)),
+        )));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn invalid_subquery_in_having_postgres() {
+        let subquery = DBImpl::Postgres.select(&[], "post", &[], &[]);
+        let condition = Condition::UnaryCondition(UnaryCondition::Exists(Box::new(
+            Condition::Subquery(Box::new(subquery)),
+        )));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .group_by(&["id"])
+                .having_clause(&condition)
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn invalid_subquery_in_join_postgres() {
+        let subquery = DBImpl::Postgres.select(&[], "post", &[], &[]);
+        let condition = Condition::UnaryCondition(UnaryCondition::Exists(Box::new(
+            Condition::Subquery(Box::new(subquery)),
+        )));
+        let joins = [DBImpl::Postgres.join_table(JoinType::Join, "post", "p", &condition)];
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "user", &joins, &[])
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn invalid_subquery_in_expression_postgres() {
+        let subquery = DBImpl::Postgres.select(&[], "post", &[], &[]);
+        let expression = Condition::Subquery(Box::new(subquery));
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert!(matches!(
+            DBImpl::Postgres
+                .select(&columns, "user", &[], &[])
+                .add_expression(&expression, Some("posts"))
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn next_val_in_where_mysql() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("id")),
+            Condition::Value(Value::NextVal("user_id")),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        assert!(matches!(
+            DBImpl::MySQL
+                .select(&columns, "user", &[], &[])
+                .where_clause(&condition)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+}
@@ -1,15 +1,40 @@
 use std::fmt::Write;
 
+use rorm_declaration::imr::DbType;
+
 use crate::aggregation::SelectAggregator;
+use crate::db_specific::cast_type;
+use crate::error::Error;
+use crate::DBImpl;
 
 /**
 Trait representing a column builder.
 */
 pub trait SelectColumn {
+    /**
+    Cast the column to the given type, e.g. `CAST("x" AS integer)`.
+
+    The cast is applied before the aggregation function.
+    MySQL casts all integers and booleans to `SIGNED`.
+
+    **Parameter**:
+    - `target_type`: The type to cast the column to.
+    */
+    fn cast(self, target_type: DbType) -> Self;
+
+    /**
+    Only aggregate distinct values, e.g. `COUNT(DISTINCT "x")`.
+
+    Has no effect, if the column isn't aggregated.
+    */
+    fn distinct(self) -> Self;
+
     /**
     Build the column selector in the provided String.
+
+    Returns an error, if the dialect doesn't support the type the column is cast to.
     */
-    fn build(&self, s: &mut String);
+    fn build(&self, s: &mut String) -> Result<(), Error>;
 }
 
 /**
@@ -17,16 +42,26 @@ Representation of a select identifier.
 
 This is due to the presence of join expressions and some reserved keywords in postgres.
  */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SelectColumnData<'until_build> {
     /// Optional name of the table
     pub table_name: Option<&'until_build str>,
     /// Name of the column
+    ///
+    /// `*` selects all columns and is never quoted, e.g. to build `COUNT(*)`.
     pub column_name: &'until_build str,
     /// Optional alias to set for the column
     pub select_alias: Option<&'until_build str>,
     /// Optional aggregation function
     pub aggregation: Option<SelectAggregator>,
+    /// Whether the aggregation function only takes distinct values into account
+    pub distinct: bool,
+    /// Optional type to cast the column to
+    pub cast: Option<DbType>,
+    /// Whether `column_name` is a raw SQL expression, e.g. `NULL` or `1`
+    ///
+    /// Raw expressions are written as is, without quoting and table name.
+    pub raw: bool,
 }
 
 /**
@@ -34,7 +69,7 @@ Representation of the column selector and implementation of the [SelectColumn] t
 
 Should only be constructed via [DBImpl::select_column](crate::DBImpl::select_column).
  */
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum SelectColumnImpl<'until_build> {
     /// SQLite representation of a column selector expression.
     #[cfg(feature = "sqlite")]
@@ -48,7 +83,31 @@ pub enum SelectColumnImpl<'until_build> {
 }
 
 impl<'until_build> SelectColumn for SelectColumnImpl<'until_build> {
-    fn build(&self, s: &mut String) {
+    fn cast(mut self, target_type: DbType) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectColumnImpl::SQLite(ref mut d) => d.cast = Some(target_type),
+            #[cfg(feature = "mysql")]
+            SelectColumnImpl::MySQL(ref mut d) => d.cast = Some(target_type),
+            #[cfg(feature = "postgres")]
+            SelectColumnImpl::Postgres(ref mut d) => d.cast = Some(target_type),
+        };
+        self
+    }
+
+    fn distinct(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            SelectColumnImpl::SQLite(ref mut d) => d.distinct = true,
+            #[cfg(feature = "mysql")]
+            SelectColumnImpl::MySQL(ref mut d) => d.distinct = true,
+            #[cfg(feature = "postgres")]
+            SelectColumnImpl::Postgres(ref mut d) => d.distinct = true,
+        };
+        self
+    }
+
+    fn build(&self, s: &mut String) -> Result<(), Error> {
         match self {
             #[cfg(feature = "sqlite")]
             SelectColumnImpl::SQLite(d) => {
@@ -61,14 +120,31 @@ impl<'until_build> SelectColumn for SelectColumnImpl<'until_build> {
                         SelectAggregator::Min => write!(s, "MIN("),
                     }
                     .unwrap();
+                    if d.distinct {
+                        write!(s, "DISTINCT ").unwrap();
+                    }
+                }
+
+                let cast = d
+                    .cast
+                    .map(|target_type| cast_type(DBImpl::SQLite, target_type))
+                    .transpose()?;
+                if cast.is_some() {
+                    write!(s, "CAST(").unwrap();
                 }
 
-                if let Some(table_name) = d.table_name {
-                    write!(s, "{table_name}.").unwrap();
+                if !d.raw {
+                    if let Some(table_name) = d.table_name {
+                        write!(s, "{table_name}.").unwrap();
+                    }
                 }
 
                 write!(s, "{}", d.column_name).unwrap();
 
+                if let Some(cast) = cast {
+                    write!(s, " AS {cast})").unwrap();
+                }
+
                 if d.aggregation.is_some() {
                     write!(s, ")").unwrap();
                 }
@@ -88,12 +164,35 @@ impl<'until_build> SelectColumn for SelectColumnImpl<'until_build> {
                         SelectAggregator::Min => write!(s, "MIN("),
                     }
                     .unwrap();
+                    if d.distinct {
+                        write!(s, "DISTINCT ").unwrap();
+                    }
+                }
+                let cast = d
+                    .cast
+                    .map(|target_type| cast_type(DBImpl::MySQL, target_type))
+                    .transpose()?;
+                if cast.is_some() {
+                    write!(s, "CAST(").unwrap();
                 }
-                if let Some(table_name) = d.table_name {
-                    write!(s, "`{table_name}`.").unwrap();
+
+                if d.raw {
+                    write!(s, "{}", d.column_name).unwrap();
+                } else {
+                    if let Some(table_name) = d.table_name {
+                        write!(s, "`{table_name}`.").unwrap();
+                    }
+
+                    if d.column_name == "*" {
+                        write!(s, "*").unwrap();
+                    } else {
+                        write!(s, "`{}`", d.column_name).unwrap();
+                    }
                 }
 
-                write!(s, "`{}`", d.column_name).unwrap();
+                if let Some(cast) = cast {
+                    write!(s, " AS {cast})").unwrap();
+                }
 
                 if d.aggregation.is_some() {
                     write!(s, ")").unwrap();
@@ -114,12 +213,35 @@ impl<'until_build> SelectColumn for SelectColumnImpl<'until_build> {
                         SelectAggregator::Min => write!(s, "MIN("),
                     }
                     .unwrap();
+                    if d.distinct {
+                        write!(s, "DISTINCT ").unwrap();
+                    }
                 }
-                if let Some(table_name) = d.table_name {
-                    write!(s, "\"{table_name}\".").unwrap();
+                let cast = d
+                    .cast
+                    .map(|target_type| cast_type(DBImpl::Postgres, target_type))
+                    .transpose()?;
+                if cast.is_some() {
+                    write!(s, "CAST(").unwrap();
                 }
 
-                write!(s, "\"{}\"", d.column_name).unwrap();
+                if d.raw {
+                    write!(s, "{}", d.column_name).unwrap();
+                } else {
+                    if let Some(table_name) = d.table_name {
+                        write!(s, "\"{table_name}\".").unwrap();
+                    }
+
+                    if d.column_name == "*" {
+                        write!(s, "*").unwrap();
+                    } else {
+                        write!(s, "\"{}\"", d.column_name).unwrap();
+                    }
+                }
+
+                if let Some(cast) = cast {
+                    write!(s, " AS {cast})").unwrap();
+                }
 
                 if d.aggregation.is_some() {
                     write!(s, ")").unwrap();
@@ -130,5 +252,28 @@ impl<'until_build> SelectColumn for SelectColumnImpl<'until_build> {
                 }
             }
         }
+        Ok(())
     }
 }
+
+/// `COUNT(*)` selector used by [DBImpl::count](crate::DBImpl::count)
+pub(crate) const COUNT_ALL: SelectColumnData<'static> = SelectColumnData {
+    table_name: None,
+    column_name: "*",
+    select_alias: None,
+    aggregation: Some(SelectAggregator::Count),
+    distinct: false,
+    cast: None,
+    raw: false,
+};
+
+/// `1` selector used by [DBImpl::exists](crate::DBImpl::exists)
+pub(crate) const ONE: SelectColumnData<'static> = SelectColumnData {
+    table_name: None,
+    column_name: "1",
+    select_alias: None,
+    aggregation: None,
+    distinct: false,
+    cast: None,
+    raw: true,
+};
@@ -0,0 +1,203 @@
+use crate::query::Query;
+use crate::value::Value;
+use crate::DBImpl;
+
+/**
+Collection of built statements that are executed together, e.g. as migration script.
+
+Should only be constructed via [crate::DBImpl::statement_batch].
+*/
+#[derive(Clone)]
+pub struct StatementBatch<'post_build> {
+    pub(crate) dialect: DBImpl,
+    pub(crate) statements: Vec<Query<'post_build>>,
+}
+
+impl<'post_build> StatementBatch<'post_build> {
+    /**
+    Add a statement to the batch.
+
+    **Parameter**:
+    - `statement`: The built statement, e.g. the result of a `build` method.
+     */
+    pub fn push(mut self, statement: impl Into<Query<'post_build>>) -> Self {
+        self.statements.push(statement.into());
+        self
+    }
+
+    /**
+    Add multiple statements to the batch, e.g. the result of [crate::create_table::CreateTable::build].
+
+    **Parameter**:
+    - `statements`: The built statements in the order they are executed.
+     */
+    pub fn extend<Q: Into<Query<'post_build>>>(
+        mut self,
+        statements: impl IntoIterator<Item = Q>,
+    ) -> Self {
+        self.statements
+            .extend(statements.into_iter().map(Into::into));
+        self
+    }
+
+    /**
+    Whether the batch can be sent to the database in a single execution.
+
+    - SQLite executes all statements of a script, e.g. via `sqlite3_exec`.
+    - MySQL requires the multi statement option of the client, which is disabled by default.
+    - Postgres only accepts multiple statements without bind parameters.
+     */
+    pub fn allows_multiple_statements(&self) -> bool {
+        match self.dialect {
+            #[cfg(feature = "sqlite")]
+            DBImpl::SQLite => true,
+            #[cfg(feature = "mysql")]
+            DBImpl::MySQL => false,
+            #[cfg(feature = "postgres")]
+            DBImpl::Postgres => self.statements.iter().all(|x| x.values().is_empty()),
+        }
+    }
+
+    /**
+    Build the script by joining the statements with `;\n`.
+
+    The values of all statements are concatenated in order.
+    Whether they can be bound to the script depends on the driver,
+    Postgres placeholders are numbered per statement.
+     */
+    pub fn build(self) -> Query<'post_build> {
+        let mut sql = String::new();
+        let mut values: Vec<Value<'post_build>> = vec![];
+
+        for statement in self.statements {
+            let (s, v) = statement.into_parts();
+            let s = s.trim_end();
+            if !sql.is_empty() {
+                sql.push('\n');
+            }
+            sql.push_str(s.strip_suffix(';').unwrap_or(s));
+            sql.push(';');
+            values.extend(v);
+        }
+
+        Query::new(sql, values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rorm_declaration::imr::DbType;
+
+    use crate::create_index::CreateIndex;
+    use crate::create_table::CreateTable;
+    use crate::query::Query;
+    #[cfg(feature = "postgres")]
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn migration_sqlite() {
+        let batch = DBImpl::SQLite
+            .statement_batch()
+            .extend(
+                DBImpl::SQLite
+                    .create_table("user")
+                    .add_column(DBImpl::SQLite.create_column("user", "id", DbType::Int64, &[]))
+                    .build()
+                    .unwrap(),
+            )
+            .push(
+                DBImpl::SQLite
+                    .create_index("user_id", "user")
+                    .add_column("id")
+                    .build()
+                    .unwrap(),
+            );
+
+        assert!(batch.allows_multiple_statements());
+        assert_eq!(
+            batch.build(),
+            Query::new(
+                "CREATE TABLE \"user\" (\"id\" INTEGER ) STRICT;\nCREATE INDEX user_id ON user (id);"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn migration_mysql() {
+        let batch = DBImpl::MySQL
+            .statement_batch()
+            .extend(
+                DBImpl::MySQL
+                    .create_table("user")
+                    .add_column(DBImpl::MySQL.create_column("user", "name", DbType::Int64, &[]))
+                    .build()
+                    .unwrap(),
+            )
+            .push(
+                DBImpl::MySQL
+                    .create_index("user_name", "user")
+                    .add_column("name")
+                    .build()
+                    .unwrap(),
+            );
+
+        assert!(!batch.allows_multiple_statements());
+        assert_eq!(
+            batch.build(),
+            Query::new(
+                "CREATE TABLE `user` (`name` BIGINT(255) );\nCREATE INDEX user_name ON user (name);"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn migration_postgres() {
+        let batch = DBImpl::Postgres
+            .statement_batch()
+            .extend(
+                DBImpl::Postgres
+                    .create_table("user")
+                    .add_column(DBImpl::Postgres.create_column("user", "id", DbType::Int64, &[]))
+                    .add_column(DBImpl::Postgres.create_column("user", "name", DbType::Int64, &[]))
+                    .build()
+                    .unwrap(),
+            )
+            .push(
+                DBImpl::Postgres
+                    .create_index("user_name", "user")
+                    .add_column("name")
+                    .build()
+                    .unwrap(),
+            );
+
+        assert!(batch.allows_multiple_statements());
+        assert_eq!(
+            batch.build().sql(),
+            "CREATE TABLE \"user\" (\"id\" bigint , \"name\" bigint );\nCREATE INDEX user_name ON user (name);"
+        );
+
+        let batch = DBImpl::Postgres
+            .statement_batch()
+            .push((
+                "UPDATE \"user\" SET \"name\" = $1;".to_string(),
+                vec![Value::I64(1)],
+            ))
+            .push("DELETE FROM \"user\";".to_string());
+        assert!(!batch.allows_multiple_statements());
+        assert_eq!(
+            batch.build(),
+            Query::new(
+                "UPDATE \"user\" SET \"name\" = $1;\nDELETE FROM \"user\";".to_string(),
+                vec![Value::I64(1)]
+            )
+        );
+    }
+}
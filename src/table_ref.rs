@@ -0,0 +1,334 @@
+use std::fmt::{Display, Formatter};
+
+use crate::DBImpl;
+
+/**
+Reference to a table, optionally qualified by the schema it's in.
+
+Plain table names convert into a [TableRef] without schema,
+so all builders accepting a [TableRef] accept a `&str` as well.
+
+SQLite uses the name of an attached database as schema, e.g. `main` or `temp`.
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TableRef<'until_build> {
+    /// Optional schema of the table
+    pub schema: Option<&'until_build str>,
+    /// Name of the table
+    pub name: &'until_build str,
+}
+
+impl<'until_build> TableRef<'until_build> {
+    /**
+    Reference a table in the given schema.
+
+    **Parameter**:
+    - `schema`: Name of the schema, or attached database for SQLite.
+    - `name`: Name of the table.
+     */
+    pub fn qualified(schema: &'until_build str, name: &'until_build str) -> Self {
+        Self {
+            schema: Some(schema),
+            name,
+        }
+    }
+
+    /**
+    Returns the reference with schema and table quoted as identifiers of the dialect,
+    e.g. `"schema"."table"`.
+     */
+    pub(crate) fn quoted(&self, dialect: DBImpl) -> String {
+        match self.schema {
+            Some(schema) => format!(
+                "{}.{}",
+                dialect.quote_identifier(schema),
+                dialect.quote_identifier(self.name)
+            ),
+            None => dialect.quote_identifier(self.name),
+        }
+    }
+}
+
+impl<'until_build> From<&'until_build str> for TableRef<'until_build> {
+    fn from(name: &'until_build str) -> Self {
+        Self { schema: None, name }
+    }
+}
+
+/**
+Writes the unquoted reference, e.g. `schema.table`.
+*/
+impl Display for TableRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(schema) = self.schema {
+            write!(f, "{schema}.")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    use rorm_declaration::imr::Annotation;
+    use rorm_declaration::imr::DbType;
+
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    use crate::alter_table::{AlterTable, AlterTableOperation};
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    use crate::conditional::Condition;
+    use crate::create_index::CreateIndex;
+    use crate::create_table::CreateTable;
+    #[cfg(feature = "postgres")]
+    use crate::drop_table::DropTable;
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    use crate::join_table::JoinType;
+    use crate::select::Select;
+    use crate::table_ref::TableRef;
+    use crate::truncate_table::TruncateTable;
+    use crate::{DBImpl, Value};
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn qualified_sqlite() {
+        let table = TableRef::qualified("aux", "user");
+        let columns = [DBImpl::SQLite.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table(table)
+                .add_column(DBImpl::SQLite.create_column("user", "id", DbType::Int64, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE \"aux\".\"user\" (\"id\" INTEGER ) STRICT; ".to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .select(&columns, table, &[], &[])
+                .build()
+                .unwrap(),
+            ("SELECT id FROM aux.user;".to_string(), vec![])
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn qualified_mysql() {
+        let table = TableRef::qualified("app", "user");
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::MySQL
+                .create_table(table)
+                .add_column(DBImpl::MySQL.create_column("user", "id", DbType::Int64, &[]))
+                .build()
+                .unwrap(),
+            [(
+                "CREATE TABLE `app`.`user` (`id` BIGINT(255) ); ".to_string(),
+                vec![]
+            )]
+        );
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, table, &[], &[])
+                .build()
+                .unwrap(),
+            ("SELECT `id` FROM app.user;".to_string(), vec![])
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn qualified_postgres() {
+        let table = TableRef::qualified("app", "user");
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, table, &[], &[])
+                .build()
+                .unwrap(),
+            ("SELECT \"id\" FROM \"app\".\"user\";".to_string(), vec![])
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .drop_table(table)
+                .add_table("post")
+                .build()
+                .unwrap(),
+            "DROP TABLE \"app\".\"user\", \"post\";"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(TableRef::qualified("app", "user").to_string(), "app.user");
+        assert_eq!(TableRef::from("user").to_string(), "user");
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn qualified_builders_sqlite() {
+        let table = TableRef::qualified("aux", "user");
+        let column = DBImpl::SQLite.create_column(
+            table,
+            "updated",
+            DbType::DateTime,
+            &[Annotation::AutoUpdateTime],
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .create_table(table)
+                .add_column(column)
+                .build()
+                .unwrap()[1]
+                .0,
+            "CREATE TRIGGER IF NOT EXISTS aux.user_updated_auto_update_time AFTER UPDATE ON user FOR EACH ROW BEGIN UPDATE user SET updated = CURRENT_TIMESTAMP WHERE ROWID = NEW.ROWID; END;"
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .create_index("user_id", table)
+                .add_column("id")
+                .build()
+                .unwrap(),
+            "CREATE INDEX aux.user_id ON user (id);"
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .truncate_table(table)
+                .restart_identity()
+                .build()
+                .unwrap(),
+            vec![
+                ("DELETE FROM \"aux\".\"user\";".to_string(), vec![]),
+                (
+                    "DELETE FROM \"aux\".sqlite_sequence WHERE name = ?;".to_string(),
+                    vec![Value::String("user")]
+                ),
+            ]
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .alter_table(
+                    table,
+                    AlterTableOperation::RenameTo {
+                        name: "member".to_string()
+                    }
+                )
+                .build()
+                .unwrap(),
+            vec![(
+                "ALTER TABLE \"aux\".\"user\" RENAME TO \"member\";".to_string(),
+                vec![]
+            )]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn qualified_builders_mysql() {
+        let table = TableRef::qualified("app", "user");
+        assert_eq!(
+            DBImpl::MySQL
+                .create_index("user_id", table)
+                .add_column("id")
+                .build()
+                .unwrap(),
+            "CREATE INDEX user_id ON app.user (id);"
+        );
+        assert_eq!(
+            DBImpl::MySQL.truncate_table(table).build().unwrap(),
+            vec![("TRUNCATE TABLE `app`.`user`;".to_string(), vec![])]
+        );
+        assert_eq!(
+            DBImpl::MySQL
+                .alter_table(
+                    table,
+                    AlterTableOperation::RenameTo {
+                        name: "member".to_string()
+                    }
+                )
+                .build()
+                .unwrap(),
+            vec![(
+                "RENAME TABLE `app`.`user` TO `app`.`member`;".to_string(),
+                vec![]
+            )]
+        );
+
+        let condition = Condition::Value(Value::Bool(true));
+        let joins = [DBImpl::MySQL.join_table(JoinType::Join, table, "u", &condition)];
+        let columns = [DBImpl::MySQL.select_column(None, "id", None, None)];
+        assert_eq!(
+            DBImpl::MySQL
+                .select(&columns, "post", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT `id` FROM post JOIN app.user AS u ON ?;"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn qualified_builders_postgres() {
+        let table = TableRef::qualified("app", "user");
+        let annotations = [Annotation::Choices(vec!["active".to_string()])];
+        let choices = DBImpl::Postgres
+            .create_column(table, "state", DbType::Choices, &annotations)
+            .comment("current state");
+        let updated = DBImpl::Postgres.create_column(
+            table,
+            "updated",
+            DbType::DateTime,
+            &[Annotation::AutoUpdateTime],
+        );
+        let statements: Vec<_> = DBImpl::Postgres
+            .create_table(table)
+            .add_column(choices)
+            .add_column(updated)
+            .build()
+            .unwrap()
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect();
+        assert_eq!(
+            statements,
+            [
+                "CREATE TYPE app._user_state AS ENUM('active');",
+                "CREATE TABLE \"app\".\"user\" (\"state\" app._user_state , \"updated\" timestamptz ); ",
+                "COMMENT ON COLUMN \"app\".\"user\".\"state\" IS 'current state';",
+                "CREATE OR REPLACE FUNCTION app.user_updated_auto_update_time_update_procedure() RETURNS TRIGGER AS $$ BEGIN NEW.updated = now(); RETURN NEW; END; $$ language 'plpgsql';",
+                "DROP TRIGGER IF EXISTS user_updated_auto_update_time_update ON \"app\".\"user\";",
+                "CREATE TRIGGER user_updated_auto_update_time_update BEFORE UPDATE ON \"app\".\"user\" FOR EACH ROW WHEN (OLD IS DISTINCT FROM NEW) EXECUTE PROCEDURE app.user_updated_auto_update_time_update_procedure();",
+            ]
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .create_index("user_id", table)
+                .add_column("id")
+                .build()
+                .unwrap(),
+            "CREATE INDEX user_id ON app.user (id);"
+        );
+        assert_eq!(
+            DBImpl::Postgres.truncate_table(table).build().unwrap(),
+            vec![("TRUNCATE TABLE \"app\".\"user\";".to_string(), vec![])]
+        );
+
+        let condition = Condition::Value(Value::Bool(true));
+        let joins = [DBImpl::Postgres.join_table(JoinType::Join, table, "u", &condition)];
+        let columns = [DBImpl::Postgres.select_column(None, "id", None, None)];
+        assert_eq!(
+            DBImpl::Postgres
+                .select(&columns, "post", &joins, &[])
+                .build()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"post\" JOIN \"app\".\"user\" AS u ON $1;"
+        );
+    }
+}
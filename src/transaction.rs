@@ -0,0 +1,285 @@
+use crate::error::Error;
+use crate::DBImpl;
+
+/**
+The statements to control transactions and savepoints.
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TransactionStatement<'until_build> {
+    /// Start a transaction, `START TRANSACTION` for MySQL and `BEGIN` for the others
+    Begin,
+    /// Commit the current transaction
+    Commit,
+    /// Roll back the current transaction
+    Rollback,
+    /// Create a savepoint with the given name in the current transaction
+    Savepoint(&'until_build str),
+    /// Release the savepoint with the given name, keeping its changes
+    ReleaseSavepoint(&'until_build str),
+    /// Roll back all changes made after the savepoint with the given name
+    RollbackToSavepoint(&'until_build str),
+}
+
+/**
+The isolation levels a transaction can be started with.
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// Representation of SERIALIZABLE in SQL
+    Serializable,
+    /// Representation of REPEATABLE READ in SQL
+    RepeatableRead,
+    /// Representation of READ COMMITTED in SQL
+    ReadCommitted,
+    /// Representation of READ UNCOMMITTED in SQL
+    ReadUncommitted,
+}
+
+impl IsolationLevel {
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    fn keyword(self) -> &'static str {
+        match self {
+            IsolationLevel::Serializable => "SERIALIZABLE",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+        }
+    }
+}
+
+/**
+Trait representing a transaction control builder.
+*/
+pub trait Transaction {
+    /**
+    Set the isolation level of the transaction to begin.
+
+    Only valid for [TransactionStatement::Begin].
+    MySQL sets the level with a separate statement before starting the transaction.
+    SQLite transactions are always serializable, which satisfies every level,
+    so the level is omitted for SQLite.
+
+    **Parameter**:
+    - `level`: The isolation level to use.
+     */
+    fn isolation_level(self, level: IsolationLevel) -> Self;
+
+    /**
+    This method is used to build the transaction control statements.
+
+    Returns an error, if an isolation level is set for a statement other than
+    [TransactionStatement::Begin].
+     */
+    fn build(self) -> Result<Vec<String>, Error>;
+}
+
+/**
+The representation of data of the transaction control statement.
+*/
+#[derive(Debug, Copy, Clone)]
+pub struct TransactionData<'until_build> {
+    pub(crate) statement: TransactionStatement<'until_build>,
+    pub(crate) isolation_level: Option<IsolationLevel>,
+}
+
+/**
+Implementation of the [Transaction] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::transaction].
+*/
+#[derive(Debug)]
+pub enum TransactionImpl<'until_build> {
+    /**
+    SQLite representation of the transaction control statement.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(TransactionData<'until_build>),
+    /**
+    MySQL representation of the transaction control statement.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(TransactionData<'until_build>),
+    /**
+    Postgres representation of the transaction control statement.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(TransactionData<'until_build>),
+}
+
+impl<'until_build> Transaction for TransactionImpl<'until_build> {
+    fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            TransactionImpl::SQLite(ref mut d) => d.isolation_level = Some(level),
+            #[cfg(feature = "mysql")]
+            TransactionImpl::MySQL(ref mut d) => d.isolation_level = Some(level),
+            #[cfg(feature = "postgres")]
+            TransactionImpl::Postgres(ref mut d) => d.isolation_level = Some(level),
+        };
+        self
+    }
+
+    fn build(self) -> Result<Vec<String>, Error> {
+        let (dialect, d) = match self {
+            #[cfg(feature = "sqlite")]
+            TransactionImpl::SQLite(d) => (DBImpl::SQLite, d),
+            #[cfg(feature = "mysql")]
+            TransactionImpl::MySQL(d) => (DBImpl::MySQL, d),
+            #[cfg(feature = "postgres")]
+            TransactionImpl::Postgres(d) => (DBImpl::Postgres, d),
+        };
+
+        if d.isolation_level.is_some() && d.statement != TransactionStatement::Begin {
+            return Err(Error::SQLBuildError(
+                "An isolation level can only be set when beginning a transaction".to_string(),
+            ));
+        }
+
+        let statement = match d.statement {
+            TransactionStatement::Begin => match dialect {
+                #[cfg(feature = "sqlite")]
+                DBImpl::SQLite => "BEGIN;".to_string(),
+                #[cfg(feature = "mysql")]
+                DBImpl::MySQL => {
+                    let begin = "START TRANSACTION;".to_string();
+                    return Ok(match d.isolation_level {
+                        Some(level) => vec![
+                            format!("SET TRANSACTION ISOLATION LEVEL {};", level.keyword()),
+                            begin,
+                        ],
+                        None => vec![begin],
+                    });
+                }
+                #[cfg(feature = "postgres")]
+                DBImpl::Postgres => match d.isolation_level {
+                    Some(level) => format!("BEGIN ISOLATION LEVEL {};", level.keyword()),
+                    None => "BEGIN;".to_string(),
+                },
+            },
+            TransactionStatement::Commit => "COMMIT;".to_string(),
+            TransactionStatement::Rollback => "ROLLBACK;".to_string(),
+            TransactionStatement::Savepoint(name) => {
+                format!("SAVEPOINT {};", dialect.quote_identifier(name))
+            }
+            TransactionStatement::ReleaseSavepoint(name) => {
+                format!("RELEASE SAVEPOINT {};", dialect.quote_identifier(name))
+            }
+            TransactionStatement::RollbackToSavepoint(name) => {
+                format!("ROLLBACK TO SAVEPOINT {};", dialect.quote_identifier(name))
+            }
+        };
+
+        Ok(vec![statement])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "sqlite")]
+    use crate::error::Error;
+    use crate::transaction::{IsolationLevel, Transaction, TransactionStatement};
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn transaction_sqlite() {
+        let build = |statement| DBImpl::SQLite.transaction(statement).build().unwrap();
+
+        assert_eq!(build(TransactionStatement::Begin), ["BEGIN;"]);
+        assert_eq!(
+            build(TransactionStatement::Savepoint("before_import")),
+            ["SAVEPOINT \"before_import\";"]
+        );
+        assert_eq!(
+            build(TransactionStatement::RollbackToSavepoint("before_import")),
+            ["ROLLBACK TO SAVEPOINT \"before_import\";"]
+        );
+        assert_eq!(build(TransactionStatement::Commit), ["COMMIT;"]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn transaction_mysql() {
+        let build = |statement| DBImpl::MySQL.transaction(statement).build().unwrap();
+
+        assert_eq!(build(TransactionStatement::Begin), ["START TRANSACTION;"]);
+        assert_eq!(
+            build(TransactionStatement::ReleaseSavepoint("before_import")),
+            ["RELEASE SAVEPOINT `before_import`;"]
+        );
+        assert_eq!(
+            build(TransactionStatement::Savepoint("a`b")),
+            ["SAVEPOINT `a``b`;"]
+        );
+        assert_eq!(build(TransactionStatement::Rollback), ["ROLLBACK;"]);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn transaction_postgres() {
+        let build = |statement| DBImpl::Postgres.transaction(statement).build().unwrap();
+
+        assert_eq!(build(TransactionStatement::Begin), ["BEGIN;"]);
+        assert_eq!(
+            build(TransactionStatement::Savepoint("before_import")),
+            ["SAVEPOINT \"before_import\";"]
+        );
+        assert_eq!(
+            build(TransactionStatement::RollbackToSavepoint("before_import")),
+            ["ROLLBACK TO SAVEPOINT \"before_import\";"]
+        );
+        assert_eq!(
+            build(TransactionStatement::ReleaseSavepoint("before_import")),
+            ["RELEASE SAVEPOINT \"before_import\";"]
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn isolation_level_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite
+                .transaction(TransactionStatement::Begin)
+                .isolation_level(IsolationLevel::ReadCommitted)
+                .build()
+                .unwrap(),
+            ["BEGIN;"]
+        );
+        assert!(matches!(
+            DBImpl::SQLite
+                .transaction(TransactionStatement::Commit)
+                .isolation_level(IsolationLevel::Serializable)
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn isolation_level_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .transaction(TransactionStatement::Begin)
+                .isolation_level(IsolationLevel::RepeatableRead)
+                .build()
+                .unwrap(),
+            [
+                "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ;",
+                "START TRANSACTION;"
+            ]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn isolation_level_postgres() {
+        assert_eq!(
+            DBImpl::Postgres
+                .transaction(TransactionStatement::Begin)
+                .isolation_level(IsolationLevel::Serializable)
+                .build()
+                .unwrap(),
+            ["BEGIN ISOLATION LEVEL SERIALIZABLE;"]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::error::Error;
+use crate::table_ref::TableRef;
+use crate::value::Value;
+use crate::DBImpl;
+
+/**
+Trait representing a truncate table builder.
+*/
+pub trait TruncateTable<'until_build> {
+    /**
+    Reset the sequences of identity columns of the table.
+
+    MySQL always resets the auto increment counter, so this is a no-op there.
+    SQLite removes the table's entry from `sqlite_sequence`,
+    which only exists if any table in the database uses `AUTOINCREMENT`.
+     */
+    fn restart_identity(self) -> Self;
+
+    /**
+    Truncate all tables that have foreign keys referencing the table as well.
+
+    Only supported by Postgres.
+     */
+    fn cascade(self) -> Self;
+
+    /**
+    Build the truncate table operation.
+
+    SQLite has no TRUNCATE statement, so a `DELETE FROM` without a condition is
+    emitted instead, which SQLite optimizes to a truncation.
+
+    **Returns**:
+    List of tuples consisting of the query string and the corresponding bind parameters.
+     */
+    fn build(self) -> Result<Vec<(String, Vec<Value<'until_build>>)>, Error>;
+}
+
+/**
+The representation of data of the truncate table statement.
+*/
+#[derive(Debug, Copy, Clone)]
+pub struct TruncateTableData<'until_build> {
+    pub(crate) name: TableRef<'until_build>,
+    pub(crate) restart_identity: bool,
+    pub(crate) cascade: bool,
+}
+
+/**
+Implementation of the [TruncateTable] trait for the different implementations.
+
+Should only be constructed via [crate::DBImpl::truncate_table].
+*/
+#[derive(Debug)]
+pub enum TruncateTableImpl<'until_build> {
+    /**
+    SQLite representation of the TRUNCATE TABLE operation.
+     */
+    #[cfg(feature = "sqlite")]
+    SQLite(TruncateTableData<'until_build>),
+    /**
+    MySQL representation of the TRUNCATE TABLE operation.
+     */
+    #[cfg(feature = "mysql")]
+    MySQL(TruncateTableData<'until_build>),
+    /**
+    Postgres representation of the TRUNCATE TABLE operation.
+     */
+    #[cfg(feature = "postgres")]
+    Postgres(TruncateTableData<'until_build>),
+}
+
+impl<'until_build> TruncateTable<'until_build> for TruncateTableImpl<'until_build> {
+    fn restart_identity(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            TruncateTableImpl::SQLite(ref mut d) => d.restart_identity = true,
+            #[cfg(feature = "mysql")]
+            TruncateTableImpl::MySQL(ref mut d) => d.restart_identity = true,
+            #[cfg(feature = "postgres")]
+            TruncateTableImpl::Postgres(ref mut d) => d.restart_identity = true,
+        };
+        self
+    }
+
+    fn cascade(mut self) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            TruncateTableImpl::SQLite(ref mut d) => d.cascade = true,
+            #[cfg(feature = "mysql")]
+            TruncateTableImpl::MySQL(ref mut d) => d.cascade = true,
+            #[cfg(feature = "postgres")]
+            TruncateTableImpl::Postgres(ref mut d) => d.cascade = true,
+        };
+        self
+    }
+
+    fn build(self) -> Result<Vec<(String, Vec<Value<'until_build>>)>, Error> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            TruncateTableImpl::SQLite(d) => {
+                if d.cascade {
+                    return Err(Error::UnsupportedFeature(
+                        "SQLite doesn't support truncating with CASCADE".to_string(),
+                    ));
+                }
+
+                let mut statements = vec![(
+                    format!("DELETE FROM {};", d.name.quoted(DBImpl::SQLite)),
+                    vec![],
+                )];
+                if d.restart_identity {
+                    // Every attached database has its own sqlite_sequence table
+                    let sequences = match d.name.schema {
+                        Some(schema) => {
+                            format!(
+                                "{}.sqlite_sequence",
+                                DBImpl::SQLite.quote_identifier(schema)
+                            )
+                        }
+                        None => "sqlite_sequence".to_string(),
+                    };
+                    statements.push((
+                        format!("DELETE FROM {sequences} WHERE name = ?;"),
+                        vec![Value::String(d.name.name)],
+                    ));
+                }
+
+                Ok(statements)
+            }
+            #[cfg(feature = "mysql")]
+            TruncateTableImpl::MySQL(d) => {
+                if d.cascade {
+                    return Err(Error::UnsupportedFeature(
+                        "MySQL doesn't support truncating with CASCADE".to_string(),
+                    ));
+                }
+
+                Ok(vec![(
+                    format!("TRUNCATE TABLE {};", d.name.quoted(DBImpl::MySQL)),
+                    vec![],
+                )])
+            }
+            #[cfg(feature = "postgres")]
+            TruncateTableImpl::Postgres(d) => Ok(vec![(
+                format!(
+                    "TRUNCATE TABLE {}{}{};",
+                    d.name.quoted(DBImpl::Postgres),
+                    if d.restart_identity {
+                        " RESTART IDENTITY"
+                    } else {
+                        ""
+                    },
+                    if d.cascade { " CASCADE" } else { "" },
+                ),
+                vec![],
+            )]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    use crate::error::Error;
+    use crate::truncate_table::TruncateTable;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn truncate_sqlite() {
+        assert_eq!(
+            DBImpl::SQLite.truncate_table("user").build().unwrap(),
+            vec![("DELETE FROM \"user\";".to_string(), Vec::<Value>::new())]
+        );
+        assert_eq!(
+            DBImpl::SQLite
+                .truncate_table("user")
+                .restart_identity()
+                .build()
+                .unwrap(),
+            vec![
+                ("DELETE FROM \"user\";".to_string(), vec![]),
+                (
+                    "DELETE FROM sqlite_sequence WHERE name = ?;".to_string(),
+                    vec![Value::String("user")]
+                ),
+            ]
+        );
+        assert!(matches!(
+            DBImpl::SQLite.truncate_table("user").cascade().build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn truncate_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .truncate_table("user")
+                .restart_identity()
+                .build()
+                .unwrap(),
+            vec![("TRUNCATE TABLE `user`;".to_string(), Vec::<Value>::new())]
+        );
+        assert!(matches!(
+            DBImpl::MySQL.truncate_table("user").cascade().build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn truncate_postgres() {
+        assert_eq!(
+            DBImpl::Postgres.truncate_table("user").build().unwrap(),
+            vec![("TRUNCATE TABLE \"user\";".to_string(), Vec::<Value>::new())]
+        );
+        assert_eq!(
+            DBImpl::Postgres
+                .truncate_table("user")
+                .restart_identity()
+                .cascade()
+                .build()
+                .unwrap(),
+            vec![(
+                "TRUNCATE TABLE \"user\" RESTART IDENTITY CASCADE;".to_string(),
+                Vec::<Value>::new()
+            )]
+        );
+    }
+}
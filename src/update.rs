@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use crate::conditional::{BuildCondition, Condition};
+use crate::conditional::{build_where, BuildCondition, Condition, WhereGroup};
 #[cfg(feature = "mysql")]
 use crate::db_specific::mysql;
 #[cfg(feature = "postgres")]
@@ -8,6 +8,8 @@ use crate::db_specific::postgres;
 #[cfg(feature = "sqlite")]
 use crate::db_specific::sqlite;
 use crate::error::Error;
+use crate::query::Query;
+use crate::table_ref::TableRef;
 use crate::value::NullType;
 use crate::{DBImpl, OnConflict, Value};
 
@@ -27,10 +29,79 @@ pub trait Update<'until_build, 'post_build> {
     fn rollback_transaction(self) -> Self;
 
     /**
-    Adds a [Condition] to the update query.
+    Add a [Condition] to the where clause of the query.
+
+    If the query already has a where clause, the condition is combined with it using AND.
+
+    **Parameter**:
+    - `condition`: Condition to add.
      */
     fn where_clause(self, condition: &'until_build Condition<'post_build>) -> Self;
 
+    /**
+    Add a [Condition] to the where clause of the query, combined with
+    the existing where clause using OR.
+
+    Everything added before is wrapped in parentheses, so
+    `a AND b OR c` is built as `((a AND b) OR c)`.
+
+    **Parameter**:
+    - `condition`: Condition to add.
+     */
+    fn or_where(self, condition: &'until_build Condition<'post_build>) -> Self;
+
+    /**
+    Replace the where clause of the query, discarding all conditions added before.
+
+    **Parameter**:
+    - `condition`: The new condition.
+     */
+    fn replace_where(self, condition: &'until_build Condition<'post_build>) -> Self;
+
+    /**
+    Add conditions to the where clause, which all have to be met.
+
+    The conditions are combined with the existing where clause using AND.
+    An empty slice doesn't change the query.
+
+    **Parameter**:
+    - `conditions`: The conditions to add.
+     */
+    fn where_all(self, conditions: &'until_build [Condition<'post_build>]) -> Self;
+
+    /**
+    Add conditions to the where clause, of which any has to be met.
+
+    See [Update::where_all] for further details.
+
+    **Parameter**:
+    - `conditions`: The conditions to add.
+     */
+    fn where_any(self, conditions: &'until_build [Condition<'post_build>]) -> Self;
+
+    /**
+    Add a table to the update query, which can be referenced in the
+    [Update::where_clause] and the updated values.
+
+    Postgres and SQLite use `UPDATE a SET ... FROM b`, MySQL uses `UPDATE a JOIN b SET ...`.
+    SQLite supports this since version 3.33.0.
+
+    **Parameter**:
+    - `table_name`: Name of the table to add.
+     */
+    fn from(self, table_name: &'until_build str) -> Self;
+
+    /**
+    Limit the number of updated rows.
+
+    Not supported by Postgres and by MySQL in combination with [Update::from].
+    SQLite requires to be compiled with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`.
+
+    **Parameter**:
+    - `limit`: Maximum number of rows to update.
+     */
+    fn limit(self, limit: u64) -> Self;
+
     /**
     Add an update
 
@@ -40,6 +111,54 @@ pub trait Update<'until_build, 'post_build> {
      */
     fn add_update(self, column_name: &'until_build str, column_value: Value<'post_build>) -> Self;
 
+    /**
+    Add an update setting the column to the result of an expression.
+
+    This allows updates depending on the current value of the row,
+    e.g. atomic increments using [crate::conditional::BinaryCondition::Add].
+
+    **Parameter**:
+    - `column_name`: The column name to set the value to.
+    - `expression`: The expression to evaluate.
+     */
+    fn add_expr_update(
+        self,
+        column_name: &'until_build str,
+        expression: &'until_build Condition<'post_build>,
+    ) -> Self;
+
+    /**
+    Update many rows with different values at once.
+
+    Every row consists of the key identifying the row to update and the value to set.
+    The rows are joined as table `v` to the updated table,
+    the values are bound row by row after the ones of the other updates:
+    - Postgres: `UPDATE t SET val = v.val FROM (VALUES (?, ?), ...) AS v (id, val) WHERE t.id = v.id`
+    - SQLite: `UPDATE t SET val = v.column2 FROM (VALUES (?, ?), ...) AS v WHERE t.id = v.column1`
+    - MySQL: `UPDATE t JOIN (VALUES ROW(?, ?), ...) AS v (id, val) ON t.id = v.id SET t.val = v.val`
+
+    SQLite supports this since version 3.33.0, MySQL since version 8.0.19.
+
+    **Parameter**:
+    - `key_column`: Column identifying the rows to update.
+    - `value_column`: Column to set.
+    - `rows`: Pairs of key and new value.
+     */
+    fn bulk_update(
+        self,
+        key_column: &'until_build str,
+        value_column: &'until_build str,
+        rows: &'until_build [(Value<'post_build>, Value<'post_build>)],
+    ) -> Self;
+
+    /**
+    Returns the number of values [Update::build] binds to the query.
+
+    Can be used to allocate the parameters before building the query.
+    If the query can't be built, 0 is returned.
+     */
+    fn value_count(&self) -> usize;
+
     /**
     Builds the given statement.
 
@@ -48,17 +167,65 @@ pub trait Update<'until_build, 'post_build> {
     This function returns an error, if no update statements are given previously.
      */
     fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error>;
+
+    /**
+    Same as [Update::build], but the query is formatted over multiple lines
+    with every clause on its own line.
+
+    Meant for logging, the bound values are the same as the ones of [Update::build].
+     */
+    fn build_pretty(self) -> Result<(String, Vec<Value<'post_build>>), Error>
+    where
+        Self: Sized,
+    {
+        self.build()
+            .map(|(query, lookup)| (crate::pretty::format(&query), lookup))
+    }
+
+    /**
+    Same as [Update::build], but the statement and its values are returned as [Query].
+     */
+    fn build_query(self) -> Result<Query<'post_build>, Error>
+    where
+        Self: Sized,
+    {
+        self.build().map(Query::from)
+    }
+}
+
+/**
+The value a column is set to in an UPDATE statement.
+ */
+#[derive(Debug, Clone)]
+pub(crate) enum UpdateValue<'until_build, 'post_build> {
+    /// A value to bind
+    Value(Value<'post_build>),
+    /// An expression to evaluate
+    Expression(&'until_build Condition<'post_build>),
+}
+
+/**
+The rows of a bulk update, see [Update::bulk_update].
+ */
+#[derive(Debug, Clone)]
+pub(crate) struct BulkUpdate<'until_build, 'post_build> {
+    pub(crate) key_column: &'until_build str,
+    pub(crate) value_column: &'until_build str,
+    pub(crate) rows: &'until_build [(Value<'post_build>, Value<'post_build>)],
 }
 
 /**
 Implementation of SQLs UPDATE statement.
  */
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UpdateData<'until_build, 'post_build> {
-    pub(crate) model: &'until_build str,
+    pub(crate) model: TableRef<'until_build>,
     pub(crate) on_conflict: OnConflict,
-    pub(crate) updates: Vec<(&'until_build str, Value<'post_build>)>,
-    pub(crate) where_clause: Option<&'until_build Condition<'post_build>>,
+    pub(crate) from: Option<&'until_build str>,
+    pub(crate) limit: Option<u64>,
+    pub(crate) updates: Vec<(&'until_build str, UpdateValue<'until_build, 'post_build>)>,
+    pub(crate) bulk: Option<BulkUpdate<'until_build, 'post_build>>,
+    pub(crate) where_groups: Vec<WhereGroup<'until_build, 'post_build>>,
     pub(crate) lookup: Vec<Value<'post_build>>,
 }
 
@@ -67,7 +234,7 @@ Implementation of the [Update] trait for the different implementations.
 
 Should only be constructed via [DBImpl::update].
  */
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum UpdateImpl<'until_build, 'post_build> {
     /**
     SQLite representation of the UPDATE operation.
@@ -104,11 +271,89 @@ impl<'until_build, 'post_build> Update<'until_build, 'post_build>
     fn where_clause(mut self, condition: &'until_build Condition<'post_build>) -> Self {
         match self {
             #[cfg(feature = "sqlite")]
-            UpdateImpl::SQLite(ref mut d) => d.where_clause = Some(condition),
+            UpdateImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::And(condition)),
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::And(condition)),
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::And(condition)),
+        };
+        self
+    }
+
+    fn or_where(mut self, condition: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::Or(condition)),
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::Or(condition)),
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::Or(condition)),
+        };
+        self
+    }
+
+    fn replace_where(mut self, condition: &'until_build Condition<'post_build>) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.where_groups = vec![WhereGroup::And(condition)],
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.where_groups = vec![WhereGroup::And(condition)],
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.where_groups = vec![WhereGroup::And(condition)],
+        };
+        self
+    }
+
+    fn where_all(mut self, conditions: &'until_build [Condition<'post_build>]) -> Self {
+        if conditions.is_empty() {
+            return self;
+        }
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::All(conditions)),
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::All(conditions)),
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::All(conditions)),
+        };
+        self
+    }
+
+    fn where_any(mut self, conditions: &'until_build [Condition<'post_build>]) -> Self {
+        if conditions.is_empty() {
+            return self;
+        }
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.where_groups.push(WhereGroup::Any(conditions)),
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.where_groups.push(WhereGroup::Any(conditions)),
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.where_groups.push(WhereGroup::Any(conditions)),
+        };
+        self
+    }
+
+    fn from(mut self, table_name: &'until_build str) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.from = Some(table_name),
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.from = Some(table_name),
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.from = Some(table_name),
+        };
+        self
+    }
+
+    fn limit(mut self, limit: u64) -> Self {
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.limit = Some(limit),
             #[cfg(feature = "mysql")]
-            UpdateImpl::MySQL(ref mut d) => d.where_clause = Some(condition),
+            UpdateImpl::MySQL(ref mut d) => d.limit = Some(limit),
             #[cfg(feature = "postgres")]
-            UpdateImpl::Postgres(ref mut d) => d.where_clause = Some(condition),
+            UpdateImpl::Postgres(ref mut d) => d.limit = Some(limit),
         };
         self
     }
@@ -118,26 +363,66 @@ impl<'until_build, 'post_build> Update<'until_build, 'post_build>
         column_name: &'until_build str,
         column_value: Value<'post_build>,
     ) -> Self {
+        let update = (column_name, UpdateValue::Value(column_value));
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.updates.push(update),
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.updates.push(update),
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.updates.push(update),
+        };
+        self
+    }
+
+    fn add_expr_update(
+        mut self,
+        column_name: &'until_build str,
+        expression: &'until_build Condition<'post_build>,
+    ) -> Self {
+        let update = (column_name, UpdateValue::Expression(expression));
         match self {
             #[cfg(feature = "sqlite")]
-            UpdateImpl::SQLite(ref mut d) => d.updates.push((column_name, column_value)),
+            UpdateImpl::SQLite(ref mut d) => d.updates.push(update),
             #[cfg(feature = "mysql")]
-            UpdateImpl::MySQL(ref mut d) => d.updates.push((column_name, column_value)),
+            UpdateImpl::MySQL(ref mut d) => d.updates.push(update),
             #[cfg(feature = "postgres")]
-            UpdateImpl::Postgres(ref mut d) => d.updates.push((column_name, column_value)),
+            UpdateImpl::Postgres(ref mut d) => d.updates.push(update),
         };
         self
     }
 
+    fn bulk_update(
+        mut self,
+        key_column: &'until_build str,
+        value_column: &'until_build str,
+        rows: &'until_build [(Value<'post_build>, Value<'post_build>)],
+    ) -> Self {
+        let bulk = Some(BulkUpdate {
+            key_column,
+            value_column,
+            rows,
+        });
+        match self {
+            #[cfg(feature = "sqlite")]
+            UpdateImpl::SQLite(ref mut d) => d.bulk = bulk,
+            #[cfg(feature = "mysql")]
+            UpdateImpl::MySQL(ref mut d) => d.bulk = bulk,
+            #[cfg(feature = "postgres")]
+            UpdateImpl::Postgres(ref mut d) => d.bulk = bulk,
+        };
+        self
+    }
+
+    fn value_count(&self) -> usize {
+        self.clone().build().map_or(0, |(_, lookup)| lookup.len())
+    }
+
     fn build(self) -> Result<(String, Vec<Value<'post_build>>), Error> {
         match self {
             #[cfg(feature = "sqlite")]
             UpdateImpl::SQLite(mut d) => {
-                if d.updates.is_empty() {
-                    return Err(Error::SQLBuildError(String::from(
-                        "There must be at least one update in an UPDATE statement",
-                    )));
-                }
+                check_updates(&d)?;
                 let mut s = format!(
                     "UPDATE {}{} SET ",
                     match d.on_conflict {
@@ -147,28 +432,70 @@ impl<'until_build, 'post_build> Update<'until_build, 'post_build>
                     d.model,
                 );
 
-                let update_index = d.updates.len() - 1;
+                let update_count = d.updates.len();
                 for (idx, (name, value)) in d.updates.into_iter().enumerate() {
-                    if let Value::Choice(c) = value {
-                        write!(s, "{name} = {}", sqlite::fmt(c)).unwrap();
-                    } else if let Value::Null(NullType::Choice) = value {
-                        write!(s, "{name} = NULL").unwrap();
-                    } else {
-                        write!(s, "{name} = ?").unwrap();
-                        d.lookup.push(value);
+                    if idx > 0 {
+                        write!(s, ", ").unwrap();
                     }
-                    if idx != update_index {
+                    match value {
+                        UpdateValue::Value(Value::Choice(c)) => {
+                            write!(s, "{name} = {}", sqlite::fmt(c)).unwrap()
+                        }
+                        UpdateValue::Value(Value::Null(NullType::Choice)) => {
+                            write!(s, "{name} = NULL").unwrap()
+                        }
+                        UpdateValue::Value(Value::Default) => {
+                            return Err(Error::UnsupportedFeature(String::from(
+                                "SQLite doesn't support DEFAULT as value",
+                            )))
+                        }
+                        UpdateValue::Value(Value::NextVal(_)) => {
+                            return Err(Error::UnsupportedFeature(String::from(
+                                "SQLite doesn't support sequences",
+                            )))
+                        }
+                        UpdateValue::Value(value) => {
+                            write!(s, "{name} = ?").unwrap();
+                            d.lookup.push(value);
+                        }
+                        UpdateValue::Expression(expression) => {
+                            expression.check_sqlite()?;
+                            write!(
+                                s,
+                                "{name} = {}",
+                                expression.build(DBImpl::SQLite, &mut d.lookup)?
+                            )
+                            .unwrap()
+                        }
+                    }
+                }
+
+                if let Some(bulk) = &d.bulk {
+                    if update_count > 0 {
                         write!(s, ", ").unwrap();
                     }
+                    write!(s, "{} = v.column2", bulk.value_column).unwrap();
                 }
 
-                if let Some(condition) = d.where_clause {
-                    write!(
-                        s,
-                        " WHERE {}",
-                        condition.build(DBImpl::SQLite, &mut d.lookup)
-                    )
-                    .unwrap();
+                let from_keyword = if d.from.is_some() { ", " } else { " FROM " };
+                if let Some(table_name) = d.from {
+                    write!(s, " FROM {table_name}").unwrap();
+                }
+                let bulk_condition = match &d.bulk {
+                    Some(bulk) => {
+                        write!(s, "{from_keyword}").unwrap();
+                        write_bulk_values(&mut s, DBImpl::SQLite, bulk.rows, &mut d.lookup)?;
+                        write!(s, " AS v").unwrap();
+                        Some(format!("({}.{} = v.column1)", d.model, bulk.key_column))
+                    }
+                    None => None,
+                };
+
+                let condition = build_where(&d.where_groups, DBImpl::SQLite, &mut d.lookup)?;
+                write_where(&mut s, [bulk_condition, condition]);
+
+                if let Some(limit) = d.limit {
+                    write!(s, " LIMIT {limit}").unwrap();
                 }
 
                 write!(s, ";").unwrap();
@@ -177,80 +504,170 @@ impl<'until_build, 'post_build> Update<'until_build, 'post_build>
             }
             #[cfg(feature = "mysql")]
             UpdateImpl::MySQL(mut d) => {
-                if d.updates.is_empty() {
-                    return Err(Error::SQLBuildError(String::from(
-                        "There must be at least one update in an UPDATE statement",
+                check_updates(&d)?;
+                if (d.from.is_some() || d.bulk.is_some()) && d.limit.is_some() {
+                    return Err(Error::UnsupportedFeature(String::from(
+                        "MySQL doesn't support LIMIT when updating multiple tables",
                     )));
                 }
                 let mut s = format!(
-                    "UPDATE {}{} SET ",
+                    "UPDATE {}{} ",
                     match d.on_conflict {
                         OnConflict::ABORT => "OR ABORT ",
                         OnConflict::ROLLBACK => "OR ROLLBACK ",
                     },
                     d.model,
                 );
+                if let Some(table_name) = d.from {
+                    write!(s, "JOIN {table_name} ").unwrap();
+                }
+                if let Some(bulk) = &d.bulk {
+                    write!(s, "JOIN ").unwrap();
+                    write_bulk_values(&mut s, DBImpl::MySQL, bulk.rows, &mut d.lookup)?;
+                    write!(
+                        s,
+                        " AS v (`{key}`, `{value}`) ON {}.`{key}` = v.`{key}` ",
+                        d.model,
+                        key = bulk.key_column,
+                        value = bulk.value_column
+                    )
+                    .unwrap();
+                }
+                write!(s, "SET ").unwrap();
 
-                let update_index = d.updates.len() - 1;
+                let update_count = d.updates.len();
                 for (idx, (name, value)) in d.updates.into_iter().enumerate() {
-                    if let Value::Choice(c) = value {
-                        write!(s, "`{name}` = {}", mysql::fmt(c)).unwrap();
-                    } else if let Value::Null(NullType::Choice) = value {
-                        write!(s, "`{name}` = NULL").unwrap();
-                    } else {
-                        write!(s, "`{name}` = ?").unwrap();
-                        d.lookup.push(value);
-                    }
-                    if idx != update_index {
+                    if idx > 0 {
                         write!(s, ", ").unwrap();
                     }
+                    match value {
+                        UpdateValue::Value(Value::Choice(c)) => {
+                            write!(s, "`{name}` = {}", mysql::fmt(c)).unwrap()
+                        }
+                        UpdateValue::Value(Value::Null(NullType::Choice)) => {
+                            write!(s, "`{name}` = NULL").unwrap()
+                        }
+                        UpdateValue::Value(Value::Default) => {
+                            write!(s, "`{name}` = DEFAULT").unwrap()
+                        }
+                        UpdateValue::Value(Value::NextVal(_)) => {
+                            return Err(Error::UnsupportedFeature(String::from(
+                                "MySQL doesn't support sequences",
+                            )))
+                        }
+                        UpdateValue::Value(value) => {
+                            write!(s, "`{name}` = ?").unwrap();
+                            d.lookup.push(value);
+                        }
+                        UpdateValue::Expression(expression) => write!(
+                            s,
+                            "`{name}` = {}",
+                            expression.build(DBImpl::MySQL, &mut d.lookup)?
+                        )
+                        .unwrap(),
+                    }
                 }
 
-                if let Some(condition) = d.where_clause {
+                if let Some(bulk) = &d.bulk {
+                    if update_count > 0 {
+                        write!(s, ", ").unwrap();
+                    }
                     write!(
                         s,
-                        " WHERE {}",
-                        condition.build(DBImpl::MySQL, &mut d.lookup)
+                        "{}.`{value}` = v.`{value}`",
+                        d.model,
+                        value = bulk.value_column
                     )
                     .unwrap();
                 }
 
+                if let Some(condition) = build_where(&d.where_groups, DBImpl::MySQL, &mut d.lookup)?
+                {
+                    write!(s, " WHERE {condition}").unwrap();
+                }
+
+                if let Some(limit) = d.limit {
+                    write!(s, " LIMIT {limit}").unwrap();
+                }
+
                 write!(s, ";").unwrap();
 
                 Ok((s, d.lookup))
             }
             #[cfg(feature = "postgres")]
             UpdateImpl::Postgres(mut d) => {
-                if d.updates.is_empty() {
-                    return Err(Error::SQLBuildError(String::from(
-                        "There must be at least one update in an UPDATE statement",
+                check_updates(&d)?;
+                if d.limit.is_some() {
+                    return Err(Error::UnsupportedFeature(String::from(
+                        "Postgres doesn't support LIMIT in UPDATE statements",
                     )));
                 }
-                let mut s = format!("UPDATE \"{}\" SET ", d.model);
+                let mut s = format!("UPDATE {} SET ", d.model.quoted(DBImpl::Postgres));
 
-                let update_index = d.updates.len() - 1;
+                let update_count = d.updates.len();
                 for (idx, (name, value)) in d.updates.into_iter().enumerate() {
-                    if let Value::Choice(c) = value {
-                        write!(s, "\"{name}\" = {}", postgres::fmt(c)).unwrap();
-                    } else if let Value::Null(NullType::Choice) = value {
-                        write!(s, "\"{name}\" = NULL").unwrap();
-                    } else {
-                        d.lookup.push(value);
-                        write!(s, "\"{name}\" = ${}", d.lookup.len()).unwrap();
+                    if idx > 0 {
+                        write!(s, ", ").unwrap();
+                    }
+                    match value {
+                        UpdateValue::Value(Value::Choice(c)) => {
+                            write!(s, "\"{name}\" = {}", postgres::fmt(c)).unwrap()
+                        }
+                        UpdateValue::Value(Value::Null(NullType::Choice)) => {
+                            write!(s, "\"{name}\" = NULL").unwrap()
+                        }
+                        UpdateValue::Value(Value::Default) => {
+                            write!(s, "\"{name}\" = DEFAULT").unwrap()
+                        }
+                        UpdateValue::Value(Value::NextVal(seq)) => {
+                            write!(s, "\"{name}\" = {}", postgres::nextval(seq)).unwrap()
+                        }
+                        UpdateValue::Value(value) => {
+                            d.lookup.push(value);
+                            write!(s, "\"{name}\" = ${}", d.lookup.len()).unwrap();
+                        }
+                        UpdateValue::Expression(expression) => write!(
+                            s,
+                            "\"{name}\" = {}",
+                            expression.build(DBImpl::Postgres, &mut d.lookup)?
+                        )
+                        .unwrap(),
                     }
-                    if idx != update_index {
+                }
+
+                if let Some(bulk) = &d.bulk {
+                    if update_count > 0 {
                         write!(s, ", ").unwrap();
                     }
+                    write!(s, "\"{value}\" = v.\"{value}\"", value = bulk.value_column).unwrap();
                 }
 
-                if let Some(condition) = d.where_clause {
-                    write!(
-                        s,
-                        " WHERE {}",
-                        condition.build(DBImpl::Postgres, &mut d.lookup)
-                    )
-                    .unwrap();
+                let from_keyword = if d.from.is_some() { ", " } else { " FROM " };
+                if let Some(table_name) = d.from {
+                    write!(s, " FROM \"{table_name}\"").unwrap();
                 }
+                let bulk_condition = match &d.bulk {
+                    Some(bulk) => {
+                        write!(s, "{from_keyword}").unwrap();
+                        write_bulk_values(&mut s, DBImpl::Postgres, bulk.rows, &mut d.lookup)?;
+                        write!(
+                            s,
+                            " AS v (\"{key}\", \"{value}\")",
+                            key = bulk.key_column,
+                            value = bulk.value_column
+                        )
+                        .unwrap();
+                        Some(format!(
+                            "({}.\"{key}\" = v.\"{key}\")",
+                            d.model.quoted(DBImpl::Postgres),
+                            key = bulk.key_column
+                        ))
+                    }
+                    None => None,
+                };
+
+                let condition = build_where(&d.where_groups, DBImpl::Postgres, &mut d.lookup)?;
+                write_where(&mut s, [bulk_condition, condition]);
 
                 write!(s, ";").unwrap();
 
@@ -259,3 +676,432 @@ impl<'until_build, 'post_build> Update<'until_build, 'post_build>
         }
     }
 }
+
+/**
+Check that the update sets at least one column and that a bulk update has rows.
+ */
+fn check_updates(d: &UpdateData) -> Result<(), Error> {
+    match &d.bulk {
+        Some(bulk) if bulk.rows.is_empty() => Err(Error::SQLBuildError(String::from(
+            "There must be at least one row in a bulk update",
+        ))),
+        None if d.updates.is_empty() => Err(Error::SQLBuildError(String::from(
+            "There must be at least one update in an UPDATE statement",
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/**
+Write the rows of a bulk update as VALUES list, e.g. `(VALUES (?, ?), (?, ?))`.
+
+The values are bound row by row.
+ */
+fn write_bulk_values<'post_build>(
+    s: &mut String,
+    dialect: DBImpl,
+    rows: &[(Value<'post_build>, Value<'post_build>)],
+    lookup: &mut Vec<Value<'post_build>>,
+) -> Result<(), Error> {
+    let row_keyword = match dialect {
+        #[cfg(feature = "mysql")]
+        DBImpl::MySQL => "ROW",
+        #[allow(unreachable_patterns)]
+        _ => "",
+    };
+
+    write!(s, "(VALUES ").unwrap();
+    for (idx, (key, value)) in rows.iter().enumerate() {
+        if idx > 0 {
+            write!(s, ", ").unwrap();
+        }
+        let (key, value) = (Condition::Value(*key), Condition::Value(*value));
+        #[cfg(feature = "sqlite")]
+        if matches!(dialect, DBImpl::SQLite) {
+            key.check_sqlite()?;
+            value.check_sqlite()?;
+        }
+        write!(
+            s,
+            "{row_keyword}({}, {})",
+            key.build(dialect, lookup)?,
+            value.build(dialect, lookup)?
+        )
+        .unwrap();
+    }
+    write!(s, ")").unwrap();
+    Ok(())
+}
+
+/**
+Write the WHERE clause AND-ing the given conditions, if there are any.
+ */
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn write_where(s: &mut String, conditions: [Option<String>; 2]) {
+    let conditions: Vec<_> = conditions.into_iter().flatten().collect();
+    match conditions.len() {
+        0 => {}
+        1 => write!(s, " WHERE {}", conditions[0]).unwrap(),
+        _ => write!(s, " WHERE ({})", conditions.join(" AND ")).unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conditional::{BinaryCondition, Condition};
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    use crate::error::Error;
+    use crate::update::Update;
+    use crate::value::Value;
+    use crate::DBImpl;
+
+    fn arithmetic<'a>(column: &'a str, value: Value<'a>) -> [Condition<'a>; 2] {
+        [
+            Condition::Value(Value::Column {
+                table_name: None,
+                column_name: column,
+            }),
+            Condition::Value(value),
+        ]
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn expr_update_sqlite() {
+        let count = Condition::BinaryCondition(BinaryCondition::Add(Box::new(arithmetic(
+            "count",
+            Value::I64(1),
+        ))));
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new(arithmetic(
+            "id",
+            Value::I64(5),
+        ))));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .update("counter")
+                .add_update("name", Value::String("foo"))
+                .add_expr_update("count", &count)
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT counter SET name = ?, count = (count + ?) WHERE (id = ?);"
+                    .to_string(),
+                vec![Value::String("foo"), Value::I64(1), Value::I64(5)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn expr_update_mysql() {
+        let score = Condition::BinaryCondition(BinaryCondition::Multiply(Box::new(arithmetic(
+            "score",
+            Value::I64(2),
+        ))));
+
+        assert_eq!(
+            DBImpl::MySQL
+                .update("counter")
+                .add_expr_update("score", &score)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT counter SET `score` = (score * ?);".to_string(),
+                vec![Value::I64(2)]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn expr_update_postgres() {
+        let count = Condition::BinaryCondition(BinaryCondition::Add(Box::new(arithmetic(
+            "count",
+            Value::I64(1),
+        ))));
+        let score = Condition::BinaryCondition(BinaryCondition::Multiply(Box::new(arithmetic(
+            "score",
+            Value::I64(2),
+        ))));
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new(arithmetic(
+            "id",
+            Value::I64(5),
+        ))));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .update("counter")
+                .add_update("name", Value::String("foo"))
+                .add_expr_update("count", &count)
+                .add_expr_update("score", &score)
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE \"counter\" SET \"name\" = $1, \"count\" = (count + $2), \"score\" = (score * $3) WHERE (id = $4);".to_string(),
+                vec![Value::String("foo"), Value::I64(1), Value::I64(2), Value::I64(5)]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn from_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("user"),
+                column_name: "id",
+            }),
+            Condition::Value(Value::Column {
+                table_name: Some("ban"),
+                column_name: "user_id",
+            }),
+        ])));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .update("user")
+                .from("ban")
+                .add_update("active", Value::Bool(false))
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT user SET active = ? FROM ban WHERE (user.id = ban.user_id);"
+                    .to_string(),
+                vec![Value::Bool(false)]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn from_mysql() {
+        let condition = Condition::Conjunction(vec![
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("user"),
+                    column_name: "id",
+                }),
+                Condition::Value(Value::Column {
+                    table_name: Some("ban"),
+                    column_name: "user_id",
+                }),
+            ]))),
+            Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+                Condition::Value(Value::Column {
+                    table_name: Some("ban"),
+                    column_name: "reason",
+                }),
+                Condition::Value(Value::String("spam")),
+            ]))),
+        ]);
+        let query = DBImpl::MySQL
+            .update("user")
+            .from("ban")
+            .add_update("active", Value::Bool(false))
+            .where_clause(&condition);
+
+        assert_eq!(query.value_count(), 2);
+        assert_eq!(
+            query.build().unwrap(),
+            (
+                "UPDATE OR ABORT user JOIN ban SET `active` = ? WHERE ((user.id = ban.user_id) AND (ban.reason = ?));".to_string(),
+                vec![Value::Bool(false), Value::String("spam")]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn from_postgres() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Column {
+                table_name: Some("ban"),
+                column_name: "reason",
+            }),
+            Condition::Value(Value::String("spam")),
+        ])));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .update("user")
+                .from("ban")
+                .add_update("active", Value::Bool(false))
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE \"user\" SET \"active\" = $1 FROM \"ban\" WHERE (\"ban\".reason = $2);"
+                    .to_string(),
+                vec![Value::Bool(false), Value::String("spam")]
+            )
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn limit_sqlite() {
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new(arithmetic(
+            "level",
+            Value::String("debug"),
+        ))));
+
+        assert_eq!(
+            DBImpl::SQLite
+                .update("log")
+                .add_update("archived", Value::Bool(true))
+                .where_clause(&condition)
+                .limit(100)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT log SET archived = ? WHERE (level = ?) LIMIT 100;".to_string(),
+                vec![Value::Bool(true), Value::String("debug")]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn limit_mysql() {
+        assert_eq!(
+            DBImpl::MySQL
+                .update("log")
+                .add_update("archived", Value::Bool(true))
+                .limit(10)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT log SET `archived` = ? LIMIT 10;".to_string(),
+                vec![Value::Bool(true)]
+            )
+        );
+        assert!(matches!(
+            DBImpl::MySQL
+                .update("user")
+                .from("ban")
+                .add_update("active", Value::Bool(false))
+                .limit(100)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn limit_postgres() {
+        assert!(matches!(
+            DBImpl::Postgres
+                .update("log")
+                .add_update("archived", Value::Bool(true))
+                .limit(100)
+                .build(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn bulk_update_sqlite() {
+        let rows = [
+            (Value::I64(1), Value::String("first")),
+            (Value::I64(2), Value::String("second")),
+        ];
+
+        assert_eq!(
+            DBImpl::SQLite
+                .update("post")
+                .add_update("edited", Value::Bool(true))
+                .bulk_update("id", "title", &rows)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT post SET edited = ?, title = v.column2 FROM (VALUES (?, ?), (?, ?)) AS v WHERE (post.id = v.column1);".to_string(),
+                vec![
+                    Value::Bool(true),
+                    Value::I64(1),
+                    Value::String("first"),
+                    Value::I64(2),
+                    Value::String("second"),
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn bulk_update_mysql() {
+        let rows = [
+            (Value::I64(1), Value::String("first")),
+            (Value::I64(2), Value::String("second")),
+        ];
+        let condition = Condition::Value(Value::Ident("active"));
+
+        // The joined VALUES come before SET, so their values are bound first
+        assert_eq!(
+            DBImpl::MySQL
+                .update("post")
+                .add_update("edited", Value::Bool(true))
+                .bulk_update("id", "title", &rows)
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE OR ABORT post JOIN (VALUES ROW(?, ?), ROW(?, ?)) AS v (`id`, `title`) ON post.`id` = v.`id` SET `edited` = ?, post.`title` = v.`title` WHERE active;".to_string(),
+                vec![
+                    Value::I64(1),
+                    Value::String("first"),
+                    Value::I64(2),
+                    Value::String("second"),
+                    Value::Bool(true),
+                ]
+            )
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn bulk_update_postgres() {
+        let rows = [(Value::I64(1), Value::I64(10))];
+        let condition = Condition::Value(Value::Ident("active"));
+
+        assert_eq!(
+            DBImpl::Postgres
+                .update("counter")
+                .bulk_update("id", "count", &rows)
+                .where_clause(&condition)
+                .build()
+                .unwrap(),
+            (
+                "UPDATE \"counter\" SET \"count\" = v.\"count\" FROM (VALUES ($1, $2)) AS v (\"id\", \"count\") WHERE ((\"counter\".\"id\" = v.\"id\") AND active);".to_string(),
+                vec![Value::I64(1), Value::I64(10)]
+            )
+        );
+        assert!(matches!(
+            DBImpl::Postgres
+                .update("counter")
+                .bulk_update("id", "count", &[])
+                .build(),
+            Err(Error::SQLBuildError(_))
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn invalid_subquery_in_expression_postgres() {
+        let subquery = DBImpl::Postgres.select(&[], "post", &[], &[]);
+        let expression = Condition::Subquery(Box::new(subquery));
+        assert!(matches!(
+            DBImpl::Postgres
+                .update("user")
+                .add_expr_update("posts", &expression)
+                .build(),
+            Err(Error::EmptyColumnList {
+                statement: "SELECT"
+            })
+        ));
+    }
+}
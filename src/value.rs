@@ -1,7 +1,18 @@
+#[cfg(feature = "postgres-only")]
+use chrono::Duration;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
-use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 use uuid::Uuid;
 
+#[cfg(feature = "mysql")]
+use crate::db_specific::mysql;
+#[cfg(feature = "postgres")]
+use crate::db_specific::postgres;
+#[cfg(feature = "sqlite")]
+use crate::db_specific::sqlite;
+use crate::error::Error;
+use crate::DBImpl;
+
 /// This enum represents a [Null](Value::Null)'s type
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum NullType {
@@ -47,6 +58,9 @@ pub enum NullType {
     UuidSimple,
     /// serde_json's Value representation
     JsonValue,
+    /// Decimal representation
+    #[cfg(feature = "decimal")]
+    Decimal,
     /// Mac address representation
     #[cfg(feature = "postgres-only")]
     MacAddress,
@@ -56,6 +70,9 @@ pub enum NullType {
     /// Bit vec representation
     #[cfg(feature = "postgres-only")]
     BitVec,
+    /// Chrono duration representation
+    #[cfg(feature = "postgres-only")]
+    ChronoDuration,
 }
 
 /**
@@ -77,6 +94,16 @@ pub enum Value<'a> {
         /// Name of the column
         column_name: &'a str,
     },
+    /// The DEFAULT keyword, sets a column to its default value.
+    ///
+    /// Only valid as value of an INSERT or UPDATE, it is never bound as parameter.
+    /// Not supported by SQLite.
+    Default,
+    /// The next value of the sequence with the given name, `nextval('name')`.
+    ///
+    /// It is never bound as parameter.
+    /// Only supported by Postgres.
+    NextVal(&'a str),
     /// Representation of choices
     Choice(&'a str),
     /// String representation
@@ -119,6 +146,9 @@ pub enum Value<'a> {
     UuidSimple(Uuid),
     /// serde_json's Value representation
     JsonValue(&'a serde_json::Value),
+    /// rust_decimal's Decimal representation
+    #[cfg(feature = "decimal")]
+    Decimal(rust_decimal::Decimal),
     /// Mac address representation
     #[cfg(feature = "postgres-only")]
     MacAddress(mac_address::MacAddress),
@@ -128,4 +158,376 @@ pub enum Value<'a> {
     /// Bit vec representation
     #[cfg(feature = "postgres-only")]
     BitVec(&'a bit_vec::BitVec),
+    /// Array representation, bound as a single parameter
+    #[cfg(feature = "postgres-only")]
+    Array(&'a [Value<'a>]),
+    /// chrono's Duration representation, bound as interval
+    #[cfg(feature = "postgres-only")]
+    ChronoDuration(Duration),
+}
+
+impl Value<'_> {
+    /**
+    Write the value as SQL literal of the given dialect, e.g. for statements which
+    don't support bind parameters like column defaults or CHECK constraints.
+
+    Strings, dates, times and JSON are written as quoted strings with embedded quotes escaped,
+    binary values are hex encoded and booleans are written with [bool_literal].
+    [Value::Uuid] is written as blob for SQLite and MySQL, like it's bound.
+    Returns an error for values which have no literal representation,
+    e.g. identifiers, [Value::Default] or non-finite floats.
+
+    **Parameter**:
+    - `dialect`: The dialect to write the literal for.
+     */
+    pub fn to_sql_literal(&self, dialect: DBImpl) -> Result<String, Error> {
+        Ok(match self {
+            Value::Null(_) => "NULL".to_string(),
+            Value::String(x) | Value::Choice(x) => quote(x, dialect),
+            Value::I64(x) => x.to_string(),
+            Value::I32(x) => x.to_string(),
+            Value::I16(x) => x.to_string(),
+            Value::F64(x) if x.is_finite() => x.to_string(),
+            Value::F32(x) if x.is_finite() => x.to_string(),
+            Value::Bool(x) => bool_literal(*x, dialect).to_string(),
+            Value::Binary(x) => hex(x, dialect),
+            Value::ChronoNaiveTime(x) => quote(&x.to_string(), dialect),
+            Value::ChronoNaiveDate(x) => quote(&x.to_string(), dialect),
+            Value::ChronoNaiveDateTime(x) => quote(&x.to_string(), dialect),
+            Value::ChronoDateTime(x) => utc(&x.naive_utc().to_string(), dialect),
+            Value::TimeDate(x) => quote(&x.to_string(), dialect),
+            Value::TimeTime(x) => quote(&format_time(x), dialect),
+            Value::TimePrimitiveDateTime(x) => {
+                quote(&format!("{} {}", x.date(), format_time(&x.time())), dialect)
+            }
+            Value::TimeOffsetDateTime(x) => {
+                let x = x.to_offset(UtcOffset::UTC);
+                utc(&format!("{} {}", x.date(), format_time(&x.time())), dialect)
+            }
+            Value::Uuid(x) => match dialect {
+                #[cfg(feature = "postgres")]
+                DBImpl::Postgres => quote(&x.hyphenated().to_string(), dialect),
+                #[allow(unreachable_patterns)]
+                _ => hex(x.as_bytes(), dialect),
+            },
+            Value::UuidHyphenated(x) => quote(&x.hyphenated().to_string(), dialect),
+            Value::UuidSimple(x) => quote(&x.simple().to_string(), dialect),
+            Value::JsonValue(x) => quote(&x.to_string(), dialect),
+            #[cfg(feature = "decimal")]
+            Value::Decimal(x) => x.to_string(),
+            _ => {
+                return Err(Error::SQLBuildError(format!(
+                    "{self:?} can't be used as literal"
+                )))
+            }
+        })
+    }
+}
+
+/**
+Write a boolean as SQL literal of the given dialect.
+
+SQLite has no boolean type and MySQL's `BOOL` is an alias for `TINYINT(1)`,
+so both get `1` and `0`. Postgres gets `TRUE` and `FALSE`,
+as its `boolean` doesn't accept integers without a cast.
+ */
+pub(crate) fn bool_literal(value: bool, dialect: DBImpl) -> &'static str {
+    match dialect {
+        #[cfg(feature = "postgres")]
+        DBImpl::Postgres => {
+            if value {
+                "TRUE"
+            } else {
+                "FALSE"
+            }
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            if value {
+                "1"
+            } else {
+                "0"
+            }
+        }
+    }
+}
+
+/// Quote and escape a string for the dialect
+fn quote(x: &str, dialect: DBImpl) -> String {
+    match dialect {
+        #[cfg(feature = "sqlite")]
+        DBImpl::SQLite => sqlite::fmt(x),
+        #[cfg(feature = "mysql")]
+        DBImpl::MySQL => mysql::fmt(x),
+        #[cfg(feature = "postgres")]
+        DBImpl::Postgres => postgres::fmt(x),
+    }
+}
+
+/// Hex encode binary data as blob literal
+fn hex(x: &[u8], dialect: DBImpl) -> String {
+    let hex: String = x.iter().map(|byte| format!("{byte:02x}")).collect();
+    match dialect {
+        #[cfg(feature = "postgres")]
+        DBImpl::Postgres => format!("'\\x{hex}'"),
+        #[allow(unreachable_patterns)]
+        _ => format!("X'{hex}'"),
+    }
+}
+
+/// Format a time as `hh:mm:ss` with optional fraction,
+/// as [Time]'s Display implementation doesn't pad the hour and always writes the fraction
+fn format_time(x: &Time) -> String {
+    let mut s = format!("{:02}:{:02}:{:02}", x.hour(), x.minute(), x.second());
+    if x.nanosecond() != 0 {
+        let fraction = format!("{:09}", x.nanosecond());
+        s.push('.');
+        s.push_str(fraction.trim_end_matches('0'));
+    }
+    s
+}
+
+/// Quote a UTC date time, MySQL doesn't accept an offset
+fn utc(x: &str, dialect: DBImpl) -> String {
+    match dialect {
+        #[cfg(feature = "mysql")]
+        DBImpl::MySQL => quote(x, dialect),
+        #[allow(unreachable_patterns)]
+        _ => quote(&format!("{x}+00:00"), dialect),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    #[cfg(feature = "sqlite")]
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+    use uuid::Uuid;
+
+    use crate::conditional::{BinaryCondition, Condition};
+    use crate::insert::Insert;
+    use crate::query::Query;
+    use crate::select::Select;
+    use crate::value::{NullType, Value};
+    use crate::DBImpl;
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn literals_sqlite() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let leap_day = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+        let nine_five = Time::from_hms(9, 5, 0).unwrap();
+        let date_time = Utc.from_utc_datetime(&date.and_hms_opt(12, 30, 0).unwrap());
+        let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+        let literal = |value: Value| value.to_sql_literal(DBImpl::SQLite).unwrap();
+
+        assert_eq!(literal(Value::Null(NullType::String)), "NULL");
+        assert_eq!(literal(Value::String("it's \\ here")), "'it''s \\ here'");
+        assert_eq!(literal(Value::I64(-7)), "-7");
+        assert_eq!(literal(Value::F64(1.5)), "1.5");
+        assert_eq!(literal(Value::Bool(true)), "1");
+        assert_eq!(literal(Value::Bool(false)), "0");
+        assert_eq!(literal(Value::Binary(&[0, 171, 255])), "X'00abff'");
+        assert_eq!(literal(Value::ChronoNaiveDate(date)), "'2024-02-29'");
+        assert_eq!(
+            literal(Value::ChronoDateTime(date_time)),
+            "'2024-02-29 12:30:00+00:00'"
+        );
+        assert_eq!(literal(Value::TimeTime(nine_five)), "'09:05:00'");
+        assert_eq!(
+            literal(Value::TimeTime(Time::from_hms_milli(9, 5, 0, 250).unwrap())),
+            "'09:05:00.25'"
+        );
+        assert_eq!(
+            literal(Value::TimePrimitiveDateTime(PrimitiveDateTime::new(
+                leap_day, nine_five
+            ))),
+            "'2024-02-29 09:05:00'"
+        );
+        assert_eq!(
+            literal(Value::TimeOffsetDateTime(
+                PrimitiveDateTime::new(leap_day, nine_five)
+                    .assume_offset(UtcOffset::from_hms(1, 0, 0).unwrap())
+            )),
+            "'2024-02-29 08:05:00+00:00'"
+        );
+        assert_eq!(
+            literal(Value::Uuid(uuid)),
+            "X'0123456789abcdef0123456789abcdef'"
+        );
+        assert_eq!(
+            literal(Value::UuidSimple(uuid)),
+            "'0123456789abcdef0123456789abcdef'"
+        );
+
+        assert!(Value::Ident("id").to_sql_literal(DBImpl::SQLite).is_err());
+        assert!(Value::F64(f64::NAN).to_sql_literal(DBImpl::SQLite).is_err());
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn literals_mysql() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let date_time = Utc.from_utc_datetime(&date.and_hms_opt(12, 30, 0).unwrap());
+        let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+        let literal = |value: Value| value.to_sql_literal(DBImpl::MySQL).unwrap();
+
+        assert_eq!(literal(Value::Null(NullType::String)), "NULL");
+        assert_eq!(literal(Value::String("it's \\ here")), "'it''s \\\\ here'");
+        assert_eq!(literal(Value::I64(-7)), "-7");
+        assert_eq!(literal(Value::F64(1.5)), "1.5");
+        assert_eq!(literal(Value::Bool(true)), "1");
+        assert_eq!(literal(Value::Bool(false)), "0");
+        assert_eq!(literal(Value::Binary(&[0, 171, 255])), "X'00abff'");
+        assert_eq!(literal(Value::ChronoNaiveDate(date)), "'2024-02-29'");
+        assert_eq!(
+            literal(Value::ChronoDateTime(date_time)),
+            "'2024-02-29 12:30:00'"
+        );
+        assert_eq!(
+            literal(Value::Uuid(uuid)),
+            "X'0123456789abcdef0123456789abcdef'"
+        );
+        assert_eq!(
+            literal(Value::UuidSimple(uuid)),
+            "'0123456789abcdef0123456789abcdef'"
+        );
+
+        assert!(Value::Ident("id").to_sql_literal(DBImpl::MySQL).is_err());
+        assert!(Value::F64(f64::NAN).to_sql_literal(DBImpl::MySQL).is_err());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn literals_postgres() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let date_time = Utc.from_utc_datetime(&date.and_hms_opt(12, 30, 0).unwrap());
+        let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+        let literal = |value: Value| value.to_sql_literal(DBImpl::Postgres).unwrap();
+
+        assert_eq!(literal(Value::Null(NullType::String)), "NULL");
+        assert_eq!(literal(Value::String("it's \\ here")), "'it''s \\ here'");
+        assert_eq!(literal(Value::I64(-7)), "-7");
+        assert_eq!(literal(Value::F64(1.5)), "1.5");
+        assert_eq!(literal(Value::Bool(true)), "TRUE");
+        assert_eq!(literal(Value::Bool(false)), "FALSE");
+        assert_eq!(literal(Value::Binary(&[0, 171, 255])), "'\\x00abff'");
+        assert_eq!(literal(Value::ChronoNaiveDate(date)), "'2024-02-29'");
+        assert_eq!(
+            literal(Value::ChronoDateTime(date_time)),
+            "'2024-02-29 12:30:00+00:00'"
+        );
+        assert_eq!(
+            literal(Value::Uuid(uuid)),
+            "'01234567-89ab-cdef-0123-456789abcdef'"
+        );
+        assert_eq!(
+            literal(Value::UuidSimple(uuid)),
+            "'0123456789abcdef0123456789abcdef'"
+        );
+
+        assert!(Value::Ident("id").to_sql_literal(DBImpl::Postgres).is_err());
+        assert!(Value::F64(f64::NAN)
+            .to_sql_literal(DBImpl::Postgres)
+            .is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn booleans_sqlite() {
+        let rows: &[&[Value]] = &[&[Value::String("foo"), Value::Bool(true)]];
+        let (sql, values) = DBImpl::SQLite
+            .insert("user", &["name", "active"], rows, None)
+            .build()
+            .unwrap();
+        assert_eq!(values, [Value::String("foo"), Value::Bool(true)]);
+        assert_eq!(
+            Query::new(sql, values)
+                .to_inlined_sql(DBImpl::SQLite)
+                .unwrap(),
+            "INSERT OR ABORT INTO user (name, active) VALUES ('foo', 1);"
+        );
+
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(false)),
+        ])));
+        let columns = [DBImpl::SQLite.select_column(None, "name", None, None)];
+        let query = DBImpl::SQLite
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build_query()
+            .unwrap();
+        assert_eq!(query.values(), [Value::Bool(false)]);
+        assert_eq!(
+            query.to_inlined_sql(DBImpl::SQLite).unwrap(),
+            "SELECT name FROM user WHERE (active = 0);"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn booleans_mysql() {
+        let rows: &[&[Value]] = &[&[Value::String("foo"), Value::Bool(true)]];
+        let (sql, values) = DBImpl::MySQL
+            .insert("user", &["name", "active"], rows, None)
+            .build()
+            .unwrap();
+        assert_eq!(values, [Value::String("foo"), Value::Bool(true)]);
+        assert_eq!(
+            Query::new(sql, values)
+                .to_inlined_sql(DBImpl::MySQL)
+                .unwrap(),
+            "INSERT INTO `user` (`name`, `active`) VALUES ('foo', 1);"
+        );
+
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(false)),
+        ])));
+        let columns = [DBImpl::MySQL.select_column(None, "name", None, None)];
+        let query = DBImpl::MySQL
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build_query()
+            .unwrap();
+        assert_eq!(query.values(), [Value::Bool(false)]);
+        assert_eq!(
+            query.to_inlined_sql(DBImpl::MySQL).unwrap(),
+            "SELECT `name` FROM user WHERE (active = 0);"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn booleans_postgres() {
+        let rows: &[&[Value]] = &[&[Value::String("foo"), Value::Bool(true)]];
+        let (sql, values) = DBImpl::Postgres
+            .insert("user", &["name", "active"], rows, None)
+            .build()
+            .unwrap();
+        assert_eq!(values, [Value::String("foo"), Value::Bool(true)]);
+        assert_eq!(
+            Query::new(sql, values)
+                .to_inlined_sql(DBImpl::Postgres)
+                .unwrap(),
+            "INSERT INTO \"user\" (\"name\", \"active\") VALUES ('foo', TRUE);"
+        );
+
+        let condition = Condition::BinaryCondition(BinaryCondition::Equals(Box::new([
+            Condition::Value(Value::Ident("active")),
+            Condition::Value(Value::Bool(false)),
+        ])));
+        let columns = [DBImpl::Postgres.select_column(None, "name", None, None)];
+        let query = DBImpl::Postgres
+            .select(&columns, "user", &[], &[])
+            .where_clause(&condition)
+            .build_query()
+            .unwrap();
+        assert_eq!(query.values(), [Value::Bool(false)]);
+        assert_eq!(
+            query.to_inlined_sql(DBImpl::Postgres).unwrap(),
+            "SELECT \"name\" FROM \"user\" WHERE (active = FALSE);"
+        );
+    }
 }